@@ -85,7 +85,7 @@ fn build_cli() -> Command {
                     Arg::new("lang")
                         .long("lang")
                         .help("Programming language")
-                        .value_parser(["rust", "go", "python", "typescript", "javascript", "markdown"])
+                        .value_parser(["rust", "go", "python", "typescript", "javascript", "java", "kotlin", "c", "cpp", "csharp", "dart", "swift", "zig", "php", "ruby", "elixir", "haskell", "markdown"])
                 )
         )
 .subcommand(
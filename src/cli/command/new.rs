@@ -1,15 +1,18 @@
 use clap::{Arg, ArgMatches, Command};
-use anyhow::{bail, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use inquire::{Select, Confirm};
 use std::fs;
 
 use crate::project_management::config::yaml_modifier::YamlModifier;
+use crate::code_generation::core::language_registry::LanguageRegistry;
 use crate::shared::utils::diff::show_diff;
 
 pub fn spec() -> Command {
+    let languages = LanguageRegistry::global().names().join(", ");
+
     Command::new("new")
         .about("Initialize a new project with moli.yml configuration")
-        .long_about(
+        .long_about(format!(
             "Initialize a new project by creating or updating moli.yml. \
             Supports two modes:\n\
             \n\
@@ -23,18 +26,20 @@ pub fn spec() -> Command {
             • Auto-generates sequential project names (app_1, app_2, etc.)\n\
             • Smart multi-project handling (removes root: true from existing projects)\n\
             • Language-specific directory structures (Rust uses src/, others use root-level)\n\
-            • Supports: rust, go, python, typescript, javascript, any"
-        )
+            • Supports: {}",
+            languages
+        ))
         .arg(
             Arg::new("lang")
                 .short('l')
                 .long("lang")
                 .help("Programming language for direct specification (AI mode)")
-                .long_help(
+                .long_help(format!(
                     "Specify the programming language directly without interactive prompts. \
-                    Supported languages: rust, go, python, typescript, javascript, any. \
-                    When omitted, enters interactive mode for human users."
-                )
+                    Supported languages: {}. \
+                    When omitted, enters interactive mode for human users.",
+                    languages
+                ))
                 .value_name("LANGUAGE")
                 .required(false)
         )
@@ -43,18 +48,17 @@ pub fn spec() -> Command {
 pub fn action(matches: &ArgMatches) -> Result<()> {
     let language = if let Some(lang) = matches.get_one::<String>("lang") {
         // AI mode - language specified via --lang option
-        let supported_languages = ["rust", "go", "python", "typescript", "javascript", "any"];
-        if !supported_languages.contains(&lang.as_str()) {
+        let supported_languages = LanguageRegistry::global().names();
+        if !supported_languages.contains(lang) {
             bail!("Unsupported language: {}. Supported languages: {}", lang, supported_languages.join(", "));
         }
         lang.clone()
     } else {
         // Human mode - interactive language selection
-        let languages = vec!["rust", "go", "python", "typescript", "javascript", "any"];
+        let languages = LanguageRegistry::global().names();
         Select::new("Programming language:", languages)
             .prompt()
-            .context("Failed to get programming language")?.
-            to_string()
+            .context("Failed to get programming language")?
     };
 
     // Determine project name
@@ -125,72 +129,15 @@ fn generate_new_moli_yml(project_name: &str, language: &str) -> Result<String> {
 }
 
 fn generate_new_project_yaml(project_name: &str, language: &str, is_root: bool) -> Result<String> {
-    let main_file = get_main_file_name(language, is_root);
+    let generator = LanguageRegistry::global()
+        .get(language)
+        .ok_or_else(|| anyhow!("Unsupported language: {}", language))?;
     let root_field = if is_root { "  root: true\n" } else { "" };
 
-    match language {
-        "rust" => {
-            // Rust standard: src/main.rs or src/lib.rs
-            Ok(format!(
-                r#"- name: {}
-{}  lang: {}
-  tree:
-    - name: src
-      file:
-        - name: {}
-"#,
-                project_name, root_field, language, main_file
-            ))
-        },
-        "go" => {
-            // Go standard: main.go at project root for simple projects
-            Ok(format!(
-                r#"- name: {}
-{}  lang: {}
-  file:
-    - name: {}
-"#,
-                project_name, root_field, language, main_file
-            ))
-        },
-        "python" | "typescript" | "javascript" => {
-            // Modern standard: src/ directory structure
-            Ok(format!(
-                r#"- name: {}
-{}  lang: {}
-  tree:
-    - name: src
-      file:
-        - name: {}
-"#,
-                project_name, root_field, language, main_file
-            ))
-        },
-        "any" => {
-            // Any language: root-level files with specified extensions
-            Ok(format!(
-                r#"- name: {}
-{}  lang: {}
-  file:
-    - name: {}
-"#,
-                project_name, root_field, language, main_file
-            ))
-        },
-        _ => {
-            // Default: src/ directory structure
-            Ok(format!(
-                r#"- name: {}
-{}  lang: {}
-  tree:
-    - name: src
-      file:
-        - name: {}
-"#,
-                project_name, root_field, language, main_file
-            ))
-        }
-    }
+    Ok(format!(
+        "- name: {}\n{}  lang: {}\n{}",
+        project_name, root_field, language, generator.new_skeleton(project_name, is_root)
+    ))
 }
 
 fn generate_appended_moli_yml(existing_content: &str, project_name: &str, language: &str) -> Result<String> {
@@ -244,15 +191,3 @@ fn replace_first_project_name_with_current_dir(content: &str) -> Result<String>
 
     Ok(result.to_string())
 }
-
-fn get_main_file_name(language: &str, is_root: bool) -> &str {
-    match language {
-        "rust" => if is_root { "main" } else { "lib" },
-        "go" => "main",
-        "python" => "main",
-        "typescript" => "index",
-        "javascript" => "index",
-        "any" => "README.md",
-        _ => "main",
-    }
-}
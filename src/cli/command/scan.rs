@@ -7,6 +7,7 @@ use crate::project_management::config::{ConfigParser, ConfigValidator};
 use crate::project_management::config::filesystem_scanner::{FilesystemScanner, UnmanagedEntry};
 use crate::project_management::config::yaml_modifier::YamlModifier;
 use crate::shared::utils::diff::show_diff;
+use crate::shared::utils::file_system::RealFs;
 use super::shared::{resolve_project, collect_directory_children, would_produce_changes};
 
 pub fn spec() -> Command {
@@ -36,13 +37,14 @@ pub fn action() -> Result<()> {
     // Parse configuration
     let config = ConfigParser::parse_default()
         .context("Failed to parse moli.yml")?;
+    ConfigParser::register_languages(&config);
 
     // Validate configuration
     ConfigValidator::validate(&config)
         .context("Configuration validation failed")?;
 
     // Scan filesystem for unmanaged entries
-    let all_entries = FilesystemScanner::scan(&config)
+    let all_entries = FilesystemScanner::scan(&RealFs, &config)
         .context("Failed to scan filesystem")?;
 
     if all_entries.is_empty() {
@@ -87,7 +89,7 @@ pub fn action() -> Result<()> {
 
     // Collect children if directory
     let children = if target.is_directory {
-        collect_directory_children(&target.relative_path)?
+        collect_directory_children(&target.relative_path, language)?
     } else {
         vec![]
     };
@@ -2,6 +2,7 @@ use anyhow::{bail, Context, Result};
 use std::path::Path;
 
 use crate::project_management::config::models::MoliConfig;
+use crate::code_generation::core::language_registry::LanguageRegistry;
 use crate::project_management::config::filesystem_scanner::UnmanagedEntry;
 use crate::project_management::config::yaml_modifier::{YamlModifier, AddChild};
 
@@ -74,7 +75,7 @@ pub fn would_produce_changes(
     let language = config.projects()[project_index].language();
 
     let children = if entry.is_directory {
-        match collect_directory_children(&entry.relative_path) {
+        match collect_directory_children(&entry.relative_path, language) {
             Ok(c) => c,
             Err(_) => return true,
         }
@@ -109,16 +110,23 @@ pub fn would_produce_changes(
 }
 
 /// Collect all children of a directory as AddChild tree using ignore crate
-pub fn collect_directory_children(dir_path: &Path) -> Result<Vec<AddChild>> {
+/// (manifests and build directories of the project's language are skipped)
+pub fn collect_directory_children(dir_path: &Path, language: &str) -> Result<Vec<AddChild>> {
     use ignore::WalkBuilder;
 
     let mut paths = Vec::new();
+    let registry = LanguageRegistry::global().subset([language]);
+    let excluded_dirs = registry.excluded_dirs();
 
     let walker = WalkBuilder::new(dir_path)
         .hidden(true)
         .git_ignore(true)
         .git_global(true)
         .git_exclude(true)
+        .filter_entry(move |entry| {
+            !entry.file_type().is_some_and(|t| t.is_dir())
+                || !excluded_dirs.iter().any(|d| entry.file_name() == d.as_str())
+        })
         .build();
 
     for result in walker {
@@ -133,12 +141,13 @@ pub fn collect_directory_children(dir_path: &Path) -> Result<Vec<AddChild>> {
         // Skip managed/excluded files
         if let Some(file_name) = path.file_name() {
             let name = file_name.to_string_lossy();
-            let skip_files = [
-                "mod.rs", "__init__.py", "index.ts", "index.js",
-                "Cargo.toml", "Cargo.lock", "package.json", "package-lock.json",
-                "go.mod", "go.sum", ".gitignore",
-            ];
-            if skip_files.contains(&name.as_ref()) {
+            let is_managed = name == ".gitignore"
+                || registry.managed_files().iter()
+                    .chain(registry.excluded_files().iter())
+                    .any(|f| f == name.as_ref());
+            let has_manifest_extension = registry.excluded_extensions().iter()
+                .any(|ext| name.ends_with(ext.as_str()));
+            if is_managed || has_manifest_extension {
                 continue;
             }
         }
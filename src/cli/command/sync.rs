@@ -10,6 +10,7 @@ use crate::project_management::config::path_collector::{PathCollector, ManagedFi
 use crate::project_management::config::filesystem_scanner::FilesystemScanner;
 use crate::project_management::config::yaml_modifier::YamlModifier;
 use crate::shared::utils::diff::show_diff;
+use crate::shared::utils::file_system::RealFs;
 use super::shared::{resolve_project, collect_directory_children, would_produce_changes};
 
 pub fn spec() -> Command {
@@ -45,6 +46,7 @@ pub fn action(matches: &clap::ArgMatches) -> Result<()> {
     // Parse configuration
     let config = ConfigParser::parse_default()
         .context("Failed to parse moli.yml")?;
+    ConfigParser::register_languages(&config);
 
     // Validate configuration
     ConfigValidator::validate(&config)
@@ -72,7 +74,7 @@ pub fn action(matches: &clap::ArgMatches) -> Result<()> {
     let entries_to_remove = filter_redundant_removals(&entries_to_remove);
 
     // === Detect entries to add (on FS but not in yml) ===
-    let unmanaged_entries = FilesystemScanner::scan(&config)
+    let unmanaged_entries = FilesystemScanner::scan(&RealFs, &config)
         .context("Failed to scan filesystem")?;
 
     let entries_to_add: Vec<_> = unmanaged_entries
@@ -124,7 +126,7 @@ pub fn action(matches: &clap::ArgMatches) -> Result<()> {
             let language = updated_config.projects()[project_index].language();
 
             let children = if entry.is_directory {
-                collect_directory_children(&entry.relative_path)?
+                collect_directory_children(&entry.relative_path, language)?
             } else {
                 vec![]
             };
@@ -1,7 +1,10 @@
-use clap::Command;
+use clap::{Command, Arg};
 use anyhow::{bail, Context, Result};
 use crate::project_management::config::{ConfigParser, ConfigValidator};
+use crate::project_management::config::models::MoliConfig;
 use crate::code_generation::core::generator::CodeGenerator;
+use crate::code_generation::core::plan::Plan;
+use crate::shared::utils::file_system::RealFs;
 
 pub fn spec() -> Command {
     Command::new("up")
@@ -27,13 +30,28 @@ pub fn spec() -> Command {
             • moli.yml must exist (create with 'moli new')\n\
             • Valid YAML configuration with supported languages"
         )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .help("Print the directories, files, managed-section rewrites and git clones without touching the filesystem")
+                .action(clap::ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("check")
+                .long("check")
+                .help("Exit with an error listing the paths that differ from moli.yml, without writing anything (for CI)")
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("dry-run")
+        )
 }
 
-pub fn action(_sub_matches: &clap::ArgMatches) -> Result<()> {
-    action_generate()
+pub fn action(sub_matches: &clap::ArgMatches) -> Result<()> {
+    let dry_run = sub_matches.get_flag("dry-run");
+    let check = sub_matches.get_flag("check");
+    action_generate(dry_run, check)
 }
 
-fn action_generate() -> Result<()> {
+fn action_generate(dry_run: bool, check: bool) -> Result<()> {
     // Check if moli.yml exists
     if !ConfigParser::config_exists() {
         bail!("moli.yml not found. Run 'moli new' to create a new project configuration.");
@@ -42,18 +60,26 @@ fn action_generate() -> Result<()> {
     // Parse configuration
     let config = ConfigParser::parse_default()
         .context("Failed to parse moli.yml")?;
+    ConfigParser::register_languages(&config);
 
     // Validate configuration
     ConfigValidator::validate(&config)
         .context("Configuration validation failed")?;
 
+    if dry_run {
+        return action_dry_run(&config);
+    }
+    if check {
+        return action_check(&config);
+    }
+
     // Print generating message for each project
     for project in config.projects() {
         println!("Generating project: {}", project.name());
     }
 
-    // Generate structure using the new CodeGenerator
-    CodeGenerator::generate_from_config(".", &config)
+    // Plan the whole run first, then write it all or nothing
+    CodeGenerator::generate_transactional(&RealFs, ".", &config)
         .context("Failed to generate project structure")?;
 
     // Print success message for each project
@@ -64,3 +90,35 @@ fn action_generate() -> Result<()> {
     println!("[Success] generate of moli has been completed.");
     Ok(())
 }
+
+fn action_dry_run(config: &MoliConfig) -> Result<()> {
+    let plan = Plan::record(&RealFs, |fs| CodeGenerator::generate_from_config(fs, ".", config))
+        .context("Failed to plan project structure")?;
+
+    if plan.is_empty() {
+        println!("Project structure is up to date with moli.yml.");
+    } else {
+        println!("The following changes would be made:");
+        plan.print();
+    }
+
+    println!("[Dry run] No changes were written.");
+    Ok(())
+}
+
+fn action_check(config: &MoliConfig) -> Result<()> {
+    let plan = Plan::record(&RealFs, |fs| CodeGenerator::generate_from_config(fs, ".", config))
+        .context("Failed to check project structure")?;
+
+    if plan.is_empty() {
+        println!("[Success] project structure is up to date with moli.yml.");
+        return Ok(());
+    }
+
+    println!("Project structure differs from moli.yml:");
+    plan.print_drift();
+    bail!(
+        "{} path(s) are out of date with moli.yml. Run 'moli up' to update them.",
+        plan.operations().len()
+    );
+}
@@ -0,0 +1,87 @@
+use crate::project_management::config::models::{Project, Module, CodeFile};
+
+/// Barrel (index file) maintenance policy for JavaScript and TypeScript modules
+pub struct BarrelPolicy;
+
+impl BarrelPolicy {
+    /// Resolve the policy for a module
+    /// Priority: module `barrels` > project `barrels` > "explicit"
+    pub fn mode<'a>(project: &'a Project, module: &'a Module) -> &'a str {
+        module.barrels_setting()
+            .or(project.barrels_setting())
+            .unwrap_or("explicit")
+    }
+
+    /// Resolve the barrel file name with extension (e.g. "index" -> "index.ts")
+    /// Priority: module `index` > project `index` > "index" (Dart: the directory name, models/models.dart)
+    pub fn filename(project: &Project, module: &Module, language: &str) -> String {
+        let name = module.index_setting()
+            .or(project.index_setting())
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| if language == "dart" { module.name() } else { "index".to_string() });
+
+        CodeFile { name, ..Default::default() }.filename_with_extension(language)
+    }
+
+    /// Check if moli maintains the barrel of this module
+    /// - "auto": every module with files or subdirectories
+    /// - "explicit": only when the barrel file is listed in `file`
+    /// - "off": never
+    pub fn is_maintained(project: &Project, module: &Module, language: &str) -> bool {
+        match Self::mode(project, module) {
+            "off" => false,
+            "auto" => module.has_files() || module.has_subtree(),
+            _ => {
+                let barrel = Self::filename(project, module, language);
+                module.files().iter()
+                    .any(|f| f.filename_with_extension(language) == barrel)
+            }
+        }
+    }
+
+    /// Check if a module should be re-exported from its parent barrel
+    /// (every module except those with barrels turned off)
+    pub fn is_reexported(project: &Project, module: &Module) -> bool {
+        Self::mode(project, module) != "off"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_policy_requires_listed_barrel() {
+        let project = Project { lang: "typescript".to_string(), index: Some("mod.ts".to_string()), ..Default::default() };
+        let listed = Module {
+            name: Some("api".to_string()),
+            file: vec![CodeFile { name: "mod.ts".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+        let unlisted = Module {
+            name: Some("api".to_string()),
+            file: vec![CodeFile { name: "index".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+
+        assert!(BarrelPolicy::is_maintained(&project, &listed, "typescript"));
+        assert!(!BarrelPolicy::is_maintained(&project, &unlisted, "typescript"));
+    }
+
+    #[test]
+    fn test_auto_policy_with_module_override() {
+        let project = Project { lang: "typescript".to_string(), barrels: Some("auto".to_string()), ..Default::default() };
+        let module = Module {
+            name: Some("lib".to_string()),
+            index: Some("public-api".to_string()),
+            file: vec![CodeFile { name: "button".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+        let disabled = Module { barrels: Some("off".to_string()), ..module.clone() };
+
+        assert!(BarrelPolicy::is_maintained(&project, &module, "typescript"));
+        assert_eq!(BarrelPolicy::filename(&project, &module, "typescript"), "public-api.ts");
+        assert!(!BarrelPolicy::is_maintained(&project, &disabled, "typescript"));
+        assert!(!BarrelPolicy::is_reexported(&project, &disabled));
+    }
+}
@@ -1,4 +1,4 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Project, Module};
@@ -9,6 +9,7 @@ pub struct DirectoryBuilder;
 impl DirectoryBuilder {
     /// Build complete project directory structure
     pub fn build_project_structure<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         project: &Project,
     ) -> Result<()> {
@@ -18,14 +19,14 @@ impl DirectoryBuilder {
         } else {
             // Non-root project creates subdirectory
             let project_dir = base_path.as_ref().join(project.name());
-            fs::create_dir_all(&project_dir)
+            fs.create_dir_all(&project_dir)
                 .with_context(|| format!("Failed to create project directory: {}", project_dir.display()))?;
             project_dir
         };
 
         // Build module structure recursively
         for module in project.tree() {
-            Self::build_module_structure(&project_path, module)?;
+            Self::build_module_structure(fs, &project_path, module)?;
         }
 
         Ok(())
@@ -33,6 +34,7 @@ impl DirectoryBuilder {
 
     /// Build module directory structure recursively
     pub fn build_module_structure<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         module: &Module,
     ) -> Result<()> {
@@ -42,31 +44,24 @@ impl DirectoryBuilder {
         // If this is a git clone target
         if let Some(git_url) = module.from.as_ref() {
             // Check if directory already exists
-            if module_path.exists() {
+            if fs.exists(&module_path) {
                 eprintln!("⚠️  Directory already exists, skipping clone: {}", module_path.display());
                 return Ok(());
             }
 
-            // Execute git clone
-            eprintln!("🔄 Cloning repository: {} -> {}", git_url, module_name);
-            let output = std::process::Command::new("git")
-                .arg("clone")
-                .arg(git_url)
-                .arg(&module_path)
-                .output();
-
-            match output {
-                Ok(result) if result.status.success() => {
-                    eprintln!("✅ Successfully cloned: {}", module_name);
-                }
-                Ok(result) => {
-                    eprintln!("❌ Failed to clone {}: {}",
-                        module_name,
-                        String::from_utf8_lossy(&result.stderr));
-                    eprintln!("⚠️  Continuing with remaining operations...");
+            // Execute git clone (dry runs and in-memory trees only record it)
+            let on_disk = fs.is_real();
+            if on_disk {
+                eprintln!("🔄 Cloning repository: {} -> {}", git_url, module_name);
+            }
+            match fs.git_clone(git_url, &module_path) {
+                Ok(()) => {
+                    if on_disk {
+                        eprintln!("✅ Successfully cloned: {}", module_name);
+                    }
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to execute git clone for {}: {}", module_name, e);
+                    eprintln!("❌ Failed to clone {}: {}", module_name, e);
                     eprintln!("⚠️  Continuing with remaining operations...");
                 }
             }
@@ -76,12 +71,12 @@ impl DirectoryBuilder {
         }
 
         // Create directory for this module
-        fs::create_dir_all(&module_path)
+        fs.create_dir_all(&module_path)
             .with_context(|| format!("Failed to create module directory: {}", module_path.display()))?;
 
         // Recursively build submodule structures
         for submodule in module.subtree() {
-            Self::build_module_structure(&module_path, submodule)?;
+            Self::build_module_structure(fs, &module_path, submodule)?;
         }
 
         Ok(())
@@ -120,6 +115,7 @@ impl DirectoryBuilder {
 
     /// Check if directory structure already exists
     pub fn structure_exists<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         project: &Project,
     ) -> bool {
@@ -127,12 +123,13 @@ impl DirectoryBuilder {
         
         directories.iter().all(|dir| {
             let full_path = base_path.as_ref().join(dir);
-            full_path.exists() && full_path.is_dir()
+            fs.is_dir(&full_path)
         })
     }
 
     /// Clean up project directory structure (for testing or rebuilding)
     pub fn clean_project_structure<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         project: &Project,
     ) -> Result<()> {
@@ -140,16 +137,16 @@ impl DirectoryBuilder {
             // For root projects, only clean specific module directories
             for module in project.tree() {
                 let module_path = base_path.as_ref().join(module.name());
-                if module_path.exists() {
-                    fs::remove_dir_all(&module_path)
+                if fs.exists(&module_path) {
+                    fs.remove_dir_all(&module_path)
                         .with_context(|| format!("Failed to remove directory: {}", module_path.display()))?;
                 }
             }
         } else {
             // For non-root projects, remove the entire project directory
             let project_path = base_path.as_ref().join(project.name());
-            if project_path.exists() {
-                fs::remove_dir_all(&project_path)
+            if fs.exists(&project_path) {
+                fs.remove_dir_all(&project_path)
                     .with_context(|| format!("Failed to remove project directory: {}", project_path.display()))?;
             }
         }
@@ -161,6 +158,7 @@ impl DirectoryBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::utils::file_system::RealFs;
     use tempfile::TempDir;
     use crate::project_management::config::models::*;
 
@@ -186,14 +184,17 @@ mod tests {
                             r#pub: None,
                             tree: vec![],
                             file: vec![],
+                            ..Default::default()
                         },
                     ],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
-        DirectoryBuilder::build_project_structure(base_path, &project).unwrap();
+        DirectoryBuilder::build_project_structure(&RealFs, base_path, &project).unwrap();
 
         // Check directories exist
         assert!(base_path.join("src").exists());
@@ -217,11 +218,13 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
-        DirectoryBuilder::build_project_structure(base_path, &project).unwrap();
+        DirectoryBuilder::build_project_structure(&RealFs, base_path, &project).unwrap();
 
         // Check project directory and subdirectories exist
         assert!(base_path.join("backend").exists());
@@ -252,14 +255,18 @@ mod tests {
                                     r#pub: None,
                                     tree: vec![],
                                     file: vec![],
+                                    ..Default::default()
                                 },
                             ],
                             file: vec![],
+                            ..Default::default()
                         },
                     ],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         let directories = DirectoryBuilder::get_project_directories(&project);
@@ -287,16 +294,18 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         // Initially doesn't exist
-        assert!(!DirectoryBuilder::structure_exists(base_path, &project));
+        assert!(!DirectoryBuilder::structure_exists(&RealFs, base_path, &project));
 
         // After building, should exist
-        DirectoryBuilder::build_project_structure(base_path, &project).unwrap();
-        assert!(DirectoryBuilder::structure_exists(base_path, &project));
+        DirectoryBuilder::build_project_structure(&RealFs, base_path, &project).unwrap();
+        assert!(DirectoryBuilder::structure_exists(&RealFs, base_path, &project));
     }
 
     #[test]
@@ -316,16 +325,18 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         // Build and verify structure exists
-        DirectoryBuilder::build_project_structure(base_path, &project).unwrap();
+        DirectoryBuilder::build_project_structure(&RealFs, base_path, &project).unwrap();
         assert!(base_path.join("backend").exists());
 
         // Clean and verify structure is removed
-        DirectoryBuilder::clean_project_structure(base_path, &project).unwrap();
+        DirectoryBuilder::clean_project_structure(&RealFs, base_path, &project).unwrap();
         assert!(!base_path.join("backend").exists());
     }
 }
\ No newline at end of file
@@ -1,16 +1,16 @@
-use std::fs;
 use std::path::{Path, PathBuf};
-use anyhow::{Context, Result};
-use crate::project_management::config::models::{Project, Module, CodeFile};
-use crate::code_generation::language::rust::module_generator::RustModuleGenerator;
-use crate::code_generation::language::typescript::module_generator::TypeScriptModuleGenerator;
+use anyhow::{anyhow, Result};
+use crate::project_management::config::models::{Project, Module};
+use crate::code_generation::core::language_registry::LanguageRegistry;
+use crate::shared::utils::file_system::Fs;
 
-/// File builder that handles language-specific file generation
+/// File builder that generates project files through the registered language generators
 pub struct FileBuilder;
 
 impl FileBuilder {
     /// Build all files for a project
     pub fn build_project_files<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         project: &Project,
     ) -> Result<()> {
@@ -20,145 +20,10 @@ impl FileBuilder {
             base_path.as_ref().join(project.name())
         };
 
-        match project.language() {
-            "rust" => Self::build_rust_project_files(&project_path, project),
-            "typescript" => Self::build_typescript_project_files(&project_path, project),
-            _ => Self::build_generic_project_files(&project_path, project),
-        }
-    }
-
-    /// Build Rust-specific project files
-    fn build_rust_project_files<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        // Generate files in all modules
-        for module in project.tree() {
-            Self::build_rust_module_files(&project_path, module, &[])?;
-        }
-
-        // Generate main.rs or lib.rs for src modules (only if explicitly specified)
-        if let Some(src_module) = project.tree().iter().find(|m| m.name() == "src") {
-            if RustModuleGenerator::should_generate_main_rs(project) {
-                RustModuleGenerator::generate_main_rs(&project_path, &[src_module.clone()])?;
-            } else if RustModuleGenerator::should_generate_lib_rs(project) {
-                RustModuleGenerator::generate_lib_rs(&project_path, &[src_module.clone()])?;
-            }
-            // If neither main nor lib is specified, don't generate either (e.g., DDD with src/bin/ structure)
-        }
-
-        Ok(())
-    }
-
-    /// Build TypeScript-specific project files
-    fn build_typescript_project_files<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        // Generate package.json and tsconfig.json
-        TypeScriptModuleGenerator::generate_package_json(&project_path, project.name())?;
-        TypeScriptModuleGenerator::generate_tsconfig_json(&project_path)?;
-
-        // Generate files in all modules
-        for module in project.tree() {
-            Self::build_typescript_module_files(&project_path, module, &[])?;
-        }
-
-        Ok(())
-    }
-
-    /// Build TypeScript module files recursively
-    fn build_typescript_module_files<P: AsRef<Path>>(
-        base_path: P,
-        module: &Module,
-        parent_modules: &[String],
-    ) -> Result<()> {
-        TypeScriptModuleGenerator::generate_module(base_path, module, parent_modules)
-    }
-
-    /// Build Rust module files recursively
-    fn build_rust_module_files<P: AsRef<Path>>(
-        base_path: P,
-        module: &Module,
-        parent_modules: &[String],
-    ) -> Result<()> {
-        RustModuleGenerator::generate_module(base_path, module, parent_modules)
-    }
-
-    /// Build generic project files (non-Rust languages)
-    fn build_generic_project_files<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        for module in project.tree() {
-            Self::build_generic_module_files(&project_path, module, project.language())?;
-        }
-        Ok(())
-    }
-
-    /// Build generic module files recursively
-    fn build_generic_module_files<P: AsRef<Path>>(
-        base_path: P,
-        module: &Module,
-        language: &str,
-    ) -> Result<()> {
-        let module_path = base_path.as_ref().join(module.name());
-
-        // Generate code files in this module
-        for codefile in module.files() {
-            let filename = codefile.filename_with_extension(language);
-            let file_path = module_path.join(&filename);
-            
-            // Create empty file with language-specific content if needed
-            let content = Self::generate_file_content(codefile, language);
-            fs::write(&file_path, content)
-                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
-        }
-
-        // Process submodules recursively
-        for submodule in module.subtree() {
-            Self::build_generic_module_files(&module_path, submodule, language)?;
-        }
-
-        Ok(())
-    }
-
-    /// Generate initial content for files based on language
-    fn generate_file_content(codefile: &CodeFile, language: &str) -> String {
-        match language {
-            "go" => {
-                if codefile.name().contains("main") {
-                    "package main\n\nfunc main() {\n\t// TODO: implement\n}\n".to_string()
-                } else {
-                    "package main\n\n// TODO: implement\n".to_string()
-                }
-            }
-            "python" => {
-                if codefile.name() == "__init__" {
-                    "# Package initialization\n".to_string()
-                } else {
-                    "# TODO: implement\n".to_string()
-                }
-            }
-            "javascript" => {
-                if codefile.name().contains("index") {
-                    "// Main entry point\nconsole.log('Hello, world!');\n".to_string()
-                } else {
-                    "// TODO: implement\n".to_string()
-                }
-            }
-            "typescript" => {
-                if codefile.name().contains("index") {
-                    "// Main entry point\nconsole.log('Hello, world!');\n".to_string()
-                } else {
-                    "// TODO: implement\n".to_string()
-                }
-            }
-            _ => {
-                // Default empty content for other languages
-                "".to_string()
-            }
-        }
+        let generator = LanguageRegistry::global()
+            .get(project.language())
+            .ok_or_else(|| anyhow!("Unsupported language: {}", project.language()))?;
+        generator.generate_project(fs, &project_path, project)
     }
 
     /// Get all file paths that will be created for a project
@@ -207,21 +72,16 @@ impl FileBuilder {
             files.push(module_path.join(filename));
         }
 
-        // Add special files for language-specific modules
-        match language {
-            "rust" => {
-                // Add mod.rs for Rust modules that have submodules or files (except src module)
-                if module.name() != "src" && (!module.subtree().is_empty() || !module.files().is_empty()) {
-                    files.push(module_path.join("mod.rs"));
-                }
+        // Add the aggregation file for modules that have submodules or files
+        // (the Rust src module is declared from main.rs/lib.rs instead of mod.rs)
+        let aggregator = LanguageRegistry::global()
+            .get(language)
+            .and_then(|g| g.aggregator_file().map(str::to_string));
+        if let Some(aggregator) = aggregator {
+            let is_crate_root = language == "rust" && module.name() == "src";
+            if !is_crate_root && (!module.subtree().is_empty() || !module.files().is_empty()) {
+                files.push(module_path.join(aggregator));
             }
-            "typescript" => {
-                // Add index.ts for TypeScript modules that have submodules or files
-                if !module.subtree().is_empty() || !module.files().is_empty() {
-                    files.push(module_path.join("index.ts"));
-                }
-            }
-            _ => {}
         }
 
         // Process submodules
@@ -232,6 +92,7 @@ impl FileBuilder {
 
     /// Check if all project files exist
     pub fn files_exist<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         project: &Project,
     ) -> bool {
@@ -239,7 +100,7 @@ impl FileBuilder {
         
         files.iter().all(|file| {
             let full_path = base_path.as_ref().join(file);
-            full_path.exists() && full_path.is_file()
+            fs.is_file(&full_path)
         })
     }
 }
@@ -247,6 +108,8 @@ impl FileBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
     use tempfile::TempDir;
     use crate::project_management::config::models::*;
     use crate::code_generation::core::directory_builder::DirectoryBuilder;
@@ -273,23 +136,26 @@ mod tests {
                             r#pub: None,
                             tree: vec![],
                             file: vec![
-                                CodeFile { name: "model".to_string(), r#pub: None },
-                                CodeFile { name: "repository".to_string(), r#pub: None },
+                                CodeFile { name: "model".to_string(), r#pub: None, ..Default::default() },
+                                CodeFile { name: "repository".to_string(), r#pub: None, ..Default::default() },
                             ],
+                            ..Default::default()
                         },
                     ],
                     file: vec![
-                        CodeFile { name: "main".to_string(), r#pub: None },
+                        CodeFile { name: "main".to_string(), r#pub: None, ..Default::default() },
                     ],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         // Build directory structure first
-        DirectoryBuilder::build_project_structure(base_path, &project).unwrap();
+        DirectoryBuilder::build_project_structure(&RealFs, base_path, &project).unwrap();
 
         // Build files
-        FileBuilder::build_project_files(base_path, &project).unwrap();
+        FileBuilder::build_project_files(&RealFs, base_path, &project).unwrap();
 
         // Check files exist
         assert!(base_path.join("src/main.rs").exists());
@@ -325,30 +191,31 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![
-                        CodeFile { name: "main".to_string(), r#pub: None },
-                        CodeFile { name: "utils".to_string(), r#pub: None },
+                        CodeFile { name: "main".to_string(), r#pub: None, ..Default::default() },
+                        CodeFile { name: "utils".to_string(), r#pub: None, ..Default::default() },
                     ],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         // Build directory structure first
-        DirectoryBuilder::build_project_structure(base_path, &project).unwrap();
+        DirectoryBuilder::build_project_structure(&RealFs, base_path, &project).unwrap();
         
         // Build files
-        FileBuilder::build_project_files(base_path, &project).unwrap();
+        FileBuilder::build_project_files(&RealFs, base_path, &project).unwrap();
 
         // Check files exist
         assert!(base_path.join("src/main.go").exists());
         assert!(base_path.join("src/utils.go").exists());
 
-        // Check content
+        // Check content (files in a directory share the directory's package)
         let main_go = fs::read_to_string(base_path.join("src/main.go")).unwrap();
-        assert!(main_go.contains("package main"));
-        assert!(main_go.contains("func main()"));
+        assert!(main_go.contains("package src"));
 
         let utils_go = fs::read_to_string(base_path.join("src/utils.go")).unwrap();
-        assert!(utils_go.contains("package main"));
+        assert!(utils_go.contains("package src"));
     }
 
     #[test]
@@ -369,12 +236,15 @@ mod tests {
                             from: None,
                             r#pub: None,
                             tree: vec![],
-                            file: vec![CodeFile { name: "model".to_string(), r#pub: None }],
+                            file: vec![CodeFile { name: "model".to_string(), r#pub: None, ..Default::default() }],
+                            ..Default::default()
                         },
                     ],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         let files = FileBuilder::get_project_files(&project);
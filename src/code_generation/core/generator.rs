@@ -1,25 +1,18 @@
-use std::fs;
 use std::path::Path;
 use anyhow::{Context, Result, anyhow};
-use crate::project_management::config::models::Project;
-use crate::code_generation::language::{
-    rust::module_generator::RustModuleGenerator,
-    rust::workspace_handler::WorkspaceHandler,
-    rust::cargo_handler::CargoHandler,
-    typescript::module_generator::TypeScriptModuleGenerator,
-    go::mod_handler::GoModuleHandler,
-    python::init_handler::PythonInitHandler,
-    javascript::package_handler::JavaScriptPackageHandler,
-    any::file_handler::AnyFileHandler,
-};
-use crate::project_management::config::models::MoliConfig;
+use crate::shared::utils::file_system::Fs;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::code_generation::core::language_registry::LanguageRegistry;
+use crate::code_generation::core::plan::Plan;
 
 /// Core code generator that delegates to language-specific generators
 pub struct CodeGenerator;
 
 impl CodeGenerator {
-    /// Generate complete project structure from configuration
+    /// Generate complete project structure from configuration into the given filesystem backend
+    /// (`RealFs` for the disk, or e.g. a `MemoryFs` when embedding moli or previewing a tree)
     pub fn generate_from_config<P: AsRef<Path>>(
+        fs: &dyn Fs,
         output_path: P,
         config: &MoliConfig,
     ) -> Result<()> {
@@ -29,54 +22,65 @@ impl CodeGenerator {
         if config.is_single_project() {
             // Single project mode - generate directly in output path
             if let Some(root_project) = config.root_project() {
-                Self::generate_single_project(output_path, root_project)?;
+                Self::generate_single_project(fs, output_path, root_project)?;
             }
         } else {
             // Multi-project mode - generate each project in its own directory
-            Self::generate_multi_project(output_path, config)?;
+            Self::generate_multi_project(fs, output_path, config)?;
         }
 
         Ok(())
     }
 
+    /// Generate complete project structure all or nothing: the run is planned first,
+    /// then applied with rollback on failure
+    pub fn generate_transactional<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        output_path: P,
+        config: &MoliConfig,
+    ) -> Result<()> {
+        let plan = Plan::record(fs, |recorder| Self::generate_from_config(recorder, output_path, config))?;
+        plan.apply(fs)
+    }
+
     /// Generate single project structure
     pub fn generate_single_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
         output_path: P,
         project: &Project,
     ) -> Result<()> {
         let output_path = output_path.as_ref();
 
         // Create output directory
-        fs::create_dir_all(output_path)
+        fs.create_dir_all(output_path)
             .with_context(|| format!("Failed to create output directory: {}", output_path.display()))?;
 
         // Delegate to language-specific generator
-        match project.language() {
-            "rust" => Self::generate_rust_project(output_path, project),
-            "typescript" => Self::generate_typescript_project(output_path, project),
-            "go" => Self::generate_go_project(output_path, project),
-            "python" => Self::generate_python_project(output_path, project),
-            "javascript" => Self::generate_javascript_project(output_path, project),
-            "any" => Self::generate_any_project(output_path, project),
-            lang => Err(anyhow!("Unsupported language: {}", lang)),
-        }
+        let generator = LanguageRegistry::global()
+            .get(project.language())
+            .ok_or_else(|| anyhow!("Unsupported language: {}", project.language()))?;
+        generator.generate_project(fs, output_path, project)
     }
 
     /// Generate multi-project structure
     fn generate_multi_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
         output_path: P,
         config: &MoliConfig,
     ) -> Result<()> {
         let output_path = output_path.as_ref();
 
         // Create workspace directory
-        fs::create_dir_all(output_path)
+        fs.create_dir_all(output_path)
             .with_context(|| format!("Failed to create workspace directory: {}", output_path.display()))?;
 
-        // Generate workspace-level files if needed
-        if WorkspaceHandler::should_generate_workspace(config) {
-            WorkspaceHandler::generate_workspace_cargo_toml(output_path, config)?;
-            WorkspaceHandler::generate_makefile_toml(output_path, config)?;
+        // Generate workspace-level files for every language used in the workspace
+        // (generators are cloned out so no registry lock is held while generating)
+        let generators = LanguageRegistry::global().generators().to_vec();
+        for generator in &generators {
+            if config.projects().iter().any(|p| p.language() == generator.name()) {
+                generator.generate_workspace(fs, output_path, config)?;
+            }
         }
 
         // Generate each project
@@ -84,132 +88,67 @@ impl CodeGenerator {
             let project_path = output_path.join(project.name());
 
             // Create project directory
-            fs::create_dir_all(&project_path)
+            fs.create_dir_all(&project_path)
                 .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
 
             // Generate project structure
-            match project.language() {
-                "rust" => Self::generate_rust_project(&project_path, project),
-                "typescript" => Self::generate_typescript_project(&project_path, project),
-                "go" => Self::generate_go_project(&project_path, project),
-                "python" => Self::generate_python_project(&project_path, project),
-                "javascript" => Self::generate_javascript_project(&project_path, project),
-                "any" => Self::generate_any_project(&project_path, project),
-                lang => Err(anyhow!("Unsupported language: {}", lang)),
-            }?;
-        }
-
-        Ok(())
-    }
-
-    /// Generate Rust project
-    fn generate_rust_project<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        let project_path = project_path.as_ref();
-
-        // Generate Cargo.toml
-        CargoHandler::generate_cargo_toml(project_path, project)?;
-
-        // Create src directory
-        let src_path = project_path.join("src");
-        fs::create_dir_all(&src_path)
-            .with_context(|| format!("Failed to create src directory: {}", src_path.display()))?;
-
-        // Generate project-level code files (only if they don't exist)
-        for codefile in project.files() {
-            let filename = codefile.filename_with_extension("rust");
-            let file_path = project_path.join(&filename);
-
-            // Only create file if it doesn't already exist
-            if !file_path.exists() {
-                fs::write(&file_path, "")
-                    .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
-            }
-        }
+            let generator = LanguageRegistry::global()
+                .get(project.language())
+                .ok_or_else(|| anyhow!("Unsupported language: {}", project.language()))?;
+            generator.generate_project(fs, &project_path, project)?;
 
-        // Generate module structure
-        for module in project.tree() {
-            RustModuleGenerator::generate_module(project_path, module, &[])?;
+            // Link the project with the other workspace members (project references, dependencies)
+            generator.link_project(fs, output_path, project, config)?;
         }
 
-        // Generate main.rs or lib.rs
-        let src_modules: Vec<_> = project.tree().iter()
-            .filter(|m| m.name() == "src")
-            .cloned()
-            .collect();
-
-        if RustModuleGenerator::should_generate_main_rs(project) {
-            RustModuleGenerator::generate_main_rs(project_path, &src_modules)?;
-        } else if RustModuleGenerator::should_generate_lib_rs(project) {
-            RustModuleGenerator::generate_lib_rs(project_path, &src_modules)?;
-        }
-        // If neither main nor lib is specified, don't generate either (e.g., DDD with src/bin/ structure)
-
         Ok(())
     }
+}
 
-    /// Generate TypeScript project
-    fn generate_typescript_project<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        let project_path = project_path.as_ref();
-
-        // Generate package.json and tsconfig.json
-        TypeScriptModuleGenerator::generate_package_json(project_path, project.name())?;
-        TypeScriptModuleGenerator::generate_tsconfig_json(project_path)?;
-
-        // Generate project-level code files (only if they don't exist)
-        for codefile in project.files() {
-            let filename = codefile.filename_with_extension("typescript");
-            let file_path = project_path.join(&filename);
-
-            // Only create file if it doesn't already exist
-            if !file_path.exists() {
-                fs::write(&file_path, "")
-                    .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
-            }
-        }
-
-        // Generate module structure
-        for module in project.tree() {
-            TypeScriptModuleGenerator::generate_module(project_path, module, &[])?;
-        }
-
-        Ok(())
-    }
-
-    /// Generate Go project
-    fn generate_go_project<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        GoModuleHandler::generate_project(project_path, project)
-    }
-
-    /// Generate Python project
-    fn generate_python_project<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        PythonInitHandler::generate_project(project_path, project)
-    }
-
-    /// Generate JavaScript project
-    fn generate_javascript_project<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        JavaScriptPackageHandler::generate_project(project_path, project)
-    }
-
-    /// Generate "any" language project
-    fn generate_any_project<P: AsRef<Path>>(
-        project_path: P,
-        project: &Project,
-    ) -> Result<()> {
-        AnyFileHandler::generate_project(project_path, project)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+    use crate::project_management::config::models::*;
+    use crate::shared::utils::memory_fs::MemoryFs;
+
+    #[test]
+    fn test_generate_into_memory() {
+        let config = MoliConfig {
+            projects: vec![Project {
+                name: "demo".to_string(),
+                root: true,
+                lang: "python".to_string(),
+                tree: vec![Module {
+                    name: Some("app".to_string()),
+                    file: vec![CodeFile { name: "user".to_string(), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let memory = MemoryFs::new();
+
+        CodeGenerator::generate_from_config(&memory, ".", &config).unwrap();
+
+        let files = memory.files();
+        let paths: Vec<&PathBuf> = files.keys().collect();
+        assert_eq!(paths, vec![
+            &PathBuf::from("app/__init__.py"),
+            &PathBuf::from("app/user.py"),
+            &PathBuf::from("main.py"),
+            &PathBuf::from("requirements.txt"),
+            &PathBuf::from("setup.py"),
+        ]);
+        assert_eq!(files[&PathBuf::from("app/__init__.py")],
+            "# start auto exported by moli.\nfrom .user import *\n# end auto exported by moli.\n\n");
+        assert_eq!(memory.dirs(), vec![PathBuf::from("app")]);
+
+        // Regenerating an in-memory tree is idempotent
+        let before: BTreeMap<_, _> = files;
+        CodeGenerator::generate_from_config(&memory, ".", &config).unwrap();
+        assert_eq!(memory.files(), before);
     }
 }
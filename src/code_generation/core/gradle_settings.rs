@@ -0,0 +1,113 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::code_generation::language::java::build_handler::JavaBuildHandler;
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+
+/// Shared settings.gradle.kts for multi-project configs with Java and Kotlin projects
+pub struct GradleSettings;
+
+impl GradleSettings {
+    /// Check if a project is built as part of the Gradle multi-project build
+    /// (Kotlin always uses Gradle, Java unless `build: maven`)
+    pub fn is_gradle_project(project: &Project) -> bool {
+        match project.language() {
+            "kotlin" => true,
+            "java" => JavaBuildHandler::build_tool(project) == "gradle",
+            _ => false,
+        }
+    }
+
+    /// Check if a settings.gradle.kts is needed
+    pub fn should_generate(config: &MoliConfig) -> bool {
+        config.projects().iter().any(Self::is_gradle_project)
+    }
+
+    /// Generate settings.gradle.kts with a managed `include(...)` block for every Gradle project
+    pub fn generate<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        workspace_path: P,
+        config: &MoliConfig,
+    ) -> Result<()> {
+        let workspace_path = workspace_path.as_ref();
+        let settings_path = workspace_path.join("settings.gradle.kts");
+
+        if !fs.exists(&settings_path) {
+            let content = format!(
+                "rootProject.name = \"{}\"\n\n// start auto exported by moli.\n// end auto exported by moli.\n",
+                paths::directory_name(fs, workspace_path, "workspace")
+            );
+            fs.write(&settings_path, content)
+                .with_context(|| format!("Failed to create settings.gradle.kts: {}", settings_path.display()))?;
+        }
+
+        ContentUpdater::update_managed_section(
+            fs,
+            &settings_path,
+            &Self::generate_include_content(config),
+            "// start auto exported by moli.\n",
+            "// end auto exported by moli.",
+        ).with_context(|| format!("Failed to update settings.gradle.kts: {}", settings_path.display()))
+    }
+
+    /// Build the `include(":api", ":core")` statement ("." is the root build itself, not an included project)
+    fn generate_include_content(config: &MoliConfig) -> String {
+        let project_paths: Vec<String> = config.projects()
+            .iter()
+            .filter(|p| Self::is_gradle_project(p) && p.name() != ".")
+            .map(|p| format!("\":{}\"", p.name().replace('/', ":")))
+            .collect();
+
+        if project_paths.is_empty() {
+            String::new()
+        } else {
+            format!("include({})", project_paths.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project(name: &str, lang: &str, build: Option<&str>) -> Project {
+        Project {
+            name: name.to_string(),
+            lang: lang.to_string(),
+            build: build.map(|b| b.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = MoliConfig {
+            projects: vec![
+                make_project("api", "kotlin", None),
+                make_project("core", "java", None),
+                make_project("services/worker", "kotlin", None),
+                make_project("legacy", "java", Some("maven")),
+                make_project("web", "typescript", None),
+            ],
+            ..Default::default()
+        };
+
+        GradleSettings::generate(&RealFs, base_path, &config).unwrap();
+        GradleSettings::generate(&RealFs, base_path, &config).unwrap();
+
+        let settings = fs::read_to_string(base_path.join("settings.gradle.kts")).unwrap();
+        assert!(settings.starts_with("rootProject.name = "));
+        assert!(settings.contains(
+            "// start auto exported by moli.\ninclude(\":api\", \":core\", \":services:worker\")\n// end auto exported by moli."
+        ));
+        assert!(!settings.contains("legacy"));
+        assert_eq!(settings.matches("include(").count(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::project_management::config::validator::ValidationError;
+use crate::shared::utils::file_system::Fs;
+
+/// A language moli can generate projects for
+///
+/// Implementations are registered in the [`LanguageRegistry`](super::language_registry::LanguageRegistry),
+/// which code generation, validation, scanning and `moli new` query instead of matching on `lang:`.
+pub trait LanguageGenerator: Send + Sync {
+    /// Language identifier used in `lang:` (e.g. "rust")
+    fn name(&self) -> &str;
+
+    /// Extension appended to file names given without one (e.g. "rs"); `None` keeps names as written
+    fn extension(&self) -> Option<&str>;
+
+    /// Generate manifests, the module tree and aggregation files of one project
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()>;
+
+    /// Generate workspace-level files of a multi-project config (runs before the projects)
+    fn generate_workspace(&self, _fs: &dyn Fs, _workspace_path: &Path, _config: &MoliConfig) -> Result<()> {
+        Ok(())
+    }
+
+    /// Link a generated project with the other workspace members (runs after the project)
+    fn link_project(&self, _fs: &dyn Fs, _workspace_path: &Path, _project: &Project, _config: &MoliConfig) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check language-specific settings of a project (`path` locates it in moli.yml, e.g. "projects[0]")
+    fn validate_project(&self, _project: &Project, _path: &str) -> Vec<ValidationError> {
+        Vec::new()
+    }
+
+    /// Check constraints across the projects of a config (runs once per language in use)
+    fn validate_workspace(&self, _config: &MoliConfig) -> Vec<ValidationError> {
+        Vec::new()
+    }
+
+    /// Language-specific moli.yml options the generator reads (e.g. "layout"); setting any other is an error
+    fn options(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Module aggregation file moli maintains in directories (e.g. "mod.rs", "__init__.py")
+    fn aggregator_file(&self) -> Option<&str> {
+        None
+    }
+
+    /// Files moli maintains that are never shown as unmanaged (defaults to the aggregation file)
+    fn managed_files(&self) -> Vec<&str> {
+        self.aggregator_file().into_iter().collect()
+    }
+
+    /// Manifests and lock files that are never load candidates (e.g. "Cargo.toml")
+    fn excluded_files(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Extensions of manifests named after the project (e.g. ".csproj")
+    fn excluded_extensions(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// Build output and dependency directories skipped by scans (e.g. "target")
+    fn excluded_dirs(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// moli.yml lines following `lang:` that `moli new` writes for a new project
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        "  tree:\n    - name: src\n      file:\n        - name: main\n".to_string()
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, RwLock, RwLockReadGuard};
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::code_generation::language::{
+    rust::generator::RustGenerator,
+    go::generator::GoGenerator,
+    python::generator::PythonGenerator,
+    typescript::generator::TypeScriptGenerator,
+    javascript::generator::JavaScriptGenerator,
+    java::generator::JavaGenerator,
+    kotlin::generator::KotlinGenerator,
+    c::generator::CGenerator,
+    csharp::generator::CSharpGenerator,
+    dart::generator::DartGenerator,
+    swift::generator::SwiftGenerator,
+    zig::generator::ZigGenerator,
+    php::generator::PhpGenerator,
+    ruby::generator::RubyGenerator,
+    elixir::generator::ElixirGenerator,
+    haskell::generator::HaskellGenerator,
+    any::generator::AnyGenerator,
+    plugin::generator::PluginGenerator,
+};
+
+static GLOBAL_REGISTRY: OnceLock<RwLock<LanguageRegistry>> = OnceLock::new();
+
+/// Held by scoped registrations so they never overlap (and restore each other's generators)
+static SCOPED_REGISTRATION: Mutex<()> = Mutex::new(());
+
+/// Registry of the languages moli can generate, looked up by their `lang:` name
+pub struct LanguageRegistry {
+    generators: Vec<Arc<dyn LanguageGenerator>>,
+}
+
+impl LanguageRegistry {
+    /// Create an empty registry
+    pub fn empty() -> Self {
+        Self { generators: Vec::new() }
+    }
+
+    /// Create a registry with every built-in language
+    pub fn builtin() -> Self {
+        let mut registry = Self::empty();
+        registry.add(Arc::new(RustGenerator));
+        registry.add(Arc::new(GoGenerator));
+        registry.add(Arc::new(PythonGenerator));
+        registry.add(Arc::new(TypeScriptGenerator));
+        registry.add(Arc::new(JavaScriptGenerator));
+        registry.add(Arc::new(JavaGenerator));
+        registry.add(Arc::new(KotlinGenerator));
+        registry.add(Arc::new(CGenerator::c()));
+        registry.add(Arc::new(CGenerator::cpp()));
+        registry.add(Arc::new(CSharpGenerator));
+        registry.add(Arc::new(DartGenerator));
+        registry.add(Arc::new(SwiftGenerator));
+        registry.add(Arc::new(ZigGenerator));
+        registry.add(Arc::new(PhpGenerator));
+        registry.add(Arc::new(RubyGenerator));
+        registry.add(Arc::new(ElixirGenerator));
+        registry.add(Arc::new(HaskellGenerator));
+        registry.add(Arc::new(AnyGenerator));
+        registry
+    }
+
+    /// Add a generator, replacing any generator registered under the same name
+    pub fn add(&mut self, generator: Arc<dyn LanguageGenerator>) {
+        match self.generators.iter().position(|g| g.name() == generator.name()) {
+            Some(index) => self.generators[index] = generator,
+            None => self.generators.push(generator),
+        }
+    }
+
+    /// Remove the generator registered under a name, returning it
+    pub fn remove(&mut self, language: &str) -> Option<Arc<dyn LanguageGenerator>> {
+        let index = self.generators.iter().position(|g| g.name() == language)?;
+        Some(self.generators.remove(index))
+    }
+
+    /// Get the generator for a language
+    /// (unregistered `x-` languages resolve to a `moli-gen-<lang>` plugin on PATH)
+    pub fn get(&self, language: &str) -> Option<Arc<dyn LanguageGenerator>> {
+        self.generators.iter()
+            .find(|g| g.name() == language)
+            .cloned()
+            .or_else(|| PluginGenerator::resolve(language).map(|p| Arc::new(p) as Arc<dyn LanguageGenerator>))
+    }
+
+    /// Create a registry with only the given languages, e.g. those used by the projects of a config
+    /// (unknown languages are skipped)
+    pub fn subset<'a>(&self, languages: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut registry = Self::empty();
+        for generator in languages.into_iter().filter_map(|language| self.get(language)) {
+            registry.add(generator);
+        }
+        registry
+    }
+
+    /// Check if a language is registered or served by a plugin
+    pub fn contains(&self, language: &str) -> bool {
+        self.get(language).is_some()
+    }
+
+    /// Get all registered generators in registration order
+    pub fn generators(&self) -> &[Arc<dyn LanguageGenerator>] {
+        &self.generators
+    }
+
+    /// Get all registered language names in registration order
+    pub fn names(&self) -> Vec<String> {
+        self.generators.iter().map(|g| g.name().to_string()).collect()
+    }
+
+    /// Get the default extension of a language
+    pub fn extension(&self, language: &str) -> Option<String> {
+        self.get(language).and_then(|g| g.extension().map(str::to_string))
+    }
+
+    /// Get the default extensions of every language (".rs", ".go", ...)
+    pub fn extensions(&self) -> Vec<String> {
+        self.collect_unique(|g| g.extension().map(|ext| format!(".{}", ext)).into_iter().collect())
+    }
+
+    /// Get the files moli maintains across all languages
+    pub fn managed_files(&self) -> Vec<String> {
+        self.collect_unique(|g| g.managed_files().iter().map(|f| f.to_string()).collect())
+    }
+
+    /// Get the manifests excluded from load candidates across all languages
+    pub fn excluded_files(&self) -> Vec<String> {
+        self.collect_unique(|g| g.excluded_files().iter().map(|f| f.to_string()).collect())
+    }
+
+    /// Get the manifest extensions excluded from load candidates across all languages
+    pub fn excluded_extensions(&self) -> Vec<String> {
+        self.collect_unique(|g| g.excluded_extensions().iter().map(|e| e.to_string()).collect())
+    }
+
+    /// Get the directories skipped by scans across all languages
+    pub fn excluded_dirs(&self) -> Vec<String> {
+        self.collect_unique(|g| g.excluded_dirs().iter().map(|d| d.to_string()).collect())
+    }
+
+    fn collect_unique(&self, values: impl Fn(&dyn LanguageGenerator) -> Vec<String>) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.generators.iter()
+            .flat_map(|g| values(g.as_ref()))
+            .filter(|value| seen.insert(value.clone()))
+            .collect()
+    }
+
+    /// Get the process-wide registry (built-in languages plus everything registered at runtime)
+    pub fn global() -> RwLockReadGuard<'static, LanguageRegistry> {
+        Self::global_lock()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Register a language in the process-wide registry
+    pub fn register(generator: Arc<dyn LanguageGenerator>) {
+        Self::global_lock()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .add(generator);
+    }
+
+    /// Register a language in the process-wide registry until the returned guard is dropped
+    /// (e.g. for tests), then restore the generator it replaced
+    ///
+    /// Only one scoped registration is held at a time; others wait until it is dropped.
+    pub fn register_scoped(generator: Arc<dyn LanguageGenerator>) -> ScopedRegistration {
+        let lock = SCOPED_REGISTRATION.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut registry = Self::global_lock()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let language = generator.name().to_string();
+        let replaced = registry.generators.iter().find(|g| g.name() == language).cloned();
+        registry.add(generator);
+        ScopedRegistration { language, replaced, _lock: lock }
+    }
+
+    fn global_lock() -> &'static RwLock<LanguageRegistry> {
+        GLOBAL_REGISTRY.get_or_init(|| RwLock::new(Self::builtin()))
+    }
+}
+
+/// Guard returned by [`LanguageRegistry::register_scoped`]; unregisters the language when dropped
+pub struct ScopedRegistration {
+    language: String,
+    replaced: Option<Arc<dyn LanguageGenerator>>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Drop for ScopedRegistration {
+    fn drop(&mut self) {
+        let mut registry = LanguageRegistry::global_lock()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match self.replaced.take() {
+            Some(generator) => registry.add(generator),
+            None => {
+                registry.remove(&self.language);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::path::Path;
+    use anyhow::Result;
+    use tempfile::TempDir;
+    use crate::code_generation::core::generator::CodeGenerator;
+    use crate::project_management::config::models::{MoliConfig, Module, Project};
+    use crate::project_management::config::validator::{ConfigValidator, ValidationError};
+    use crate::shared::utils::file_system::Fs;
+
+    struct NixGenerator;
+
+    impl LanguageGenerator for NixGenerator {
+        fn name(&self) -> &str {
+            "nix"
+        }
+
+        fn extension(&self) -> Option<&str> {
+            Some("nix")
+        }
+
+        fn generate_project(&self, fs: &dyn Fs, project_path: &Path, _project: &Project) -> Result<()> {
+            fs.write(project_path.join("default.nix"), "{ }\n")?;
+            Ok(())
+        }
+
+        fn aggregator_file(&self) -> Option<&str> {
+            Some("default.nix")
+        }
+
+        fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+            project.tree().iter()
+                .map(|_| ValidationError {
+                    message: "Nix projects have no module tree".to_string(),
+                    path: format!("{}.tree", path),
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_builtin_languages() {
+        let registry = LanguageRegistry::builtin();
+
+        assert!(registry.contains("rust"));
+        assert!(registry.contains("cpp"));
+        assert_eq!(registry.extension("python").as_deref(), Some("py"));
+        assert_eq!(registry.extension("any"), None);
+        assert!(registry.managed_files().contains(&"mod.rs".to_string()));
+        assert!(registry.excluded_dirs().contains(&"node_modules".to_string()));
+    }
+
+    #[test]
+    fn test_subset_of_languages() {
+        let registry = LanguageRegistry::builtin().subset(["rust", "rust", "cobol"]);
+
+        assert_eq!(registry.names(), vec!["rust"]);
+        assert_eq!(registry.excluded_dirs(), vec!["target"]);
+        assert!(!registry.excluded_files().contains(&"package.json".to_string()));
+    }
+
+    #[test]
+    fn test_add_language() {
+        let mut registry = LanguageRegistry::builtin();
+        registry.add(Arc::new(NixGenerator));
+
+        assert_eq!(registry.names().last().map(String::as_str), Some("nix"));
+        assert!(registry.managed_files().contains(&"default.nix".to_string()));
+        assert!(registry.extensions().contains(&".nix".to_string()));
+    }
+
+    #[test]
+    fn test_register_global_language() {
+        let temp_dir = TempDir::new().unwrap();
+        let registration = LanguageRegistry::register_scoped(Arc::new(NixGenerator));
+
+        let project = Project {
+            name: "infra".to_string(),
+            root: true,
+            lang: "nix".to_string(),
+            ..Default::default()
+        };
+        CodeGenerator::generate_single_project(&RealFs, temp_dir.path(), &project).unwrap();
+
+        assert!(temp_dir.path().join("default.nix").exists());
+        assert_eq!(LanguageRegistry::global().extension("nix").as_deref(), Some("nix"));
+
+        drop(registration);
+        assert!(!LanguageRegistry::global().contains("nix"));
+    }
+
+    #[test]
+    fn test_registered_language_validates_projects() {
+        let _registration = LanguageRegistry::register_scoped(Arc::new(NixGenerator));
+
+        let config = MoliConfig {
+            projects: vec![Project {
+                name: "infra".to_string(),
+                root: true,
+                lang: "nix".to_string(),
+                tree: vec![Module { name: Some("modules".to_string()), ..Default::default() }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let error = ConfigValidator::validate(&config).unwrap_err().to_string();
+
+        assert!(error.contains("projects[0].tree: Nix projects have no module tree"), "{}", error);
+    }
+}
@@ -2,9 +2,17 @@
 pub mod generator;
 pub mod file_builder;
 pub mod directory_builder;
+pub mod barrel_policy;
+pub mod gradle_settings;
+pub mod language_generator;
+pub mod language_registry;
+pub mod plan;
+pub mod transaction;
 // end auto exported by moli.
 
 // Re-exports for convenience
 pub use directory_builder::DirectoryBuilder;
 pub use file_builder::FileBuilder;
+pub use language_generator::LanguageGenerator;
+pub use language_registry::LanguageRegistry;
 
@@ -0,0 +1,408 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use anyhow::Result;
+use serde::Serialize;
+use crate::shared::utils::diff::show_diff;
+use crate::code_generation::core::transaction::Transaction;
+use crate::shared::utils::file_system::{normalize, Fs, WalkEntry};
+
+/// Filesystem operation performed by `moli up`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PlannedOperation {
+    CreateDir { path: PathBuf },
+    CreateFile { path: PathBuf, content: String },
+    /// Rewrite of an existing file (managed sections, manifest fields)
+    UpdateFile { path: PathBuf, old: String, new: String },
+    GitClone { url: String, path: PathBuf },
+}
+
+impl PlannedOperation {
+    /// Get the path the operation affects
+    pub fn path(&self) -> &Path {
+        match self {
+            PlannedOperation::CreateDir { path }
+            | PlannedOperation::CreateFile { path, .. }
+            | PlannedOperation::UpdateFile { path, .. }
+            | PlannedOperation::GitClone { path, .. } => path,
+        }
+    }
+}
+
+/// Ordered list of the operations a generation run performs
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Plan {
+    operations: Vec<PlannedOperation>,
+}
+
+impl Plan {
+    /// Create a plan from operations in execution order
+    pub fn new(operations: Vec<PlannedOperation>) -> Self {
+        Self { operations }
+    }
+
+    /// Run a generation without touching the filesystem and return the operations it would perform
+    ///
+    /// The generation is handed a recording backend layered over `base`; reads see the planned
+    /// state, so later steps build on earlier planned writes.
+    pub fn record<F>(base: &dyn Fs, generate: F) -> Result<Plan>
+    where
+        F: FnOnce(&dyn Fs) -> Result<()>,
+    {
+        let recorder = PlanRecorder::new(base);
+        generate(&recorder)?;
+        Ok(recorder.finish())
+    }
+
+    /// Get the planned operations in execution order
+    pub fn operations(&self) -> &[PlannedOperation] {
+        &self.operations
+    }
+
+    /// Check if the run would change nothing
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Print every operation, with a diff for rewritten files
+    pub fn print(&self) {
+        for operation in &self.operations {
+            match operation {
+                PlannedOperation::CreateDir { path } => {
+                    println!("  + {}/", path.display());
+                }
+                PlannedOperation::CreateFile { path, .. } => {
+                    println!("  + {}", path.display());
+                }
+                PlannedOperation::UpdateFile { path, old, new } => {
+                    println!("  ~ {}", path.display());
+                    show_diff(old, new);
+                }
+                PlannedOperation::GitClone { url, path } => {
+                    println!("  + {}/ (git clone {})", path.display(), url);
+                }
+            }
+        }
+    }
+
+    /// Print every path that disagrees with moli.yml, with a diff for stale content
+    pub fn print_drift(&self) {
+        for operation in &self.operations {
+            match operation {
+                PlannedOperation::CreateDir { path } => {
+                    println!("  missing directory: {}/", path.display());
+                }
+                PlannedOperation::CreateFile { path, .. } => {
+                    println!("  missing file: {}", path.display());
+                }
+                PlannedOperation::UpdateFile { path, old, new } => {
+                    println!("  stale: {}", path.display());
+                    show_diff(old, new);
+                }
+                PlannedOperation::GitClone { url, path } => {
+                    println!("  missing clone: {}/ (from {})", path.display(), url);
+                }
+            }
+        }
+    }
+
+    /// Perform the planned operations on a filesystem backend, all or nothing
+    pub fn apply(&self, fs: &dyn Fs) -> Result<()> {
+        Transaction::commit(fs, self)
+    }
+}
+
+/// Backend recording writes as planned operations on top of another backend, which is only read
+struct PlanRecorder<'a> {
+    base: &'a dyn Fs,
+    state: Mutex<PlanState>,
+}
+
+/// Planned operations and the planned state of the tree
+#[derive(Default)]
+struct PlanState {
+    operations: Vec<PlannedOperation>,
+    /// Operation index per file path, so repeated writes collapse into one operation
+    file_operations: HashMap<PathBuf, usize>,
+    files: HashMap<PathBuf, String>,
+    dirs: HashSet<PathBuf>,
+}
+
+impl<'a> PlanRecorder<'a> {
+    fn new(base: &'a dyn Fs) -> Self {
+        Self { base, state: Mutex::new(PlanState::default()) }
+    }
+
+    /// Finish recording, dropping rewrites that ended up restoring the original content
+    fn finish(&self) -> Plan {
+        let state = std::mem::take(&mut *self.state.lock().unwrap());
+        let operations = state.operations
+            .into_iter()
+            .filter(|operation| !matches!(operation, PlannedOperation::UpdateFile { old, new, .. } if old == new))
+            .collect();
+        Plan { operations }
+    }
+
+    fn is_dir_in(&self, state: &PlanState, path: &Path) -> bool {
+        state.dirs.contains(path) || (!state.files.contains_key(path) && self.base.is_dir(path))
+    }
+}
+
+impl Fs for PlanRecorder<'_> {
+    fn make_dir_all(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.state.lock().unwrap();
+        if path.as_os_str().is_empty() || self.is_dir_in(&state, &path) {
+            return Ok(());
+        }
+        state.operations.push(PlannedOperation::CreateDir { path: path.clone() });
+        for ancestor in path.ancestors().filter(|a| !a.as_os_str().is_empty()) {
+            state.dirs.insert(ancestor.to_path_buf());
+        }
+        Ok(())
+    }
+
+    fn write_bytes(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let path = normalize(path);
+        let content = String::from_utf8_lossy(contents).to_string();
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(&index) = state.file_operations.get(&path) {
+            match &mut state.operations[index] {
+                PlannedOperation::CreateFile { content: planned, .. }
+                | PlannedOperation::UpdateFile { new: planned, .. } => *planned = content.clone(),
+                _ => {}
+            }
+        } else {
+            let operation = match self.base.read_to_string(&path) {
+                Ok(old) if old == content => None,
+                Ok(old) => Some(PlannedOperation::UpdateFile { path: path.clone(), old, new: content.clone() }),
+                Err(_) => Some(PlannedOperation::CreateFile { path: path.clone(), content: content.clone() }),
+            };
+            if let Some(operation) = operation {
+                let index = state.operations.len();
+                state.file_operations.insert(path.clone(), index);
+                state.operations.push(operation);
+            }
+        }
+
+        state.files.insert(path, content);
+        Ok(())
+    }
+
+    fn read_string(&self, path: &Path) -> io::Result<String> {
+        let planned = self.state.lock().unwrap().files.get(&normalize(path)).cloned();
+        match planned {
+            Some(content) => Ok(content),
+            None => self.base.read_to_string(path),
+        }
+    }
+
+    fn move_file(&self, from: &Path, _to: &Path) -> io::Result<()> {
+        Err(unsupported("rename", from))
+    }
+
+    fn delete_file(&self, path: &Path) -> io::Result<()> {
+        Err(unsupported("remove", path))
+    }
+
+    fn delete_dir_all(&self, path: &Path) -> io::Result<()> {
+        Err(unsupported("remove", path))
+    }
+
+    fn entry_exists(&self, path: &Path) -> bool {
+        let path = normalize(path);
+        let state = self.state.lock().unwrap();
+        state.files.contains_key(&path) || state.dirs.contains(&path) || self.base.exists(&path)
+    }
+
+    fn dir_exists(&self, path: &Path) -> bool {
+        let path = normalize(path);
+        self.is_dir_in(&self.state.lock().unwrap(), &path)
+    }
+
+    fn walk_tree(&self, root: &Path) -> io::Result<Vec<WalkEntry>> {
+        let mut entries = if self.base.is_dir(root) { self.base.walk(root)? } else { Vec::new() };
+
+        // Add planned entries below the root that do not exist yet
+        let normalized_root = normalize(root);
+        let state = self.state.lock().unwrap();
+        let planned = state.dirs.iter().map(|path| (path, true))
+            .chain(state.files.keys().map(|path| (path, false)));
+        for (path, is_dir) in planned {
+            if let Ok(relative) = path.strip_prefix(&normalized_root) {
+                if !relative.as_os_str().is_empty() && !self.base.exists(path) {
+                    entries.push(WalkEntry { path: root.join(relative), is_dir });
+                }
+            }
+        }
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(entries)
+    }
+
+    fn clone_repo(&self, url: &str, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut state = self.state.lock().unwrap();
+        state.operations.push(PlannedOperation::GitClone { url: url.to_string(), path: path.clone() });
+        state.dirs.insert(path);
+        Ok(())
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        self.base.canonicalize(path)
+    }
+}
+
+/// Generators only create and write; moving or removing entries is not part of a plan
+fn unsupported(operation: &str, path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("Cannot {} {} while recording a plan", operation, path.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+    use crate::code_generation::core::generator::CodeGenerator;
+    use crate::project_management::config::models::*;
+    use crate::shared::utils::file_system::RealFs;
+
+    fn rust_project(files: &[&str]) -> Project {
+        Project {
+            name: "demo".to_string(),
+            root: true,
+            lang: "rust".to_string(),
+            tree: vec![Module {
+                name: Some("src".to_string()),
+                file: files.iter().map(|name| CodeFile { name: name.to_string(), ..Default::default() }).collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_does_not_touch_filesystem() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = rust_project(&["main", "user"]);
+
+        let plan = Plan::record(&RealFs, |fs| CodeGenerator::generate_single_project(fs, base_path, &project)).unwrap();
+
+        assert!(!base_path.join("src").exists());
+        assert!(!base_path.join("Cargo.toml").exists());
+        assert!(plan.operations().contains(&PlannedOperation::CreateDir { path: base_path.join("src") }));
+        assert!(plan.operations().iter().any(|op| matches!(op,
+            PlannedOperation::CreateFile { path, .. } if path == &base_path.join("src/user.rs"))));
+
+        // Files written several times during generation are planned once, with their final content
+        let main_rs: Vec<_> = plan.operations().iter()
+            .filter(|op| op.path() == base_path.join("src/main.rs"))
+            .collect();
+        assert_eq!(main_rs.len(), 1);
+        match main_rs[0] {
+            PlannedOperation::CreateFile { content, .. } => assert!(content.contains("mod user;")),
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_record_managed_section_rewrite() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        CodeGenerator::generate_single_project(&RealFs, base_path, &rust_project(&["main", "user"])).unwrap();
+        let original = fs::read_to_string(base_path.join("src/main.rs")).unwrap();
+
+        let plan = Plan::record(&RealFs, |fs| {
+            CodeGenerator::generate_single_project(fs, base_path, &rust_project(&["main", "user", "order"]))
+        }).unwrap();
+
+        assert_eq!(fs::read_to_string(base_path.join("src/main.rs")).unwrap(), original);
+        assert!(!base_path.join("src/order.rs").exists());
+        match plan.operations().iter().find(|op| op.path() == base_path.join("src/main.rs")) {
+            Some(PlannedOperation::UpdateFile { old, new, .. }) => {
+                assert_eq!(old, &original);
+                assert!(new.contains("mod order;"));
+            }
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_record_up_to_date_tree_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = rust_project(&["main", "user"]);
+        CodeGenerator::generate_single_project(&RealFs, base_path, &project).unwrap();
+
+        let plan = Plan::record(&RealFs, |fs| CodeGenerator::generate_single_project(fs, base_path, &project)).unwrap();
+
+        assert!(plan.is_empty(), "unexpected operations: {:?}", plan.operations());
+    }
+
+    #[test]
+    fn test_record_detects_drift() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = rust_project(&["main", "user"]);
+        CodeGenerator::generate_single_project(&RealFs, base_path, &project).unwrap();
+
+        // Edits outside the managed section are not drift
+        let main_rs = base_path.join("src/main.rs");
+        let edited = format!("{}\nfn main() {{}}\n", fs::read_to_string(&main_rs).unwrap());
+        fs::write(&main_rs, &edited).unwrap();
+        fs::remove_file(base_path.join("Cargo.toml")).unwrap();
+        fs::remove_file(base_path.join("src/user.rs")).unwrap();
+
+        let plan = Plan::record(&RealFs, |fs| CodeGenerator::generate_single_project(fs, base_path, &project)).unwrap();
+
+        let paths: Vec<_> = plan.operations().iter().map(|op| op.path().to_path_buf()).collect();
+        assert_eq!(paths.len(), 2, "unexpected operations: {:?}", plan.operations());
+        assert!(paths.contains(&base_path.join("Cargo.toml")));
+        assert!(paths.contains(&base_path.join("src/user.rs")));
+
+        // A hand-edited managed section is stale
+        fs::write(&main_rs, edited.replace("mod user;", "mod users;")).unwrap();
+        let plan = Plan::record(&RealFs, |fs| CodeGenerator::generate_single_project(fs, base_path, &project)).unwrap();
+        assert!(plan.operations().iter().any(|op| matches!(op,
+            PlannedOperation::UpdateFile { path, .. } if path == &main_rs)));
+    }
+
+    #[test]
+    fn test_record_git_clone() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("vendor");
+
+        let plan = Plan::record(&RealFs, |fs| {
+            assert!(!fs.is_real());
+            fs.git_clone("https://example.com/repo.git", &target)?;
+            assert!(fs.is_dir(&target));
+            Ok(())
+        }).unwrap();
+
+        assert!(!target.exists());
+        assert_eq!(plan.operations(), &[PlannedOperation::GitClone {
+            url: "https://example.com/repo.git".to_string(),
+            path: target,
+        }]);
+    }
+
+    #[test]
+    fn test_apply_plan() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = rust_project(&["main", "user"]);
+
+        let plan = Plan::record(&RealFs, |fs| CodeGenerator::generate_single_project(fs, base_path, &project)).unwrap();
+        plan.apply(&RealFs).unwrap();
+
+        assert!(base_path.join("src/user.rs").exists());
+        let replanned = Plan::record(&RealFs, |fs| CodeGenerator::generate_single_project(fs, base_path, &project)).unwrap();
+        assert!(replanned.is_empty(), "unexpected operations: {:?}", replanned.operations());
+    }
+}
@@ -0,0 +1,225 @@
+use std::path::{Path, PathBuf};
+use anyhow::{anyhow, Context, Result};
+use crate::code_generation::core::plan::{Plan, PlannedOperation};
+use crate::shared::utils::file_system::Fs;
+
+/// Step that undoes part of a partially applied plan
+#[derive(Debug)]
+enum Undo {
+    RemoveFile(PathBuf),
+    RemoveDir(PathBuf),
+    Restore { path: PathBuf, content: String },
+}
+
+/// All-or-nothing application of a plan
+///
+/// Every file is first staged next to its target, then all staged files are renamed into
+/// place. On any error the journal is replayed backwards: created files and directories are
+/// removed and rewritten files get their original content back.
+pub struct Transaction<'a> {
+    fs: &'a dyn Fs,
+    journal: Vec<Undo>,
+}
+
+impl<'a> Transaction<'a> {
+    /// Apply a plan to a filesystem backend, rolling back on failure
+    pub fn commit(fs: &'a dyn Fs, plan: &Plan) -> Result<()> {
+        let mut transaction = Transaction { fs, journal: Vec::new() };
+        let error = match transaction.run(plan) {
+            Ok(()) => return Ok(()),
+            Err(error) => error,
+        };
+
+        let leftovers = transaction.rollback();
+        if leftovers.is_empty() {
+            Err(error.context("Generation failed; all changes were rolled back"))
+        } else {
+            Err(error.context(format!(
+                "Generation failed and these paths could not be rolled back: {}",
+                leftovers.join(", ")
+            )))
+        }
+    }
+
+    fn run(&mut self, plan: &Plan) -> Result<()> {
+        // Stage: create directories, clone repositories and write every file beside its target
+        let mut staged = Vec::new();
+        for operation in plan.operations() {
+            match operation {
+                PlannedOperation::CreateDir { path } => {
+                    self.create_dir_all(path)?;
+                }
+                PlannedOperation::CreateFile { path, content }
+                | PlannedOperation::UpdateFile { path, new: content, .. } => {
+                    let staging_path = Self::staging_path(path)?;
+                    self.fs.write(&staging_path, content)
+                        .with_context(|| format!("Failed to write file: {}", path.display()))?;
+                    self.journal.push(Undo::RemoveFile(staging_path.clone()));
+                    staged.push((staging_path, path));
+                }
+                PlannedOperation::GitClone { url, path } => {
+                    self.git_clone(url, path);
+                }
+            }
+        }
+
+        // Commit: move the staged files into place
+        for (staging_path, path) in staged {
+            let original = if self.fs.exists(path) {
+                Some(self.fs.read_to_string(path)
+                    .with_context(|| format!("Failed to read file: {}", path.display()))?)
+            } else {
+                None
+            };
+            self.fs.rename(&staging_path, path)
+                .with_context(|| format!("Failed to write file: {}", path.display()))?;
+
+            // The staged file is gone; undoing now means restoring or removing the target
+            self.journal.retain(|undo| !matches!(undo, Undo::RemoveFile(p) if p == &staging_path));
+            self.journal.push(match original {
+                Some(content) => Undo::Restore { path: path.clone(), content },
+                None => Undo::RemoveFile(path.clone()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Create a directory, remembering the outermost directory that did not exist
+    fn create_dir_all(&mut self, path: &Path) -> Result<()> {
+        let outermost_missing = path.ancestors()
+            .take_while(|ancestor| !ancestor.as_os_str().is_empty() && !self.fs.exists(ancestor))
+            .last()
+            .map(Path::to_path_buf);
+
+        self.fs.create_dir_all(path)
+            .with_context(|| format!("Failed to create directory: {}", path.display()))?;
+
+        if let Some(created) = outermost_missing {
+            self.journal.push(Undo::RemoveDir(created));
+        }
+        Ok(())
+    }
+
+    /// Clone a repository; like `moli up` always has, a failed clone is reported and skipped
+    fn git_clone(&mut self, url: &str, path: &Path) {
+        let on_disk = self.fs.is_real();
+        if on_disk {
+            eprintln!("🔄 Cloning repository: {} -> {}", url, path.display());
+        }
+        match self.fs.git_clone(url, path) {
+            Ok(()) => {
+                if on_disk {
+                    eprintln!("✅ Successfully cloned: {}", path.display());
+                }
+                self.journal.push(Undo::RemoveDir(path.to_path_buf()));
+            }
+            Err(e) => {
+                eprintln!("❌ Failed to clone {}: {}", path.display(), e);
+                eprintln!("⚠️  Continuing with remaining operations...");
+            }
+        }
+    }
+
+    /// Undo every journaled step, newest first, returning the paths that could not be restored
+    fn rollback(&mut self) -> Vec<String> {
+        let mut leftovers = Vec::new();
+        while let Some(undo) = self.journal.pop() {
+            let (path, result) = match &undo {
+                Undo::RemoveFile(path) => (path, self.fs.remove_file(path)),
+                Undo::RemoveDir(path) => (path, self.fs.remove_dir_all(path)),
+                Undo::Restore { path, content } => (path, self.fs.write(path, content)),
+            };
+            if result.is_err() && self.fs.exists(path) {
+                leftovers.push(path.display().to_string());
+            }
+        }
+        leftovers
+    }
+
+    /// Hidden sibling the content is staged in before being renamed over the target
+    fn staging_path(path: &Path) -> Result<PathBuf> {
+        let file_name = path.file_name()
+            .ok_or_else(|| anyhow!("Invalid file path: {}", path.display()))?;
+        Ok(path.with_file_name(format!(".{}.moli-tmp", file_name.to_string_lossy())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::memory_fs::MemoryFs;
+
+    #[test]
+    fn test_commit_applies_plan() {
+        let memory = MemoryFs::new().with_file("src/lib.rs", "old\n");
+        let plan = Plan::new(vec![
+            PlannedOperation::CreateDir { path: PathBuf::from("src/core") },
+            PlannedOperation::CreateFile { path: PathBuf::from("src/core/mod.rs"), content: "mod.rs\n".to_string() },
+            PlannedOperation::UpdateFile {
+                path: PathBuf::from("src/lib.rs"),
+                old: "old\n".to_string(),
+                new: "new\n".to_string(),
+            },
+        ]);
+
+        Transaction::commit(&memory, &plan).unwrap();
+
+        let files = memory.files();
+        assert_eq!(files.len(), 2, "staged files must not be left behind: {:?}", files);
+        assert_eq!(files[&PathBuf::from("src/core/mod.rs")], "mod.rs\n");
+        assert_eq!(files[&PathBuf::from("src/lib.rs")], "new\n");
+    }
+
+    #[test]
+    fn test_commit_rolls_back_on_staging_failure() {
+        let memory = MemoryFs::new()
+            .with_file("src/lib.rs", "old\n")
+            .with_file("blocker", "");
+        let plan = Plan::new(vec![
+            PlannedOperation::CreateDir { path: PathBuf::from("app/core") },
+            PlannedOperation::CreateFile { path: PathBuf::from("app/core/main.rs"), content: String::new() },
+            PlannedOperation::UpdateFile {
+                path: PathBuf::from("src/lib.rs"),
+                old: "old\n".to_string(),
+                new: "new\n".to_string(),
+            },
+            // The parent is a file, so staging this write fails
+            PlannedOperation::CreateFile { path: PathBuf::from("blocker/file.rs"), content: String::new() },
+        ]);
+
+        let result = Transaction::commit(&memory, &plan);
+
+        assert!(format!("{:#}", result.unwrap_err()).contains("rolled back"));
+        assert_eq!(memory.dirs(), vec![PathBuf::from("src")]);
+        assert_eq!(memory.files().into_iter().collect::<Vec<_>>(), vec![
+            (PathBuf::from("blocker"), String::new()),
+            (PathBuf::from("src/lib.rs"), "old\n".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_commit_restores_renamed_files_on_commit_failure() {
+        let memory = MemoryFs::new()
+            .with_file("src/lib.rs", "old\n")
+            .with_file("taken/keep.txt", "");
+        let plan = Plan::new(vec![
+            PlannedOperation::CreateFile { path: PathBuf::from("src/new.rs"), content: String::new() },
+            PlannedOperation::UpdateFile {
+                path: PathBuf::from("src/lib.rs"),
+                old: "old\n".to_string(),
+                new: "new\n".to_string(),
+            },
+            // Staging succeeds, but a directory cannot be replaced by a file
+            PlannedOperation::CreateFile { path: PathBuf::from("taken"), content: String::new() },
+        ]);
+
+        let result = Transaction::commit(&memory, &plan);
+
+        assert!(result.is_err());
+        assert_eq!(memory.files().into_iter().collect::<Vec<_>>(), vec![
+            (PathBuf::from("src/lib.rs"), "old\n".to_string()),
+            (PathBuf::from("taken/keep.txt"), String::new()),
+        ]);
+    }
+}
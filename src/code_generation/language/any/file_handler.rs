@@ -1,4 +1,4 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::Path;
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Project, Module};
@@ -12,6 +12,7 @@ impl AnyFileHandler {
     /// - No module management files (no mod.rs, index.ts, __init__.py, etc.)
     /// - Only generates files specified in codefile with their exact extensions
     pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         project: &Project,
     ) -> Result<()> {
@@ -31,15 +32,15 @@ impl AnyFileHandler {
             let file_path = project_path.join(&filename);
 
             // Only create file if it doesn't already exist
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
         }
 
         // Generate module structure (directories and files)
         for module in project.tree() {
-            Self::generate_module(project_path, module)?;
+            Self::generate_module(fs, project_path, module)?;
         }
 
         Ok(())
@@ -47,6 +48,7 @@ impl AnyFileHandler {
 
     /// Generate module structure recursively
     fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
         parent_path: P,
         module: &Module,
     ) -> Result<()> {
@@ -57,31 +59,24 @@ impl AnyFileHandler {
         // If this is a git clone target
         if let Some(git_url) = module.from.as_ref() {
             // Check if directory already exists
-            if module_path.exists() {
+            if fs.exists(&module_path) {
                 eprintln!("⚠️  Directory already exists, skipping clone: {}", module_path.display());
                 return Ok(());
             }
 
-            // Execute git clone
-            eprintln!("🔄 Cloning repository: {} -> {}", git_url, module_name);
-            let output = std::process::Command::new("git")
-                .arg("clone")
-                .arg(git_url)
-                .arg(&module_path)
-                .output();
-
-            match output {
-                Ok(result) if result.status.success() => {
-                    eprintln!("✅ Successfully cloned: {}", module_name);
-                }
-                Ok(result) => {
-                    eprintln!("❌ Failed to clone {}: {}",
-                        module_name,
-                        String::from_utf8_lossy(&result.stderr));
-                    eprintln!("⚠️  Continuing with remaining operations...");
+            // Execute git clone (dry runs and in-memory trees only record it)
+            let on_disk = fs.is_real();
+            if on_disk {
+                eprintln!("🔄 Cloning repository: {} -> {}", git_url, module_name);
+            }
+            match fs.git_clone(git_url, &module_path) {
+                Ok(()) => {
+                    if on_disk {
+                        eprintln!("✅ Successfully cloned: {}", module_name);
+                    }
                 }
                 Err(e) => {
-                    eprintln!("❌ Failed to execute git clone for {}: {}", module_name, e);
+                    eprintln!("❌ Failed to clone {}: {}", module_name, e);
                     eprintln!("⚠️  Continuing with remaining operations...");
                 }
             }
@@ -91,7 +86,7 @@ impl AnyFileHandler {
         }
 
         // Create module directory
-        fs::create_dir_all(&module_path)
+        fs.create_dir_all(&module_path)
             .with_context(|| format!("Failed to create module directory: {}", module_path.display()))?;
 
         // Generate code files in this module (only if they don't exist)
@@ -104,15 +99,15 @@ impl AnyFileHandler {
 
             let file_path = module_path.join(&filename);
 
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
         }
 
         // Recursively generate submodules
         for submodule in module.subtree() {
-            Self::generate_module(&module_path, submodule)?;
+            Self::generate_module(fs, &module_path, submodule)?;
         }
 
         Ok(())
@@ -0,0 +1,30 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::Project;
+use crate::code_generation::language::any::file_handler::AnyFileHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Language-agnostic projects whose files keep the names written in moli.yml
+pub struct AnyGenerator;
+
+impl LanguageGenerator for AnyGenerator {
+    fn name(&self) -> &str {
+        "any"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        None
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        AnyFileHandler::generate_project(fs, project_path, project)
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Any language: root-level files with specified extensions
+        r#"  file:
+    - name: README.md
+"#.to_string()
+    }
+}
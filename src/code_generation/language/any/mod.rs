@@ -1,4 +1,5 @@
 // start auto exported by moli.
 pub mod file_handler;
+pub mod generator;
 // end auto exported by moli.
 
@@ -0,0 +1,148 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, Project};
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+use super::source_generator::CSourceGenerator;
+
+/// CMake handler for generating C/C++ project structure
+pub struct CMakeHandler;
+
+impl CMakeHandler {
+    /// Generate complete C/C++ project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate root CMakeLists.txt (project/target definition only once)
+        let cmake_lists_path = project_path.join("CMakeLists.txt");
+        if !fs.exists(&cmake_lists_path) {
+            fs.write(&cmake_lists_path, Self::generate_root_cmake_lists_content(fs, project_path, project))
+                .with_context(|| format!("Failed to create CMakeLists.txt: {}", cmake_lists_path.display()))?;
+        }
+
+        // Generate project-level code files
+        for codefile in project.files() {
+            CSourceGenerator::generate_file(fs, project_path, codefile, &[], project)?;
+        }
+
+        // Generate module structure
+        for module in project.tree() {
+            CSourceGenerator::generate_module(fs, project_path, module, &[], project)?;
+        }
+
+        // Maintain project-level sources and add_subdirectory for each top-level module
+        let statements = CSourceGenerator::generate_cmake_statements(project.files(), project.tree(), project.language());
+        ContentUpdater::update_cmake_lists(fs, &cmake_lists_path, &statements)?;
+
+        Ok(())
+    }
+
+    /// Check if the project builds an executable (declares a `main` file anywhere)
+    fn is_executable(project: &Project) -> bool {
+        project.files().iter().any(|f| f.name() == "main")
+            || project.tree().iter().any(Self::module_has_main)
+    }
+
+    fn module_has_main(module: &Module) -> bool {
+        module.files().iter().any(|f| f.name() == "main")
+            || module.subtree().iter().any(Self::module_has_main)
+    }
+
+    /// Get a CMake project name (last path segment, directory name for ".")
+    fn cmake_project_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        let name = if project.name() == "." {
+            paths::directory_name(fs, project_path, "app")
+        } else {
+            project.name().rsplit('/').next().unwrap_or(project.name()).to_string()
+        };
+
+        name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+            .collect()
+    }
+
+    /// Generate root CMakeLists.txt content with an empty managed section
+    fn generate_root_cmake_lists_content(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        let (cmake_language, standard) = if project.language() == "cpp" {
+            ("CXX", "set(CMAKE_CXX_STANDARD 17)\nset(CMAKE_CXX_STANDARD_REQUIRED ON)")
+        } else {
+            ("C", "set(CMAKE_C_STANDARD 11)\nset(CMAKE_C_STANDARD_REQUIRED ON)")
+        };
+
+        let target = if Self::is_executable(project) {
+            "add_executable(${PROJECT_NAME})"
+        } else {
+            "add_library(${PROJECT_NAME})"
+        };
+
+        format!(
+            r#"cmake_minimum_required(VERSION 3.16)
+project({} LANGUAGES {})
+
+{}
+
+{}
+
+# start auto exported by moli.
+# end auto exported by moli.
+"#,
+            Self::cmake_project_name(fs, project_path, project),
+            cmake_language,
+            standard,
+            target
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::CodeFile;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = Project {
+            name: "native/engine".to_string(),
+            lang: "cpp".to_string(),
+            tree: vec![
+                Module {
+                    name: Some("src".to_string()),
+                    file: vec![CodeFile { name: "main".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+                Module {
+                    name: Some("render".to_string()),
+                    file: vec![CodeFile { name: "mesh".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        CMakeHandler::generate_project(&RealFs, base_path, &project).unwrap();
+        CMakeHandler::generate_project(&RealFs, base_path, &project).unwrap();
+
+        let cmake_lists = fs::read_to_string(base_path.join("CMakeLists.txt")).unwrap();
+        assert!(cmake_lists.starts_with("cmake_minimum_required(VERSION 3.16)\nproject(engine LANGUAGES CXX)"));
+        assert!(cmake_lists.contains("add_executable(${PROJECT_NAME})"));
+        assert!(cmake_lists.contains(
+            "# start auto exported by moli.\nadd_subdirectory(src)\nadd_subdirectory(render)\n# end auto exported by moli."
+        ));
+        assert_eq!(cmake_lists.matches("add_subdirectory(src)").count(), 1);
+        assert!(base_path.join("render/mesh.hpp").exists());
+    }
+}
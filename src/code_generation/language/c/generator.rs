@@ -0,0 +1,59 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::Project;
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::c::cmake_handler::CMakeHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// C and C++ projects with header/source pairs and a managed CMakeLists.txt
+pub struct CGenerator {
+    language: &'static str,
+}
+
+impl CGenerator {
+    /// Generator for `lang: c`
+    pub fn c() -> Self {
+        Self { language: "c" }
+    }
+
+    /// Generator for `lang: cpp`
+    pub fn cpp() -> Self {
+        Self { language: "cpp" }
+    }
+}
+
+impl LanguageGenerator for CGenerator {
+    fn name(&self) -> &str {
+        self.language
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some(self.language)
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["guard"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        CMakeHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if let Some(guard) = project.guard_setting() {
+            if !matches!(guard, "ifndef" | "pragma") {
+                errors.push(ValidationError {
+                    message: format!("Unsupported header guard style: {} (expected ifndef or pragma)", guard),
+                    path: format!("{}.guard", path),
+                });
+            }
+        }
+        errors
+    }
+
+    fn managed_files(&self) -> Vec<&str> {
+        vec!["CMakeLists.txt"]
+    }
+}
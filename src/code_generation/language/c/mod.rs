@@ -0,0 +1,5 @@
+// start auto exported by moli.
+pub mod source_generator;
+pub mod cmake_handler;
+pub mod generator;
+// end auto exported by moli.
@@ -0,0 +1,227 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile, Project};
+use crate::shared::utils::content_updater::ContentUpdater;
+
+/// C/C++ source generator (shared by `lang: c` and `lang: cpp`)
+pub struct CSourceGenerator;
+
+impl CSourceGenerator {
+    /// Check if a file is a C/C++ header file
+    fn is_header_file(filename: &str) -> bool {
+        filename.ends_with(".h") || filename.ends_with(".hpp") || filename.ends_with(".hh")
+    }
+
+    /// Generate C/C++ module structure recursively with a CMakeLists.txt per module
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+        project: &Project,
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module_name);
+
+        // Generate source/header pairs in this module
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, &module_segments, project)?;
+        }
+
+        // Process submodules recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &module_segments, project)?;
+        }
+
+        // Maintain target_sources/add_subdirectory in this module's CMakeLists.txt
+        let cmake_lists_path = module_path.join("CMakeLists.txt");
+        let statements = Self::generate_cmake_statements(module.files(), module.subtree(), project.language());
+        ContentUpdater::update_cmake_lists(fs, &cmake_lists_path, &statements)?;
+
+        Ok(())
+    }
+
+    /// Create a source file and its paired header (only if they don't exist)
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        module_segments: &[String],
+        project: &Project,
+    ) -> Result<()> {
+        let dir_path = dir_path.as_ref();
+        let language = project.language();
+        let filename = codefile.filename_with_extension(language);
+        let header_filename = Self::header_filename(codefile, language);
+
+        let file_path = dir_path.join(&filename);
+        if !fs.exists(&file_path) {
+            let content = if Self::is_header_file(&filename) {
+                Self::generate_header_content(&filename, module_segments, project)
+            } else if let Some(header) = &header_filename {
+                format!("#include \"{}\"\n", header)
+            } else {
+                String::new()
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        if let Some(header) = header_filename {
+            let header_path = dir_path.join(&header);
+            if !fs.exists(&header_path) {
+                fs.write(&header_path, Self::generate_header_content(&header, module_segments, project))
+                    .with_context(|| format!("Failed to create file: {}", header_path.display()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the paired header for a file declared without an extension ("main" has none)
+    pub fn header_filename(codefile: &CodeFile, language: &str) -> Option<String> {
+        if codefile.name().contains('.') || codefile.name() == "main" {
+            return None;
+        }
+
+        let extension = if language == "cpp" { "hpp" } else { "h" };
+        Some(format!("{}.{}", codefile.name(), extension))
+    }
+
+    /// Get the header guard style (defaults to "ifndef" for C and "pragma" for C++)
+    pub fn guard_style(project: &Project) -> &str {
+        project.guard_setting()
+            .unwrap_or(if project.language() == "cpp" { "pragma" } else { "ifndef" })
+    }
+
+    /// Build the CMake statements for a directory: one target_sources list and add_subdirectory per module
+    pub fn generate_cmake_statements(files: &[CodeFile], submodules: &[Module], language: &str) -> Vec<String> {
+        let mut statements = Vec::new();
+
+        let sources: Vec<String> = files.iter()
+            .flat_map(|codefile| {
+                std::iter::once(codefile.filename_with_extension(language))
+                    .chain(Self::header_filename(codefile, language))
+            })
+            .collect();
+
+        if !sources.is_empty() {
+            statements.push("target_sources(${PROJECT_NAME} PRIVATE".to_string());
+            statements.extend(sources.iter().map(|source| format!("    {}", source)));
+            statements.push(")".to_string());
+        }
+
+        statements.extend(submodules.iter().map(|submodule| format!("add_subdirectory({})", submodule.name())));
+
+        statements
+    }
+
+    /// Generate header content with an include guard or #pragma once
+    fn generate_header_content(header_filename: &str, module_segments: &[String], project: &Project) -> String {
+        if Self::guard_style(project) == "pragma" {
+            return "#pragma once\n\n".to_string();
+        }
+
+        let macro_name = Self::guard_macro(header_filename, module_segments);
+        format!("#ifndef {0}\n#define {0}\n\n#endif /* {0} */\n", macro_name)
+    }
+
+    /// Build an include guard macro from the header's path (e.g. src/net/socket.h -> SRC_NET_SOCKET_H)
+    fn guard_macro(header_filename: &str, module_segments: &[String]) -> String {
+        module_segments.iter()
+            .map(|segment| segment.as_str())
+            .chain(std::iter::once(header_filename))
+            .collect::<Vec<_>>()
+            .join("_")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project(lang: &str) -> Project {
+        Project {
+            name: "engine".to_string(),
+            lang: lang.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn make_module() -> Module {
+        Module {
+            name: Some("src".to_string()),
+            file: vec![
+                CodeFile { name: "main".to_string(), ..Default::default() },
+                CodeFile { name: "engine".to_string(), ..Default::default() },
+            ],
+            tree: vec![Module {
+                name: Some("net".to_string()),
+                file: vec![CodeFile { name: "socket".to_string(), ..Default::default() }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_c_module() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = make_project("c");
+
+        CSourceGenerator::generate_module(&RealFs, base_path, &make_module(), &[], &project).unwrap();
+
+        assert_eq!(fs::read_to_string(base_path.join("src/main.c")).unwrap(), "");
+        assert!(!base_path.join("src/main.h").exists());
+        assert_eq!(fs::read_to_string(base_path.join("src/net/socket.c")).unwrap(), "#include \"socket.h\"\n");
+        assert_eq!(
+            fs::read_to_string(base_path.join("src/net/socket.h")).unwrap(),
+            "#ifndef SRC_NET_SOCKET_H\n#define SRC_NET_SOCKET_H\n\n#endif /* SRC_NET_SOCKET_H */\n"
+        );
+
+        let cmake_lists = fs::read_to_string(base_path.join("src/CMakeLists.txt")).unwrap();
+        assert!(cmake_lists.contains(
+            "# start auto exported by moli.\ntarget_sources(${PROJECT_NAME} PRIVATE\n    main.c\n    engine.c\n    engine.h\n)\nadd_subdirectory(net)\n# end auto exported by moli."
+        ));
+        assert!(base_path.join("src/net/CMakeLists.txt").exists());
+    }
+
+    #[test]
+    fn test_generate_cpp_module() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = make_project("cpp");
+
+        CSourceGenerator::generate_module(&RealFs, base_path, &make_module(), &[], &project).unwrap();
+
+        assert_eq!(fs::read_to_string(base_path.join("src/engine.hpp")).unwrap(), "#pragma once\n\n");
+        assert_eq!(fs::read_to_string(base_path.join("src/engine.cpp")).unwrap(), "#include \"engine.hpp\"\n");
+
+        // Custom content outside the managed section survives regeneration
+        let cmake_lists_path = base_path.join("src/CMakeLists.txt");
+        let custom = format!("{}\ntarget_compile_definitions(${{PROJECT_NAME}} PRIVATE DEBUG)\n", fs::read_to_string(&cmake_lists_path).unwrap());
+        fs::write(&cmake_lists_path, custom).unwrap();
+        CSourceGenerator::generate_module(&RealFs, base_path, &make_module(), &[], &project).unwrap();
+
+        let cmake_lists = fs::read_to_string(&cmake_lists_path).unwrap();
+        assert!(cmake_lists.contains("target_compile_definitions(${PROJECT_NAME} PRIVATE DEBUG)"));
+        assert!(cmake_lists.contains("target_sources(${PROJECT_NAME} PRIVATE"));
+        assert_eq!(cmake_lists.matches("target_sources(").count(), 1);
+    }
+}
@@ -0,0 +1,50 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::code_generation::language::csharp::project_handler::CSharpProjectHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// C# projects with namespaces, .csproj and a workspace .sln
+pub struct CSharpGenerator;
+
+impl LanguageGenerator for CSharpGenerator {
+    fn name(&self) -> &str {
+        "csharp"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("cs")
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        CSharpProjectHandler::generate_project(fs, project_path, project)
+    }
+
+    fn generate_workspace(&self, fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        if CSharpProjectHandler::should_generate_solution(config) {
+            CSharpProjectHandler::generate_solution(fs, workspace_path, config)?;
+        }
+        Ok(())
+    }
+
+    fn link_project(&self, fs: &dyn Fs, workspace_path: &Path, project: &Project, config: &MoliConfig) -> Result<()> {
+        // Map depends_on to <ProjectReference> items
+        CSharpProjectHandler::update_project_references(fs, workspace_path, project, config)
+    }
+
+    fn excluded_extensions(&self) -> Vec<&str> {
+        vec![".csproj", ".sln"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["bin", "obj"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // .NET standard: Program.cs next to the .csproj
+        r#"  file:
+    - name: Program
+"#.to_string()
+    }
+}
@@ -0,0 +1,133 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile};
+
+/// C#-specific namespace generator
+pub struct CSharpNamespaceGenerator;
+
+impl CSharpNamespaceGenerator {
+    /// Check if a file is a C# code file (ends with .cs)
+    fn is_csharp_code_file(filename: &str) -> bool {
+        filename.ends_with(".cs")
+    }
+
+    /// Generate C# directory structure recursively
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_namespace: &str,
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        // Each directory adds a namespace segment (Models -> Acme.Api.Models)
+        let namespace = format!("{}.{}", parent_namespace, Self::sanitize_identifier(&module_name));
+
+        // Generate code files in this namespace
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, &namespace)?;
+        }
+
+        // Process subdirectories recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &namespace)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a code file with a file-scoped namespace and class skeleton (only if it doesn't exist)
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        namespace: &str,
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("csharp");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            let content = if filename == "Program.cs" {
+                // Top-level statements cannot follow a namespace declaration
+                Self::generate_program_content()
+            } else if Self::is_csharp_code_file(&filename) {
+                let class_name = filename.trim_end_matches(".cs");
+                Self::generate_csharp_file_content(namespace, class_name)
+            } else {
+                String::new() // Non-C# files get no content
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Make a name usable as a namespace segment (invalid characters become "_")
+    pub fn sanitize_identifier(name: &str) -> String {
+        let result: String = name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+            .collect();
+
+        if result.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+            format!("_{}", result)
+        } else {
+            result
+        }
+    }
+
+    /// Generate C# file content
+    fn generate_csharp_file_content(namespace: &str, class_name: &str) -> String {
+        format!("namespace {};\n\npublic class {}\n{{\n}}\n", namespace, class_name)
+    }
+
+    /// Generate Program.cs content
+    fn generate_program_content() -> String {
+        "Console.WriteLine(\"Hello, world!\");\n".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_module() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let module = Module {
+            name: Some("Models".to_string()),
+            tree: vec![Module {
+                name: Some("order-items".to_string()),
+                file: vec![CodeFile { name: "LineItem".to_string(), ..Default::default() }],
+                ..Default::default()
+            }],
+            file: vec![
+                CodeFile { name: "User".to_string(), ..Default::default() },
+                CodeFile { name: "seed.json".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        CSharpNamespaceGenerator::generate_module(&RealFs, base_path, &module, "Acme.Api").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(base_path.join("Models/User.cs")).unwrap(),
+            "namespace Acme.Api.Models;\n\npublic class User\n{\n}\n"
+        );
+        assert!(fs::read_to_string(base_path.join("Models/order-items/LineItem.cs")).unwrap()
+            .starts_with("namespace Acme.Api.Models.order_items;"));
+        assert_eq!(fs::read_to_string(base_path.join("Models/seed.json")).unwrap(), "");
+    }
+}
@@ -0,0 +1,337 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{MoliConfig, Module, Project};
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+use super::namespace_generator::CSharpNamespaceGenerator;
+
+/// SDK-style C# project type GUID used in solution files
+const CSHARP_PROJECT_TYPE_GUID: &str = "9A19103F-16F7-4668-BE54-9A1E7A4F7556";
+
+/// .NET project handler for generating C# projects, .csproj and .sln files
+pub struct CSharpProjectHandler;
+
+impl CSharpProjectHandler {
+    /// Generate complete C# project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        let assembly_name = Self::assembly_name(fs, project_path, project);
+        let root_namespace = Self::root_namespace(&assembly_name);
+
+        // Generate <Name>.csproj
+        let csproj_path = project_path.join(format!("{}.csproj", assembly_name));
+        if !fs.exists(&csproj_path) {
+            let content = Self::generate_csproj_content(&root_namespace, Self::is_executable(project));
+            fs.write(&csproj_path, content)
+                .with_context(|| format!("Failed to create .csproj: {}", csproj_path.display()))?;
+        }
+
+        // Generate project-level code files (root namespace)
+        for codefile in project.files() {
+            CSharpNamespaceGenerator::generate_file(fs, project_path, codefile, &root_namespace)?;
+        }
+
+        // Generate directory structure
+        for module in project.tree() {
+            CSharpNamespaceGenerator::generate_module(fs, project_path, module, &root_namespace)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the assembly (and .csproj) name: last path segment, directory name for "."
+    pub fn assembly_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        if project.name() == "." {
+            return paths::directory_name(fs, project_path, "App");
+        }
+
+        project.name()
+            .rsplit('/')
+            .next()
+            .unwrap_or(project.name())
+            .to_string()
+    }
+
+    /// Derive the root namespace from the assembly name (my-api -> my_api, Acme.Api stays dotted)
+    pub fn root_namespace(assembly_name: &str) -> String {
+        assembly_name.split('.')
+            .map(CSharpNamespaceGenerator::sanitize_identifier)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Check if the project is an executable (declares a Program file anywhere)
+    fn is_executable(project: &Project) -> bool {
+        project.files().iter().any(|f| f.name() == "Program" || f.name() == "Program.cs")
+            || project.tree().iter().any(Self::module_has_program)
+    }
+
+    fn module_has_program(module: &Module) -> bool {
+        module.files().iter().any(|f| f.name() == "Program" || f.name() == "Program.cs")
+            || module.subtree().iter().any(Self::module_has_program)
+    }
+
+    /// Get the .csproj path of a project relative to the workspace (backslash-separated, as in .sln files)
+    fn csproj_relative_path(fs: &dyn Fs, workspace_path: &Path, project: &Project) -> String {
+        let assembly_name = Self::assembly_name(fs, &workspace_path.join(project.name()), project);
+
+        if project.name() == "." {
+            format!("{}.csproj", assembly_name)
+        } else {
+            format!("{}\\{}.csproj", project.name().replace('/', "\\"), assembly_name)
+        }
+    }
+
+    /// Maintain `<ProjectReference>` items for the C# projects listed in `depends_on`
+    pub fn update_project_references<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        workspace_path: P,
+        project: &Project,
+        config: &MoliConfig,
+    ) -> Result<()> {
+        let workspace_path = workspace_path.as_ref();
+        let project_path = workspace_path.join(project.name());
+        let csproj_path = project_path.join(format!("{}.csproj", Self::assembly_name(fs, &project_path, project)));
+
+        // Relative path from this project back to the workspace root
+        let depth = if project.name() == "." { 0 } else { project.name().split('/').count() };
+        let to_root = "..\\".repeat(depth);
+
+        let references: Vec<String> = project.dependencies()
+            .iter()
+            .filter_map(|dep| config.projects().iter().find(|p| p.name() == dep.as_str() && p.language() == "csharp"))
+            .map(|dep| format!(
+                "    <ProjectReference Include=\"{}{}\" />",
+                to_root,
+                Self::csproj_relative_path(fs, workspace_path, dep)
+            ))
+            .collect();
+
+        let managed_content = if references.is_empty() {
+            String::new()
+        } else {
+            format!("  <ItemGroup>\n{}\n  </ItemGroup>", references.join("\n"))
+        };
+
+        ContentUpdater::update_managed_section(
+            fs,
+            &csproj_path,
+            &managed_content,
+            "  <!-- start auto exported by moli. -->\n",
+            "  <!-- end auto exported by moli. -->",
+        ).with_context(|| format!("Failed to update project references: {}", csproj_path.display()))
+    }
+
+    /// Check if a solution file is needed (multi-project configs with C# projects)
+    pub fn should_generate_solution(config: &MoliConfig) -> bool {
+        config.projects().iter().any(|p| p.language() == "csharp")
+    }
+
+    /// Generate <workspace>.sln with a moli-maintained project list and configuration mapping
+    pub fn generate_solution<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        workspace_path: P,
+        config: &MoliConfig,
+    ) -> Result<()> {
+        let workspace_path = workspace_path.as_ref();
+        let solution_name = Self::assembly_name(fs, workspace_path, &Project { name: ".".to_string(), ..Default::default() });
+        let solution_path = workspace_path.join(format!("{}.sln", solution_name));
+
+        if !fs.exists(&solution_path) {
+            fs.write(&solution_path, Self::generate_solution_header())
+                .with_context(|| format!("Failed to create .sln: {}", solution_path.display()))?;
+        }
+
+        let projects: Vec<(String, String, String)> = config.projects()
+            .iter()
+            .filter(|p| p.language() == "csharp")
+            .map(|p| {
+                let path = Self::csproj_relative_path(fs, workspace_path, p);
+                let name = Self::assembly_name(fs, &workspace_path.join(p.name()), p);
+                (name, path.clone(), Self::project_guid(&path))
+            })
+            .collect();
+
+        ContentUpdater::update_managed_section(
+            fs,
+            &solution_path,
+            &Self::generate_solution_projects_content(&projects),
+            "# start auto exported by moli.\n",
+            "# end auto exported by moli.",
+        ).with_context(|| format!("Failed to update .sln: {}", solution_path.display()))
+    }
+
+    /// Build a stable project GUID from the project path (FNV-1a, so reruns keep the same GUID)
+    fn project_guid(seed: &str) -> String {
+        let hash = |offset: u64| {
+            seed.bytes().fold(offset, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+        };
+        let high = hash(0xcbf29ce484222325);
+        let low = hash(0x84222325cbf29ce4);
+
+        format!(
+            "{:08X}-{:04X}-{:04X}-{:04X}-{:012X}",
+            high >> 32,
+            (high >> 16) & 0xffff,
+            high & 0xffff,
+            low >> 48,
+            low & 0xffff_ffff_ffff
+        )
+    }
+
+    /// Generate the fixed .sln header
+    fn generate_solution_header() -> String {
+        r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+VisualStudioVersion = 17.0.31903.59
+MinimumVisualStudioVersion = 10.0.40219.1
+# start auto exported by moli.
+# end auto exported by moli.
+"#.to_string()
+    }
+
+    /// Generate the managed .sln body: Project entries plus the Global configuration block
+    fn generate_solution_projects_content(projects: &[(String, String, String)]) -> String {
+        let mut lines = Vec::new();
+
+        for (name, path, guid) in projects {
+            lines.push(format!(
+                "Project(\"{{{}}}\") = \"{}\", \"{}\", \"{{{}}}\"",
+                CSHARP_PROJECT_TYPE_GUID, name, path, guid
+            ));
+            lines.push("EndProject".to_string());
+        }
+
+        lines.push("Global".to_string());
+        lines.push("\tGlobalSection(SolutionConfigurationPlatforms) = preSolution".to_string());
+        lines.push("\t\tDebug|Any CPU = Debug|Any CPU".to_string());
+        lines.push("\t\tRelease|Any CPU = Release|Any CPU".to_string());
+        lines.push("\tEndGlobalSection".to_string());
+        lines.push("\tGlobalSection(ProjectConfigurationPlatforms) = postSolution".to_string());
+        for (_, _, guid) in projects {
+            for configuration in ["Debug", "Release"] {
+                lines.push(format!("\t\t{{{}}}.{1}|Any CPU.ActiveCfg = {1}|Any CPU", guid, configuration));
+                lines.push(format!("\t\t{{{}}}.{1}|Any CPU.Build.0 = {1}|Any CPU", guid, configuration));
+            }
+        }
+        lines.push("\tEndGlobalSection".to_string());
+        lines.push("EndGlobal".to_string());
+
+        lines.join("\n")
+    }
+
+    /// Generate .csproj content with an empty managed section for project references
+    fn generate_csproj_content(root_namespace: &str, is_executable: bool) -> String {
+        let output_type = if is_executable {
+            "\n    <OutputType>Exe</OutputType>"
+        } else {
+            ""
+        };
+
+        format!(
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+
+  <PropertyGroup>{}
+    <TargetFramework>net8.0</TargetFramework>
+    <ImplicitUsings>enable</ImplicitUsings>
+    <Nullable>enable</Nullable>
+    <RootNamespace>{}</RootNamespace>
+  </PropertyGroup>
+
+  <!-- start auto exported by moli. -->
+  <!-- end auto exported by moli. -->
+
+</Project>
+"#,
+            output_type, root_namespace
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::CodeFile;
+    use tempfile::TempDir;
+
+    fn make_config() -> MoliConfig {
+        MoliConfig {
+            projects: vec![
+                Project {
+                    name: "Acme.Core".to_string(),
+                    lang: "csharp".to_string(),
+                    tree: vec![Module {
+                        name: Some("Models".to_string()),
+                        file: vec![CodeFile { name: "User".to_string(), ..Default::default() }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                Project {
+                    name: "apps/web-api".to_string(),
+                    lang: "csharp".to_string(),
+                    depends_on: vec!["Acme.Core".to_string()],
+                    file: vec![CodeFile { name: "Program".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = make_config();
+
+        let core_path = base_path.join("Acme.Core");
+        CSharpProjectHandler::generate_project(&RealFs, &core_path, &config.projects()[0]).unwrap();
+
+        let csproj = fs::read_to_string(core_path.join("Acme.Core.csproj")).unwrap();
+        assert!(csproj.contains("<RootNamespace>Acme.Core</RootNamespace>"));
+        assert!(!csproj.contains("<OutputType>Exe</OutputType>"));
+
+        let user = fs::read_to_string(core_path.join("Models/User.cs")).unwrap();
+        assert!(user.starts_with("namespace Acme.Core.Models;"));
+    }
+
+    #[test]
+    fn test_project_references_and_solution() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = make_config();
+
+        for project in config.projects() {
+            CSharpProjectHandler::generate_project(&RealFs, base_path.join(project.name()), project).unwrap();
+            CSharpProjectHandler::update_project_references(&RealFs, base_path, project, &config).unwrap();
+        }
+        CSharpProjectHandler::generate_solution(&RealFs, base_path, &config).unwrap();
+        CSharpProjectHandler::generate_solution(&RealFs, base_path, &config).unwrap();
+
+        let web_csproj = fs::read_to_string(base_path.join("apps/web-api/web-api.csproj")).unwrap();
+        assert!(web_csproj.contains("<OutputType>Exe</OutputType>"));
+        assert!(web_csproj.contains("<RootNamespace>web_api</RootNamespace>"));
+        assert!(web_csproj.contains("<ProjectReference Include=\"..\\..\\Acme.Core\\Acme.Core.csproj\" />"));
+
+        let solution_name = base_path.canonicalize().unwrap().file_name().unwrap().to_string_lossy().to_string();
+        let solution = fs::read_to_string(base_path.join(format!("{}.sln", solution_name))).unwrap();
+        assert!(solution.contains("\"Acme.Core\", \"Acme.Core\\Acme.Core.csproj\""));
+        assert!(solution.contains("\"web-api\", \"apps\\web-api\\web-api.csproj\""));
+        assert_eq!(solution.matches("EndGlobal\n").count(), 1);
+        assert_eq!(solution.matches(".Debug|Any CPU.Build.0").count(), 2);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{Project, Module, CodeFile, LanguageDefinition};
+use crate::shared::utils::content_updater::ContentUpdater;
+
+/// Generator for a language declared in the `languages:` entry of moli.yml
+///
+/// Creates the declared directories and files, and maintains the aggregation file of the
+/// project root and every directory with children, with one templated line per child.
+pub struct CustomLanguageGenerator {
+    definition: LanguageDefinition,
+}
+
+impl CustomLanguageGenerator {
+    /// Create a generator from a moli.yml language definition
+    pub fn new(definition: LanguageDefinition) -> Self {
+        Self { definition }
+    }
+
+    /// Generate one directory: its files, its subdirectories and its aggregation file
+    fn generate_directory(&self, fs: &dyn Fs, dir_path: &Path, files: &[CodeFile], modules: &[Module]) -> Result<()> {
+        fs.create_dir_all(dir_path)
+            .with_context(|| format!("Failed to create directory: {}", dir_path.display()))?;
+
+        for codefile in files {
+            let file_path = dir_path.join(self.filename(codefile));
+
+            // Only create file if it doesn't already exist
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
+                    .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+            }
+        }
+
+        for module in modules {
+            self.generate_directory(fs, &dir_path.join(module.name()), module.files(), module.subtree())?;
+        }
+
+        if let Some(aggregator) = self.definition.aggregator.as_deref() {
+            let aggregator_path = dir_path.join(aggregator);
+            let lines = self.aggregator_lines(files, modules);
+
+            // Leaf directories get no aggregator, but an existing one still has its section cleared
+            if lines.is_empty() && !fs.exists(&aggregator_path) {
+                return Ok(());
+            }
+
+            let comment = self.definition.comment();
+            ContentUpdater::update_managed_section(
+                fs,
+                aggregator_path,
+                &lines.join("\n"),
+                &format!("{} start auto exported by moli.\n", comment),
+                &format!("{} end auto exported by moli.", comment),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Render the aggregator lines for the files and subdirectories of a directory
+    fn aggregator_lines(&self, files: &[CodeFile], modules: &[Module]) -> Vec<String> {
+        let aggregator = self.definition.aggregator.as_deref();
+        let mut lines = Vec::new();
+
+        if let Some(template) = self.definition.file_line() {
+            for codefile in files {
+                let filename = self.filename(codefile);
+                // The aggregator does not list itself
+                if Some(filename.as_str()) == aggregator {
+                    continue;
+                }
+                let name = Path::new(&filename)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_else(|| filename.clone());
+                lines.push(Self::render(template, &name, &filename));
+            }
+        }
+
+        if let Some(template) = self.definition.dir_line() {
+            for module in modules {
+                let name = module.name();
+                lines.push(Self::render(template, &name, &name));
+            }
+        }
+
+        lines
+    }
+
+    /// Get the file name with the language's default extension when none is given
+    fn filename(&self, codefile: &CodeFile) -> String {
+        match self.definition.extension() {
+            Some(ext) if !codefile.name().contains('.') => format!("{}.{}", codefile.name(), ext),
+            _ => codefile.name().to_string(),
+        }
+    }
+
+    /// Substitute `{name}` and `{file}` in a line template
+    fn render(template: &str, name: &str, file: &str) -> String {
+        template.replace("{name}", name).replace("{file}", file)
+    }
+}
+
+impl LanguageGenerator for CustomLanguageGenerator {
+    fn name(&self) -> &str {
+        &self.definition.name
+    }
+
+    fn extension(&self) -> Option<&str> {
+        self.definition.extension()
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        self.generate_directory(fs, project_path, project.files(), project.tree())
+    }
+
+    fn aggregator_file(&self) -> Option<&str> {
+        self.definition.aggregator.as_deref()
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        self.definition.ignored_files()
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        self.definition.ignored_dirs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn terraform() -> LanguageDefinition {
+        LanguageDefinition {
+            name: "terraform".to_string(),
+            extension: Some("tf".to_string()),
+            aggregator: Some("main.tf".to_string()),
+            line: Some("module \"{name}\" { source = \"./{name}\" }".to_string()),
+            file_line: Some(String::new()),
+            ignore: vec![".terraform.lock.hcl".to_string(), ".terraform/".to_string()],
+            ..Default::default()
+        }
+    }
+
+    fn module(name: &str, files: &[&str], tree: Vec<Module>) -> Module {
+        Module {
+            name: Some(name.to_string()),
+            file: files.iter()
+                .map(|f| CodeFile { name: f.to_string(), ..Default::default() })
+                .collect(),
+            tree,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_terraform_modules() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = Project {
+            name: "infra".to_string(),
+            root: true,
+            lang: "terraform".to_string(),
+            file: vec![CodeFile { name: "variables".to_string(), ..Default::default() }],
+            tree: vec![
+                module("network", &["outputs"], vec![module("subnets", &[], vec![])]),
+                module("cluster", &[], vec![]),
+            ],
+            ..Default::default()
+        };
+
+        fs::create_dir_all(base_path.join("network/subnets")).unwrap();
+        fs::write(base_path.join("network/subnets/main.tf"), "resource \"null_resource\" \"x\" {}\n").unwrap();
+
+        let generator = CustomLanguageGenerator::new(terraform());
+        generator.generate_project(&RealFs, base_path, &project).unwrap();
+
+        assert!(base_path.join("variables.tf").exists());
+        assert!(base_path.join("network/outputs.tf").exists());
+        assert!(!base_path.join("cluster/main.tf").exists());
+        let subnets = fs::read_to_string(base_path.join("network/subnets/main.tf")).unwrap();
+        assert!(subnets.starts_with("# start auto exported by moli.\n\n# end auto exported by moli."));
+
+        let root = fs::read_to_string(base_path.join("main.tf")).unwrap();
+        assert!(root.starts_with("# start auto exported by moli.\n"));
+        assert!(root.contains("module \"network\" { source = \"./network\" }\nmodule \"cluster\" { source = \"./cluster\" }"));
+        assert!(!root.contains("variables"));
+
+        let network = fs::read_to_string(base_path.join("network/main.tf")).unwrap();
+        assert!(network.contains("module \"subnets\" { source = \"./subnets\" }"));
+
+        assert_eq!(generator.excluded_files(), vec![".terraform.lock.hcl"]);
+        assert_eq!(generator.excluded_dirs(), vec![".terraform"]);
+    }
+
+    #[test]
+    fn test_file_lines_and_comment_syntax() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        fs::write(base_path.join("default.nix"), "{ pkgs }: {\n}\n").unwrap();
+
+        let definition = LanguageDefinition {
+            name: "nix".to_string(),
+            extension: Some(".nix".to_string()),
+            aggregator: Some("default.nix".to_string()),
+            line: Some("{name} = import ./{file};".to_string()),
+            ..Default::default()
+        };
+        let project = Project {
+            name: "pkgs".to_string(),
+            root: true,
+            lang: "nix".to_string(),
+            file: vec![
+                CodeFile { name: "default".to_string(), ..Default::default() },
+                CodeFile { name: "hello".to_string(), ..Default::default() },
+            ],
+            tree: vec![module("tools", &[], vec![])],
+            ..Default::default()
+        };
+
+        CustomLanguageGenerator::new(definition).generate_project(&RealFs, base_path, &project).unwrap();
+
+        let content = fs::read_to_string(base_path.join("default.nix")).unwrap();
+        assert_eq!(
+            content,
+            "# start auto exported by moli.\nhello = import ./hello.nix;\ntools = import ./tools;\n# end auto exported by moli.\n\n{ pkgs }: {\n}"
+        );
+    }
+}
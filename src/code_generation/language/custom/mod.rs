@@ -0,0 +1,3 @@
+// start auto exported by moli.
+pub mod generator;
+// end auto exported by moli.
@@ -0,0 +1,48 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::Project;
+use crate::code_generation::language::dart::pubspec_handler::DartPubspecHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Dart packages with library barrels and pubspec.yaml
+pub struct DartGenerator;
+
+impl LanguageGenerator for DartGenerator {
+    fn name(&self) -> &str {
+        "dart"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("dart")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["flutter", "barrels", "index"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        DartPubspecHandler::generate_project(fs, project_path, project)
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["pubspec.yaml", "pubspec.lock", "analysis_options.yaml"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec![".dart_tool"]
+    }
+
+    fn new_skeleton(&self, project_name: &str, _is_root: bool) -> String {
+        // Pub standard: lib/<package>.dart and test/
+        format!(
+            r#"  tree:
+    - name: lib
+      file:
+        - name: {}
+    - name: test
+"#,
+            project_name
+        )
+    }
+}
@@ -0,0 +1,237 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile, Project};
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::code_generation::core::barrel_policy::BarrelPolicy;
+
+/// Dart-specific library generator
+pub struct DartLibraryGenerator;
+
+impl DartLibraryGenerator {
+    /// Check if a file is a Dart library file that can be exported
+    /// (generated part files such as user.g.dart or user.freezed.dart are skipped)
+    fn is_exportable_dart_file(filename: &str) -> bool {
+        filename.ends_with(".dart")
+            && !filename.ends_with(".g.dart")
+            && !filename.ends_with(".freezed.dart")
+    }
+
+    /// Check if a directory is a library directory below lib/ (lib/ itself holds the hand-written package library)
+    pub fn is_library_dir(module_segments: &[String]) -> bool {
+        module_segments.len() > 1 && module_segments[0] == "lib"
+    }
+
+    /// Generate Dart directory structure recursively
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+        project: &Project,
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module_name);
+
+        let barrel_filename = BarrelPolicy::filename(project, module, "dart");
+        let mut export_statements = Vec::new();
+
+        // Generate code files in this directory
+        for codefile in module.files() {
+            let filename = codefile.filename_with_extension("dart");
+            if filename == barrel_filename {
+                continue; // The barrel is written by the managed section below
+            }
+
+            Self::generate_file(fs, &module_path, codefile, &module_segments, project)?;
+
+            if Self::is_exportable_dart_file(&filename) {
+                export_statements.push(format!("export '{}';", filename));
+            }
+        }
+
+        // Process subdirectories recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &module_segments, project)?;
+
+            // Re-export the subdirectory barrel only if it exists
+            let mut submodule_segments = module_segments.clone();
+            submodule_segments.push(submodule.name());
+            if Self::is_barrel_maintained(project, submodule, &submodule_segments) {
+                let submodule_barrel = BarrelPolicy::filename(project, submodule, "dart");
+                export_statements.push(format!("export '{}/{}';", submodule.name(), submodule_barrel));
+            }
+        }
+
+        // Maintain the barrel of library directories
+        if Self::is_barrel_maintained(project, module, &module_segments) {
+            let barrel_path = module_path.join(&barrel_filename);
+            ContentUpdater::update_js_index_file(fs, &barrel_path, &export_statements)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check if moli maintains the barrel of this directory (library directories only)
+    fn is_barrel_maintained(project: &Project, module: &Module, module_segments: &[String]) -> bool {
+        Self::is_library_dir(module_segments) && BarrelPolicy::is_maintained(project, module, "dart")
+    }
+
+    /// Create a Dart file (only if it doesn't exist)
+    /// Files under test/ get a test skeleton, files under bin/ an entry point
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        module_segments: &[String],
+        project: &Project,
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("dart");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            let content = if !filename.ends_with(".dart") {
+                String::new() // Non-Dart files get no content
+            } else {
+                match module_segments.first().map(|s| s.as_str()) {
+                    Some("test") => Self::generate_test_content(project.is_flutter()),
+                    Some("bin") => "void main(List<String> arguments) {\n}\n".to_string(),
+                    _ => String::new(),
+                }
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Collect barrel paths (relative to the project) that moli maintains without a `file` entry
+    pub fn collect_barrel_paths(project: &Project) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        for module in project.tree() {
+            Self::collect_module_barrel_paths(project, module, &[], &mut paths);
+        }
+
+        paths
+    }
+
+    fn collect_module_barrel_paths(
+        project: &Project,
+        module: &Module,
+        parent_modules: &[String],
+        paths: &mut Vec<String>,
+    ) {
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module.name());
+
+        if Self::is_barrel_maintained(project, module, &module_segments) {
+            paths.push(format!("{}/{}", module_segments.join("/"), BarrelPolicy::filename(project, module, "dart")));
+        }
+
+        for submodule in module.subtree() {
+            Self::collect_module_barrel_paths(project, submodule, &module_segments, paths);
+        }
+    }
+
+    /// Generate test file content
+    fn generate_test_content(flutter: bool) -> String {
+        let test_package = if flutter {
+            "package:flutter_test/flutter_test.dart"
+        } else {
+            "package:test/test.dart"
+        };
+
+        format!("import '{}';\n\nvoid main() {{\n}}\n", test_package)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project() -> Project {
+        Project {
+            name: "app".to_string(),
+            lang: "dart".to_string(),
+            tree: vec![
+                Module {
+                    name: Some("lib".to_string()),
+                    tree: vec![Module {
+                        name: Some("src".to_string()),
+                        barrels: Some("off".to_string()),
+                        tree: vec![Module {
+                            name: Some("models".to_string()),
+                            file: vec![
+                                CodeFile { name: "models".to_string(), ..Default::default() },
+                                CodeFile { name: "user".to_string(), ..Default::default() },
+                                CodeFile { name: "user.g.dart".to_string(), ..Default::default() },
+                            ],
+                            tree: vec![Module {
+                                name: Some("dto".to_string()),
+                                barrels: Some("auto".to_string()),
+                                file: vec![CodeFile { name: "login".to_string(), ..Default::default() }],
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                Module {
+                    name: Some("test".to_string()),
+                    file: vec![CodeFile { name: "user_test".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_library_barrels() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = make_project();
+
+        for module in project.tree() {
+            DartLibraryGenerator::generate_module(&RealFs, base_path, module, &[], &project).unwrap();
+        }
+
+        let models = fs::read_to_string(base_path.join("lib/src/models/models.dart")).unwrap();
+        assert!(models.contains(
+            "// start auto exported by moli.\nexport 'user.dart';\nexport 'dto/dto.dart';\n// end auto exported by moli."
+        ));
+        assert!(!models.contains("user.g.dart"));
+
+        let dto = fs::read_to_string(base_path.join("lib/src/models/dto/dto.dart")).unwrap();
+        assert!(dto.contains("export 'login.dart';"));
+        assert!(!base_path.join("lib/src/src.dart").exists());
+
+        let test = fs::read_to_string(base_path.join("test/user_test.dart")).unwrap();
+        assert_eq!(test, "import 'package:test/test.dart';\n\nvoid main() {\n}\n");
+    }
+
+    #[test]
+    fn test_collect_barrel_paths() {
+        let paths = DartLibraryGenerator::collect_barrel_paths(&make_project());
+
+        assert_eq!(paths, vec![
+            "lib/src/models/models.dart".to_string(),
+            "lib/src/models/dto/dto.dart".to_string(),
+        ]);
+    }
+}
@@ -0,0 +1,5 @@
+// start auto exported by moli.
+pub mod library_generator;
+pub mod pubspec_handler;
+pub mod generator;
+// end auto exported by moli.
@@ -0,0 +1,147 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::paths;
+use super::library_generator::DartLibraryGenerator;
+
+/// Dart pub handler for generating Dart/Flutter project structure
+pub struct DartPubspecHandler;
+
+impl DartPubspecHandler {
+    /// Generate complete Dart project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate pubspec.yaml
+        Self::generate_pubspec_yaml(fs, project_path, project)?;
+
+        // Generate project-level code files
+        for codefile in project.files() {
+            DartLibraryGenerator::generate_file(fs, project_path, codefile, &[], project)?;
+        }
+
+        // Generate directory structure
+        for module in project.tree() {
+            DartLibraryGenerator::generate_module(fs, project_path, module, &[], project)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate pubspec.yaml (only if it doesn't exist)
+    pub fn generate_pubspec_yaml<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+        let pubspec_path = project_path.join("pubspec.yaml");
+
+        if !fs.exists(&pubspec_path) {
+            let package_name = Self::package_name(fs, project_path, project);
+            fs.write(&pubspec_path, Self::generate_pubspec_content(&package_name, project.is_flutter()))
+                .with_context(|| format!("Failed to create pubspec.yaml: {}", pubspec_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get a pub package name (lowercase with underscores; directory name for ".")
+    fn package_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        let name = if project.name() == "." {
+            paths::directory_name(fs, project_path, "app")
+        } else {
+            project.name().rsplit('/').next().unwrap_or(project.name()).to_string()
+        };
+
+        name.to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+
+    /// Generate pubspec.yaml content
+    fn generate_pubspec_content(package_name: &str, flutter: bool) -> String {
+        if flutter {
+            return format!(
+                r#"name: {}
+description: A new Flutter project.
+version: 1.0.0+1
+publish_to: none
+
+environment:
+  sdk: ^3.3.0
+
+dependencies:
+  flutter:
+    sdk: flutter
+
+dev_dependencies:
+  flutter_test:
+    sdk: flutter
+  flutter_lints: ^3.0.0
+
+flutter:
+  uses-material-design: true
+"#,
+                package_name
+            );
+        }
+
+        format!(
+            r#"name: {}
+description: A new Dart project.
+version: 1.0.0
+publish_to: none
+
+environment:
+  sdk: ^3.3.0
+
+dev_dependencies:
+  lints: ^3.0.0
+  test: ^1.24.0
+"#,
+            package_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_pubspec_yaml() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = Project {
+            name: "apps/my-app".to_string(),
+            lang: "dart".to_string(),
+            flutter: true,
+            ..Default::default()
+        };
+
+        DartPubspecHandler::generate_project(&RealFs, base_path, &project).unwrap();
+
+        let pubspec = fs::read_to_string(base_path.join("pubspec.yaml")).unwrap();
+        assert!(pubspec.starts_with("name: my_app\n"));
+        assert!(pubspec.contains("sdk: flutter"));
+
+        // Existing pubspec.yaml is never overwritten
+        fs::write(base_path.join("pubspec.yaml"), "name: custom\n").unwrap();
+        DartPubspecHandler::generate_project(&RealFs, base_path, &project).unwrap();
+        assert_eq!(fs::read_to_string(base_path.join("pubspec.yaml")).unwrap(), "name: custom\n");
+    }
+}
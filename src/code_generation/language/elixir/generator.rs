@@ -0,0 +1,81 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::elixir::mix_handler::ElixirMixHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Elixir Mix projects with paired ExUnit tests and umbrella apps
+pub struct ElixirGenerator;
+
+impl LanguageGenerator for ElixirGenerator {
+    fn name(&self) -> &str {
+        "elixir"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("ex")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["test"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        ElixirMixHandler::generate_project(fs, project_path, project)
+    }
+
+    fn generate_workspace(&self, fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        if ElixirMixHandler::should_generate_umbrella(config) {
+            ElixirMixHandler::generate_umbrella(fs, workspace_path)?;
+        }
+        Ok(())
+    }
+
+    fn link_project(&self, fs: &dyn Fs, workspace_path: &Path, project: &Project, config: &MoliConfig) -> Result<()> {
+        // Map depends_on to in_umbrella dependencies
+        ElixirMixHandler::update_umbrella_dependencies(fs, workspace_path, project, config)
+    }
+
+    fn validate_workspace(&self, config: &MoliConfig) -> Vec<ValidationError> {
+        if config.is_single_project() {
+            return Vec::new();
+        }
+
+        // Mix umbrellas keep every app below apps/
+        config.projects()
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.language() == "elixir" && !ElixirMixHandler::is_umbrella_app(p))
+            .map(|(i, p)| ValidationError {
+                message: format!(
+                    "Elixir projects in a multi-project config must be umbrella apps named {}/<app>: {}",
+                    ElixirMixHandler::UMBRELLA_APPS_DIR, p.name()
+                ),
+                path: format!("projects[{}].name", i),
+            })
+            .collect()
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["mix.exs", "mix.lock"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["_build", "deps"]
+    }
+
+    fn new_skeleton(&self, project_name: &str, _is_root: bool) -> String {
+        // Mix standard: lib/<app>.ex with paired tests in test/
+        format!(
+            r#"  tree:
+    - name: lib
+      file:
+        - name: {}
+    - name: test
+"#,
+            project_name.replace('-', "_")
+        )
+    }
+}
@@ -0,0 +1,236 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+use super::module_generator::ElixirModuleGenerator;
+
+/// Mix handler for generating Elixir projects and umbrella workspaces
+pub struct ElixirMixHandler;
+
+impl ElixirMixHandler {
+    /// Directory that holds the apps of an umbrella project
+    pub const UMBRELLA_APPS_DIR: &'static str = "apps";
+
+    /// Generate complete Mix project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate project-level files
+        for codefile in project.files() {
+            ElixirModuleGenerator::generate_file(fs, project_path, codefile, &[])?;
+        }
+
+        // Generate lib/ and test/ trees
+        for module in project.tree() {
+            ElixirModuleGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        // Generate mix.exs (only if it doesn't exist)
+        let mix_exs_path = project_path.join("mix.exs");
+        if !fs.exists(&mix_exs_path) {
+            let app_name = Self::app_name(fs, project_path, project);
+            fs.write(&mix_exs_path, Self::generate_mix_exs_content(&app_name, Self::is_umbrella_app(project)))
+                .with_context(|| format!("Failed to create mix.exs: {}", mix_exs_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the OTP application name (last path segment, directory name for ".")
+    pub fn app_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        let name = if project.name() == "." {
+            paths::directory_name(fs, project_path, "app")
+        } else {
+            project.name().rsplit('/').next().unwrap_or(project.name()).to_string()
+        };
+
+        name.replace('-', "_")
+    }
+
+    /// Check if a project lives in the umbrella apps/ directory (apps/<app>)
+    pub fn is_umbrella_app(project: &Project) -> bool {
+        project.name()
+            .strip_prefix(&format!("{}/", Self::UMBRELLA_APPS_DIR))
+            .is_some_and(|app| !app.is_empty() && !app.contains('/'))
+    }
+
+    /// Check if an umbrella root is needed (multi-project configs with Elixir projects)
+    pub fn should_generate_umbrella(config: &MoliConfig) -> bool {
+        config.projects().iter().any(|p| p.language() == "elixir")
+    }
+
+    /// Generate the umbrella root mix.exs and config/config.exs (only if they don't exist)
+    pub fn generate_umbrella<P: AsRef<Path>>(fs: &dyn Fs, workspace_path: P) -> Result<()> {
+        let workspace_path = workspace_path.as_ref();
+        let umbrella_name = Self::app_name(fs, workspace_path, &Project { name: ".".to_string(), ..Default::default() });
+
+        let mix_exs_path = workspace_path.join("mix.exs");
+        if !fs.exists(&mix_exs_path) {
+            fs.write(&mix_exs_path, Self::generate_umbrella_mix_exs_content(&umbrella_name))
+                .with_context(|| format!("Failed to create mix.exs: {}", mix_exs_path.display()))?;
+        }
+
+        let config_dir = workspace_path.join("config");
+        let config_exs_path = config_dir.join("config.exs");
+        if !fs.exists(&config_exs_path) {
+            fs.create_dir_all(&config_dir)
+                .with_context(|| format!("Failed to create directory: {}", config_dir.display()))?;
+            fs.write(&config_exs_path, "import Config\n")
+                .with_context(|| format!("Failed to create config.exs: {}", config_exs_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Map depends_on to `{:app, in_umbrella: true}` entries in the managed deps section
+    pub fn update_umbrella_dependencies<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        workspace_path: P,
+        project: &Project,
+        config: &MoliConfig,
+    ) -> Result<()> {
+        let workspace_path = workspace_path.as_ref();
+        let mix_exs_path = workspace_path.join(project.name()).join("mix.exs");
+
+        let dependencies: Vec<String> = project.dependencies()
+            .iter()
+            .filter_map(|dep| config.projects().iter().find(|p| p.name() == dep.as_str() && p.language() == "elixir"))
+            .map(|dep| format!("      {{:{}, in_umbrella: true}},", Self::app_name(fs, &workspace_path.join(dep.name()), dep)))
+            .collect();
+
+        ContentUpdater::update_managed_section(
+            fs,
+            &mix_exs_path,
+            &dependencies.join("\n"),
+            "      # start auto exported by moli.\n",
+            "      # end auto exported by moli.",
+        ).with_context(|| format!("Failed to update umbrella dependencies: {}", mix_exs_path.display()))
+    }
+
+    /// Generate mix.exs content (umbrella apps share _build, config, deps and mix.lock with the root)
+    fn generate_mix_exs_content(app_name: &str, is_umbrella_app: bool) -> String {
+        let umbrella_paths = if is_umbrella_app {
+            r#"      build_path: "../../_build",
+      config_path: "../../config/config.exs",
+      deps_path: "../../deps",
+      lockfile: "../../mix.lock",
+"#
+        } else {
+            ""
+        };
+
+        format!(
+            r#"defmodule {}.MixProject do
+  use Mix.Project
+
+  def project do
+    [
+      app: :{},
+      version: "0.1.0",
+{}      elixir: "~> 1.15",
+      start_permanent: Mix.env() == :prod,
+      deps: deps()
+    ]
+  end
+
+  def application do
+    [
+      extra_applications: [:logger]
+    ]
+  end
+
+  defp deps do
+    [
+      # start auto exported by moli.
+      # end auto exported by moli.
+    ]
+  end
+end
+"#,
+            ElixirModuleGenerator::camelize(app_name), app_name, umbrella_paths
+        )
+    }
+
+    /// Generate the umbrella root mix.exs content
+    fn generate_umbrella_mix_exs_content(umbrella_name: &str) -> String {
+        format!(
+            r#"defmodule {}.MixProject do
+  use Mix.Project
+
+  def project do
+    [
+      apps_path: "{}",
+      version: "0.1.0",
+      start_permanent: Mix.env() == :prod,
+      deps: deps()
+    ]
+  end
+
+  defp deps do
+    []
+  end
+end
+"#,
+            ElixirModuleGenerator::camelize(umbrella_name), Self::UMBRELLA_APPS_DIR
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::Module;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_umbrella_dependencies() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = MoliConfig {
+            projects: vec![
+                Project {
+                    name: "apps/accounts".to_string(),
+                    lang: "elixir".to_string(),
+                    tree: vec![Module { name: Some("lib".to_string()), ..Default::default() }],
+                    ..Default::default()
+                },
+                Project {
+                    name: "apps/web_api".to_string(),
+                    lang: "elixir".to_string(),
+                    depends_on: vec!["apps/accounts".to_string()],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        ElixirMixHandler::generate_umbrella(&RealFs, temp_dir.path()).unwrap();
+        for project in config.projects() {
+            let project_path = temp_dir.path().join(project.name());
+            ElixirMixHandler::generate_project(&RealFs, &project_path, project).unwrap();
+            ElixirMixHandler::update_umbrella_dependencies(&RealFs, temp_dir.path(), project, &config).unwrap();
+        }
+
+        let root_mix = fs::read_to_string(temp_dir.path().join("mix.exs")).unwrap();
+        assert!(root_mix.contains("apps_path: \"apps\""));
+        assert!(temp_dir.path().join("config/config.exs").exists());
+
+        let web_mix = fs::read_to_string(temp_dir.path().join("apps/web_api/mix.exs")).unwrap();
+        assert!(web_mix.starts_with("defmodule WebApi.MixProject do"));
+        assert!(web_mix.contains("build_path: \"../../_build\""));
+        assert!(web_mix.contains(
+            "      # start auto exported by moli.\n      {:accounts, in_umbrella: true},\n      # end auto exported by moli."
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+// start auto exported by moli.
+pub mod module_generator;
+pub mod mix_handler;
+pub mod generator;
+// end auto exported by moli.
@@ -0,0 +1,262 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile, Project};
+
+/// Elixir-specific module generator
+pub struct ElixirModuleGenerator;
+
+impl ElixirModuleGenerator {
+    /// Check if a file is an Elixir source file (ends with .ex)
+    fn is_elixir_source_file(filename: &str) -> bool {
+        filename.ends_with(".ex")
+    }
+
+    /// Generate Elixir directory structure recursively
+    /// Files below lib/ get a `defmodule` derived from their path and a paired test below test/
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        module: &Module,
+        parent_modules: &[String],
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module.name());
+
+        let module_path = module_segments.iter().fold(project_path.to_path_buf(), |path, segment| path.join(segment));
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, &module_segments)?;
+            Self::generate_test_file(fs, project_path, codefile, &module_segments)?;
+        }
+
+        // Process subdirectories recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, project_path, submodule, &module_segments)?;
+        }
+
+        // test_helper.exs is what `mix test` loads first
+        if module_segments.len() == 1 && module_segments[0] == "test" {
+            Self::generate_test_helper(fs, project_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a code file (only if it doesn't exist)
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        module_segments: &[String],
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("elixir");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            let content = if module_segments.first().map(String::as_str) == Some("lib") && Self::is_elixir_source_file(&filename) {
+                let module_name = Self::module_name(&module_segments[1..], filename.trim_end_matches(".ex"));
+                format!("defmodule {} do\nend\n", module_name)
+            } else if module_segments.first().map(String::as_str) == Some("test") && filename.ends_with("_test.exs") {
+                let module_name = Self::module_name(&module_segments[1..], filename.trim_end_matches(".exs"));
+                Self::generate_test_content(&module_name, None)
+            } else if filename == "test_helper.exs" {
+                "ExUnit.start()\n".to_string()
+            } else {
+                String::new()
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Create test/<path>_test.exs for a lib/<path>.ex file (unless `test: no`)
+    fn generate_test_file(
+        fs: &dyn Fs,
+        project_path: &Path,
+        codefile: &CodeFile,
+        module_segments: &[String],
+    ) -> Result<()> {
+        let test_path = match Self::test_path(codefile, module_segments) {
+            Some(path) => project_path.join(path),
+            None => return Ok(()),
+        };
+
+        if !fs.exists(&test_path) {
+            if let Some(parent) = test_path.parent() {
+                fs.create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            }
+
+            let stem = codefile.filename_with_extension("elixir").trim_end_matches(".ex").to_string();
+            let module_name = Self::module_name(&module_segments[1..], &stem);
+            let content = Self::generate_test_content(&format!("{}Test", module_name), Some(&module_name));
+            fs.write(&test_path, content)
+                .with_context(|| format!("Failed to create file: {}", test_path.display()))?;
+
+            Self::generate_test_helper(fs, project_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the paired test path (relative to the project) of a file below lib/
+    /// e.g. lib/my_app/accounts/user.ex -> test/my_app/accounts/user_test.exs
+    pub fn test_path(codefile: &CodeFile, module_segments: &[String]) -> Option<String> {
+        if module_segments.first().map(String::as_str) != Some("lib") || codefile.test_setting() == Some("no") {
+            return None;
+        }
+
+        let filename = codefile.filename_with_extension("elixir");
+        let stem = filename.strip_suffix(".ex")?;
+        let mut segments = vec!["test".to_string()];
+        segments.extend(module_segments[1..].iter().cloned());
+        segments.push(format!("{}_test.exs", stem));
+
+        Some(segments.join("/"))
+    }
+
+    /// Collect test paths (relative to the project) that moli writes without a `file` entry
+    pub fn collect_test_paths(project: &Project) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        for module in project.tree() {
+            Self::collect_module_test_paths(module, &[], &mut paths);
+        }
+
+        let has_test_dir = project.tree().iter().any(|m| m.name() == "test");
+        if !paths.is_empty() || has_test_dir {
+            paths.push("test/test_helper.exs".to_string());
+        }
+
+        paths
+    }
+
+    fn collect_module_test_paths(module: &Module, parent_modules: &[String], paths: &mut Vec<String>) {
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module.name());
+
+        for codefile in module.files() {
+            if let Some(test_path) = Self::test_path(codefile, &module_segments) {
+                paths.push(test_path);
+            }
+        }
+
+        for submodule in module.subtree() {
+            Self::collect_module_test_paths(submodule, &module_segments, paths);
+        }
+    }
+
+    /// Build a module name from the directories below lib/ and a file stem (my_app/user_service -> MyApp.UserService)
+    pub fn module_name(dir_segments: &[String], stem: &str) -> String {
+        dir_segments.iter()
+            .map(String::as_str)
+            .chain(std::iter::once(stem))
+            .map(Self::camelize)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Convert a snake_case name to an alias segment (user_service -> UserService)
+    pub fn camelize(name: &str) -> String {
+        name.split('_')
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// Create test/test_helper.exs (only if it doesn't exist)
+    fn generate_test_helper(fs: &dyn Fs, project_path: &Path) -> Result<()> {
+        let helper_path = project_path.join("test").join("test_helper.exs");
+
+        if !fs.exists(&helper_path) {
+            fs.write(&helper_path, "ExUnit.start()\n")
+                .with_context(|| format!("Failed to create file: {}", helper_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate an ExUnit test module
+    fn generate_test_content(test_module: &str, subject: Option<&str>) -> String {
+        let alias = subject
+            .map(|subject| format!("\n  alias {}\n", subject))
+            .unwrap_or_default();
+
+        format!("defmodule {} do\n  use ExUnit.Case, async: true\n{}end\n", test_module, alias)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_lib() -> Module {
+        Module {
+            name: Some("lib".to_string()),
+            tree: vec![Module {
+                name: Some("my_app".to_string()),
+                tree: vec![Module {
+                    name: Some("accounts".to_string()),
+                    file: vec![
+                        CodeFile { name: "user_service".to_string(), ..Default::default() },
+                        CodeFile { name: "schema".to_string(), test: Some("no".to_string()), ..Default::default() },
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_module_with_paired_tests() {
+        let temp_dir = TempDir::new().unwrap();
+
+        ElixirModuleGenerator::generate_module(&RealFs, temp_dir.path(), &make_lib(), &[]).unwrap();
+
+        let source = fs::read_to_string(temp_dir.path().join("lib/my_app/accounts/user_service.ex")).unwrap();
+        assert_eq!(source, "defmodule MyApp.Accounts.UserService do\nend\n");
+
+        let test = fs::read_to_string(temp_dir.path().join("test/my_app/accounts/user_service_test.exs")).unwrap();
+        assert_eq!(
+            test,
+            "defmodule MyApp.Accounts.UserServiceTest do\n  use ExUnit.Case, async: true\n\n  alias MyApp.Accounts.UserService\nend\n"
+        );
+
+        assert!(!temp_dir.path().join("test/my_app/accounts/schema_test.exs").exists());
+        assert!(temp_dir.path().join("test/test_helper.exs").exists());
+    }
+
+    #[test]
+    fn test_collect_test_paths() {
+        let project = Project {
+            lang: "elixir".to_string(),
+            tree: vec![make_lib()],
+            ..Default::default()
+        };
+
+        assert_eq!(ElixirModuleGenerator::collect_test_paths(&project), vec![
+            "test/my_app/accounts/user_service_test.exs".to_string(),
+            "test/test_helper.exs".to_string(),
+        ]);
+    }
+}
@@ -0,0 +1,113 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{Project, Module, CodeFile};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::go::package_generator::GoPackageGenerator;
+use crate::code_generation::language::go::mod_handler::GoModuleHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Go modules with package declarations and go.mod
+pub struct GoGenerator;
+
+impl LanguageGenerator for GoGenerator {
+    fn name(&self) -> &str {
+        "go"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("go")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["package", "test"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        GoModuleHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        // Every file in a directory must share one package
+        let mut errors = Vec::new();
+
+        // Project-level files
+        let root_packages: Vec<(&CodeFile, String)> = project.files().iter()
+            .map(|f| (f, GoModuleHandler::root_package_name(project, f)))
+            .collect();
+        Self::check_directory(&root_packages, path, &mut errors);
+
+        for (i, module) in project.tree().iter().enumerate() {
+            Self::validate_module_packages(module, &format!("{}.tree[{}]", path, i), &mut errors);
+        }
+
+        errors
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["go.mod", "go.sum"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Go standard: main.go at project root for simple projects
+        r#"  file:
+    - name: main
+"#.to_string()
+    }
+}
+
+impl GoGenerator {
+    /// Validate Go packages of a module and its subtree
+    fn validate_module_packages(module: &Module, path: &str, errors: &mut Vec<ValidationError>) {
+        if module.package_flag().is_some() {
+            errors.push(ValidationError {
+                message: "Go modules expect a package name for 'package'".to_string(),
+                path: format!("{}.package", path),
+            });
+        }
+
+        let packages: Vec<(&CodeFile, String)> = module.files().iter()
+            .map(|f| (f, GoPackageGenerator::get_package_name_for_module(module, f)))
+            .collect();
+        Self::check_directory(&packages, path, errors);
+
+        for (i, submodule) in module.subtree().iter().enumerate() {
+            Self::validate_module_packages(submodule, &format!("{}.tree[{}]", path, i), errors);
+        }
+    }
+
+    /// Check that the Go files of one directory agree on a package name
+    fn check_directory(packages: &[(&CodeFile, String)], path: &str, errors: &mut Vec<ValidationError>) {
+        let go_files: Vec<&(&CodeFile, String)> = packages.iter()
+            .filter(|(f, _)| f.filename_with_extension("go").ends_with(".go"))
+            .collect();
+
+        if let Some((_, expected)) = go_files.first() {
+            for (codefile, package) in &go_files {
+                if package != expected {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Go files in one directory must share a package: '{}' declares '{}' but expected '{}'",
+                            codefile.name(), package, expected
+                        ),
+                        path: format!("{}.file", path),
+                    });
+                }
+            }
+        }
+
+        for (codefile, _) in packages {
+            if let Some(test) = codefile.test_setting() {
+                if !matches!(test, "internal" | "external") {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Invalid test setting '{}' for '{}' (expected 'internal' or 'external')",
+                            test, codefile.name()
+                        ),
+                        path: format!("{}.file", path),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,6 @@
 // start auto exported by moli.
 pub mod package_generator;
 pub mod mod_handler;
+pub mod generator;
 // end auto exported by moli.
 
@@ -1,7 +1,7 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::Path;
 use anyhow::{Context, Result};
-use crate::project_management::config::models::{Project, Module};
+use crate::project_management::config::models::{Project, Module, CodeFile};
 use super::package_generator::GoPackageGenerator;
 
 /// Go module handler for generating Go project structure
@@ -15,59 +15,67 @@ impl GoModuleHandler {
 
     /// Generate complete Go project structure
     pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         project: &Project,
     ) -> Result<()> {
         let project_path = project_path.as_ref();
         
         // Create project directory
-        fs::create_dir_all(project_path)
+        fs.create_dir_all(project_path)
             .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
 
         // Generate go.mod
-        GoPackageGenerator::generate_go_mod(project_path, project.name())?;
+        GoPackageGenerator::generate_go_mod(fs, project_path, project.name())?;
         
         // Generate go.sum
-        GoPackageGenerator::generate_go_sum(project_path)?;
+        GoPackageGenerator::generate_go_sum(fs, project_path)?;
         
         // Generate main.go if needed
         if GoPackageGenerator::should_generate_main_go(project) {
-            GoPackageGenerator::generate_main_go(project_path)?;
+            GoPackageGenerator::generate_main_go(fs, project_path)?;
         }
         
         // Generate project-level code files
         for codefile in project.files() {
             let filename = codefile.filename_with_extension("go");
             let file_path = project_path.join(&filename);
+            let package_name = Self::root_package_name(project, codefile);
 
             // Only create file if it doesn't already exist
-            if !file_path.exists() {
+            if !fs.exists(&file_path) {
                 // Only add package declaration for Go code files
                 let content = if Self::is_go_code_file(&filename) {
-                    // Use main package for root level files (Go convention)
-                    let package_name = if codefile.name() == "main" || filename == "main.go" {
-                        "main".to_string()
-                    } else {
-                        "main".to_string() // Most Go projects use main package for root level files
-                    };
                     format!("package {}\n\n", package_name)
                 } else {
                     String::new() // Non-Go files get no content
                 };
 
-                fs::write(&file_path, content)
+                fs.write(&file_path, content)
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
+
+            // Create _test variant if requested
+            GoPackageGenerator::generate_test_file(fs, project_path, codefile, &package_name)?;
         }
         
         // Generate module structure
         for module in project.tree() {
-            GoPackageGenerator::generate_module(project_path, module, &[])?;
+            GoPackageGenerator::generate_module(fs, project_path, module, &[])?;
         }
 
         Ok(())
     }
     
+    /// Get package name for a project-level file
+    /// Priority: file `package` > project `package` > "main" (Go convention for root files)
+    pub fn root_package_name(project: &Project, codefile: &CodeFile) -> String {
+        codefile.package_setting()
+            .or(project.package_setting())
+            .unwrap_or("main")
+            .to_string()
+    }
+
     /// Sanitize package name for Go (convert hyphens to underscores, make lowercase)
     fn sanitize_package_name(name: &str) -> String {
         name.replace('-', "_").to_lowercase()
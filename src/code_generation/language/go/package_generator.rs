@@ -1,4 +1,4 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Module, CodeFile, Project};
@@ -14,6 +14,7 @@ impl GoPackageGenerator {
 
     /// Generate Go module structure recursively
     pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         module: &Module,
         parent_modules: &[String],
@@ -22,7 +23,7 @@ impl GoPackageGenerator {
         let module_path = base_path.as_ref().join(&module_name);
 
         // Create directory
-        fs::create_dir_all(&module_path)
+        fs.create_dir_all(&module_path)
             .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
 
         // Generate code files in this module
@@ -30,19 +31,23 @@ impl GoPackageGenerator {
             let filename = codefile.filename_with_extension("go");
             let file_path = module_path.join(&filename);
 
+            let package_name = Self::get_package_name_for_module(module, codefile);
+
             // Create file (only if it doesn't exist)
-            if !file_path.exists() {
+            if !fs.exists(&file_path) {
                 // Only add package declaration for Go code files
                 let content = if Self::is_go_code_file(&filename) {
-                    let package_name = Self::get_package_name_for_module(&module_name, codefile.name());
                     Self::generate_go_file_content(&package_name)
                 } else {
                     String::new() // Non-Go files get no content
                 };
 
-                fs::write(&file_path, content)
+                fs.write(&file_path, content)
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
+
+            // Create _test variant if requested
+            Self::generate_test_file(fs, &module_path, codefile, &package_name)?;
         }
 
         // Process submodules recursively
@@ -50,7 +55,7 @@ impl GoPackageGenerator {
             let mut new_parent_modules = parent_modules.to_vec();
             new_parent_modules.push(module.name().to_string());
             
-            Self::generate_module(&module_path, submodule, &new_parent_modules)?;
+            Self::generate_module(fs, &module_path, submodule, &new_parent_modules)?;
         }
 
         Ok(())
@@ -58,6 +63,7 @@ impl GoPackageGenerator {
 
     /// Generate go.mod file for Go project
     pub fn generate_go_mod<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         project_name: &str,
     ) -> Result<()> {
@@ -65,8 +71,8 @@ impl GoPackageGenerator {
         let go_mod_content = Self::generate_go_mod_content(project_name);
         
         // Only create go.mod if it doesn't already exist
-        if !go_mod_path.exists() {
-            fs::write(&go_mod_path, go_mod_content)
+        if !fs.exists(&go_mod_path) {
+            fs.write(&go_mod_path, go_mod_content)
                 .with_context(|| format!("Failed to create go.mod: {}", go_mod_path.display()))?;
         }
 
@@ -75,13 +81,14 @@ impl GoPackageGenerator {
 
     /// Generate go.sum file for Go project
     pub fn generate_go_sum<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
     ) -> Result<()> {
         let go_sum_path = project_path.as_ref().join("go.sum");
         
         // Create empty go.sum file (only if it doesn't exist)
-        if !go_sum_path.exists() {
-            fs::write(&go_sum_path, "")
+        if !fs.exists(&go_sum_path) {
+            fs.write(&go_sum_path, "")
                 .with_context(|| format!("Failed to create go.sum: {}", go_sum_path.display()))?;
         }
 
@@ -90,14 +97,15 @@ impl GoPackageGenerator {
 
     /// Generate main.go file for Go project
     pub fn generate_main_go<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
     ) -> Result<()> {
         let main_go_path = project_path.as_ref().join("main.go");
         let main_content = Self::generate_main_go_content();
         
         // Only create main.go if it doesn't already exist
-        if !main_go_path.exists() {
-            fs::write(&main_go_path, main_content)
+        if !fs.exists(&main_go_path) {
+            fs.write(&main_go_path, main_content)
                 .with_context(|| format!("Failed to create main.go: {}", main_go_path.display()))?;
         }
 
@@ -109,10 +117,61 @@ impl GoPackageGenerator {
         format!("package {}\n\n", package_name)
     }
 
-    /// Get appropriate package name for Go file based on module and file name
-    fn get_package_name_for_module(module_name: &str, file_name: &str) -> String {
-        // Use module name as package name (Go convention)
-        Self::sanitize_package_name(module_name)
+    /// Generate `<name>_test.go` next to a Go file when the file has a `test` setting
+    /// - "internal": test file shares the package (`package foo`)
+    /// - "external": black-box test package (`package foo_test`)
+    pub fn generate_test_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        package_name: &str,
+    ) -> Result<()> {
+        let test_setting = match codefile.test_setting() {
+            Some(setting) => setting,
+            None => return Ok(()),
+        };
+
+        let test_filename = match Self::test_filename(&codefile.filename_with_extension("go")) {
+            Some(name) => name,
+            None => return Ok(()),
+        };
+
+        let test_path = dir_path.as_ref().join(&test_filename);
+
+        // Only create test file if it doesn't already exist
+        if !fs.exists(&test_path) {
+            let test_package = Self::test_package_name(package_name, test_setting);
+            fs.write(&test_path, Self::generate_go_test_file_content(&test_package))
+                .with_context(|| format!("Failed to create file: {}", test_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the `_test` file name for a Go file (e.g. "user.go" -> "user_test.go")
+    pub fn test_filename(filename: &str) -> Option<String> {
+        let stem = filename.strip_suffix(".go")?;
+        if stem.ends_with("_test") {
+            return None;
+        }
+        Some(format!("{}_test.go", stem))
+    }
+
+    /// Get package name for a test file based on its `test` setting
+    pub fn test_package_name(package_name: &str, test_setting: &str) -> String {
+        match test_setting {
+            "external" => format!("{}_test", package_name),
+            _ => package_name.to_string(),
+        }
+    }
+
+    /// Get appropriate package name for Go file
+    /// Priority: file `package` > module `package` > directory name (Go convention)
+    pub fn get_package_name_for_module(module: &Module, codefile: &CodeFile) -> String {
+        codefile.package_setting()
+            .or(module.package_setting())
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| Self::sanitize_package_name(&module.name()))
     }
 
     /// Sanitize package name for Go (convert hyphens to underscores, make lowercase)
@@ -128,6 +187,11 @@ impl GoPackageGenerator {
         )
     }
 
+    /// Generate Go test file content
+    fn generate_go_test_file_content(package_name: &str) -> String {
+        format!("package {}\n\nimport \"testing\"\n\n", package_name)
+    }
+
     /// Generate main.go file content
     fn generate_main_go_content() -> String {
         r#"package main
@@ -142,6 +206,11 @@ func main() {
 
     /// Check if project should have main.go
     pub fn should_generate_main_go(project: &Project) -> bool {
+        // Library repos declare a non-main root package and have no entry point
+        if project.package_setting().is_some_and(|p| p != "main") {
+            return false;
+        }
+
         // Generate main.go if there's no explicit main.go file defined in the project
         let has_main_in_project = project.files().iter()
             .any(|f| f.name() == "main" || f.filename_with_extension("go") == "main.go");
@@ -169,4 +238,50 @@ func main() {
         
         results
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+    use crate::project_management::config::models::PackageSetting;
+
+    #[test]
+    fn test_generate_module_with_package_override() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let module = Module {
+            name: Some("user-service".to_string()),
+            package: Some(PackageSetting::Name("users".to_string())),
+            file: vec![
+                CodeFile { name: "model".to_string(), test: Some("external".to_string()), ..Default::default() },
+                CodeFile { name: "repository".to_string(), test: Some("internal".to_string()), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        GoPackageGenerator::generate_module(&RealFs, base_path, &module, &[]).unwrap();
+
+        let model = fs::read_to_string(base_path.join("user-service/model.go")).unwrap();
+        assert!(model.starts_with("package users\n"));
+
+        let model_test = fs::read_to_string(base_path.join("user-service/model_test.go")).unwrap();
+        assert!(model_test.starts_with("package users_test\n"));
+
+        let repository_test = fs::read_to_string(base_path.join("user-service/repository_test.go")).unwrap();
+        assert!(repository_test.starts_with("package users\n"));
+    }
+
+    #[test]
+    fn test_package_name_defaults_to_directory() {
+        let module = Module {
+            name: Some("http-handler".to_string()),
+            ..Default::default()
+        };
+        let codefile = CodeFile { name: "api".to_string(), ..Default::default() };
+
+        assert_eq!(GoPackageGenerator::get_package_name_for_module(&module, &codefile), "http_handler");
+    }
+}
@@ -0,0 +1,219 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+use super::module_generator::HaskellModuleGenerator;
+
+/// Cabal handler for generating Haskell project structure
+pub struct HaskellCabalHandler;
+
+impl HaskellCabalHandler {
+    /// Generate complete Haskell project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate project-level files
+        for codefile in project.files() {
+            HaskellModuleGenerator::generate_file(fs, project_path, codefile, &[])?;
+        }
+
+        // Generate src/, app/ and test/ trees
+        for module in project.tree() {
+            HaskellModuleGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        // Generate <package>.cabal and keep the library module lists in sync with the tree
+        Self::generate_cabal_file(fs, project_path, project)?;
+
+        Ok(())
+    }
+
+    /// Generate <package>.cabal (created once) and update its managed exposed-modules / other-modules
+    pub fn generate_cabal_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+        let package_name = Self::package_name(fs, project_path, project);
+        let cabal_path = project_path.join(format!("{}.cabal", package_name));
+        let src_module = project.tree().iter().find(|m| m.name() == "src");
+
+        if !fs.exists(&cabal_path) {
+            let has_dir = |name: &str| project.tree().iter().any(|m| m.name() == name);
+            let content = Self::generate_cabal_content(&package_name, src_module.is_some(), has_dir("app"), has_dir("test"));
+            fs.write(&cabal_path, content)
+                .with_context(|| format!("Failed to create cabal file: {}", cabal_path.display()))?;
+        }
+
+        let (exposed, other) = src_module
+            .map(HaskellModuleGenerator::collect_library_modules)
+            .unwrap_or_default();
+
+        ContentUpdater::update_managed_section(
+            fs,
+            &cabal_path,
+            &Self::generate_module_lists(&exposed, &other),
+            "    -- start auto exported by moli.\n",
+            "    -- end auto exported by moli.",
+        ).with_context(|| format!("Failed to update cabal module lists: {}", cabal_path.display()))
+    }
+
+    /// Get the package name (last path segment, directory name for "."; my_lib -> my-lib)
+    fn package_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        let name = if project.name() == "." {
+            paths::directory_name(fs, project_path, "app")
+        } else {
+            project.name().rsplit('/').next().unwrap_or(project.name()).to_string()
+        };
+
+        name.replace('_', "-")
+    }
+
+    /// Render the managed `exposed-modules:` / `other-modules:` fields (empty lists are omitted)
+    fn generate_module_lists(exposed: &[String], other: &[String]) -> String {
+        let mut fields = Vec::new();
+
+        for (field, modules) in [("exposed-modules", exposed), ("other-modules", other)] {
+            if !modules.is_empty() {
+                let entries: Vec<String> = modules.iter().map(|m| format!("        {}", m)).collect();
+                fields.push(format!("    {}:\n{}", field, entries.join("\n")));
+            }
+        }
+
+        fields.join("\n")
+    }
+
+    /// Generate <package>.cabal content with a stanza per source directory
+    fn generate_cabal_content(package_name: &str, has_library: bool, has_executable: bool, has_tests: bool) -> String {
+        let mut content = format!(
+            r#"cabal-version:      3.0
+name:               {}
+version:            0.1.0.0
+build-type:         Simple
+
+common warnings
+    ghc-options: -Wall
+"#,
+            package_name
+        );
+
+        let build_depends = if has_library {
+            format!("\n        base >=4.14 && <5,\n        {}", package_name)
+        } else {
+            "    base >=4.14 && <5".to_string()
+        };
+
+        if has_library {
+            content.push_str(
+                r#"
+library
+    import:           warnings
+    hs-source-dirs:   src
+    build-depends:    base >=4.14 && <5
+    default-language: Haskell2010
+    -- start auto exported by moli.
+    -- end auto exported by moli.
+"#,
+            );
+        }
+
+        if has_executable {
+            content.push_str(&format!(
+                r#"
+executable {}
+    import:           warnings
+    main-is:          Main.hs
+    hs-source-dirs:   app
+    build-depends:{}
+    default-language: Haskell2010
+"#,
+                package_name, build_depends
+            ));
+        }
+
+        if has_tests {
+            content.push_str(&format!(
+                r#"
+test-suite {}-test
+    import:           warnings
+    type:             exitcode-stdio-1.0
+    main-is:          Main.hs
+    hs-source-dirs:   test
+    build-depends:{}
+    default-language: Haskell2010
+"#,
+                package_name, build_depends
+            ));
+        }
+
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::{CodeFile, Module};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_cabal_module_lists() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut project = Project {
+            name: "my_lib".to_string(),
+            lang: "haskell".to_string(),
+            tree: vec![
+                Module {
+                    name: Some("src".to_string()),
+                    tree: vec![Module {
+                        name: Some("Data".to_string()),
+                        file: vec![
+                            CodeFile { name: "Parser".to_string(), ..Default::default() },
+                            CodeFile { name: "Lexer".to_string(), r#pub: Some("no".to_string()), ..Default::default() },
+                        ],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                Module {
+                    name: Some("app".to_string()),
+                    file: vec![CodeFile { name: "Main".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        HaskellCabalHandler::generate_project(&RealFs, temp_dir.path(), &project).unwrap();
+
+        let cabal = fs::read_to_string(temp_dir.path().join("my-lib.cabal")).unwrap();
+        assert!(cabal.contains(
+            "    -- start auto exported by moli.\n    exposed-modules:\n        Data.Parser\n    other-modules:\n        Data.Lexer\n    -- end auto exported by moli.\n"
+        ));
+        assert!(cabal.contains("executable my-lib\n"));
+        assert!(!cabal.contains("test-suite"));
+
+        let main = fs::read_to_string(temp_dir.path().join("app/Main.hs")).unwrap();
+        assert!(main.starts_with("module Main (main) where"));
+
+        // Making a module private moves it between the lists on the next run
+        project.tree[0].tree[0].file[0].r#pub = Some("no".to_string());
+        HaskellCabalHandler::generate_project(&RealFs, temp_dir.path(), &project).unwrap();
+        let cabal = fs::read_to_string(temp_dir.path().join("my-lib.cabal")).unwrap();
+        assert!(cabal.contains("    other-modules:\n        Data.Parser\n        Data.Lexer\n"));
+        assert!(!cabal.contains("exposed-modules"));
+    }
+}
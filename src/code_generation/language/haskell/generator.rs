@@ -0,0 +1,88 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{Project, Module};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::haskell::module_generator::HaskellModuleGenerator;
+use crate::code_generation::language::haskell::cabal_handler::HaskellCabalHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Haskell packages with module headers and a managed .cabal file
+pub struct HaskellGenerator;
+
+impl LanguageGenerator for HaskellGenerator {
+    fn name(&self) -> &str {
+        "haskell"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("hs")
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        HaskellCabalHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        // Module names below src/, app/ and test/
+        let mut errors = Vec::new();
+        for (i, module) in project.tree().iter().enumerate() {
+            if HaskellModuleGenerator::SOURCE_DIRS.contains(&module.name().as_str()) {
+                Self::check_module_names(module, &format!("{}.tree[{}]", path, i), &mut errors);
+            }
+        }
+        errors
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["cabal.project", "stack.yaml"]
+    }
+
+    fn excluded_extensions(&self) -> Vec<&str> {
+        vec![".cabal"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["dist-newstyle", ".stack-work"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Cabal standard: library in src/, executable in app/
+        r#"  tree:
+    - name: src
+      file:
+        - name: Lib
+    - name: app
+      file:
+        - name: Main
+"#.to_string()
+    }
+}
+
+impl HaskellGenerator {
+    /// Check that Haskell files and directories below a source directory are capitalized module names
+    fn check_module_names(module: &Module, path: &str, errors: &mut Vec<ValidationError>) {
+        for (i, codefile) in module.files().iter().enumerate() {
+            let filename = codefile.filename_with_extension("haskell");
+            if let Some(stem) = filename.strip_suffix(".hs") {
+                if !HaskellModuleGenerator::is_valid_module_segment(stem) {
+                    errors.push(ValidationError {
+                        message: format!("Invalid module file name: {} (expected a capitalized name)", filename),
+                        path: format!("{}.file[{}].name", path, i),
+                    });
+                }
+            }
+        }
+
+        for (i, submodule) in module.subtree().iter().enumerate() {
+            let submodule_path = format!("{}.tree[{}]", path, i);
+            if !HaskellModuleGenerator::is_valid_module_segment(&submodule.name()) {
+                errors.push(ValidationError {
+                    message: format!("Invalid module directory name: {} (expected a capitalized name)", submodule.name()),
+                    path: format!("{}.name", submodule_path),
+                });
+            }
+            Self::check_module_names(submodule, &submodule_path, errors);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile};
+
+/// Haskell-specific module generator
+pub struct HaskellModuleGenerator;
+
+impl HaskellModuleGenerator {
+    /// Top-level directories whose contents are named after their path (hs-source-dirs)
+    pub const SOURCE_DIRS: &'static [&'static str] = &["src", "app", "test"];
+
+    /// Check if a file is a Haskell code file (ends with .hs)
+    fn is_haskell_code_file(filename: &str) -> bool {
+        filename.ends_with(".hs")
+    }
+
+    /// Generate Haskell directory structure recursively
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module_name);
+
+        // Generate code files in this directory
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, &module_segments)?;
+        }
+
+        // Process subdirectories recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &module_segments)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a code file with a `module ... where` header (only if it doesn't exist)
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        module_segments: &[String],
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("haskell");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            let content = match Self::module_name(module_segments, &filename) {
+                Some(module_name) if module_name == "Main" => Self::generate_main_content(),
+                Some(module_name) => format!("module {} where\n", module_name),
+                None => String::new(),
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the module name of a file below a source directory (src/Data/Foo/Bar.hs -> Data.Foo.Bar)
+    pub fn module_name(module_segments: &[String], filename: &str) -> Option<String> {
+        let source_dir = module_segments.first()?;
+        if !Self::SOURCE_DIRS.contains(&source_dir.as_str()) || !Self::is_haskell_code_file(filename) {
+            return None;
+        }
+
+        let mut segments: Vec<&str> = module_segments[1..].iter().map(String::as_str).collect();
+        segments.push(filename.trim_end_matches(".hs"));
+        Some(segments.join("."))
+    }
+
+    /// Collect library modules below src/ split into (exposed, other) by `pub`
+    /// Modules are exposed unless the file or one of its directories sets `pub: no`
+    pub fn collect_library_modules(src_module: &Module) -> (Vec<String>, Vec<String>) {
+        let mut exposed = Vec::new();
+        let mut other = Vec::new();
+
+        Self::collect_modules(src_module, &[src_module.name()], false, &mut exposed, &mut other);
+
+        (exposed, other)
+    }
+
+    fn collect_modules(
+        module: &Module,
+        module_segments: &[String],
+        inherited_hidden: bool,
+        exposed: &mut Vec<String>,
+        other: &mut Vec<String>,
+    ) {
+        for codefile in module.files() {
+            let filename = codefile.filename_with_extension("haskell");
+            if let Some(module_name) = Self::module_name(module_segments, &filename) {
+                let is_hidden = match codefile.pub_setting() {
+                    Some("no") => true,
+                    Some(_) => false,
+                    None => inherited_hidden,
+                };
+
+                if is_hidden {
+                    other.push(module_name);
+                } else {
+                    exposed.push(module_name);
+                }
+            }
+        }
+
+        for submodule in module.subtree() {
+            let mut submodule_segments = module_segments.to_vec();
+            submodule_segments.push(submodule.name());
+
+            let is_hidden = match submodule.pub_setting() {
+                Some("no") => true,
+                Some(_) => false,
+                None => inherited_hidden,
+            };
+
+            Self::collect_modules(submodule, &submodule_segments, is_hidden, exposed, other);
+        }
+    }
+
+    /// Check if a name is a valid module name component (capitalized identifier)
+    pub fn is_valid_module_segment(segment: &str) -> bool {
+        segment.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+            && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '\'')
+    }
+
+    /// Generate Main.hs content
+    fn generate_main_content() -> String {
+        "module Main (main) where\n\nmain :: IO ()\nmain = putStrLn \"Hello, Haskell!\"\n".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_src() -> Module {
+        Module {
+            name: Some("src".to_string()),
+            tree: vec![Module {
+                name: Some("Data".to_string()),
+                tree: vec![Module {
+                    name: Some("Foo".to_string()),
+                    file: vec![
+                        CodeFile { name: "Bar".to_string(), ..Default::default() },
+                        CodeFile { name: "Internal".to_string(), r#pub: Some("no".to_string()), ..Default::default() },
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_module_headers() {
+        let temp_dir = TempDir::new().unwrap();
+
+        HaskellModuleGenerator::generate_module(&RealFs, temp_dir.path(), &make_src(), &[]).unwrap();
+
+        let bar = fs::read_to_string(temp_dir.path().join("src/Data/Foo/Bar.hs")).unwrap();
+        assert_eq!(bar, "module Data.Foo.Bar where\n");
+    }
+
+    #[test]
+    fn test_collect_library_modules() {
+        let (exposed, other) = HaskellModuleGenerator::collect_library_modules(&make_src());
+
+        assert_eq!(exposed, vec!["Data.Foo.Bar".to_string()]);
+        assert_eq!(other, vec!["Data.Foo.Internal".to_string()]);
+    }
+}
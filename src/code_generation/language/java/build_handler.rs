@@ -0,0 +1,221 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::paths;
+use super::package_generator::JavaPackageGenerator;
+
+/// Java build handler for generating Java project structure and build files
+pub struct JavaBuildHandler;
+
+impl JavaBuildHandler {
+    /// Generate complete Java project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate pom.xml or build.gradle.kts
+        if Self::build_tool(project) == "maven" {
+            Self::generate_pom_xml(fs, project_path, project)?;
+        } else {
+            Self::generate_build_gradle(fs, project_path, project)?;
+        }
+
+        // Generate project-level code files (default package)
+        for codefile in project.files() {
+            JavaPackageGenerator::generate_file(fs, project_path, codefile, None)?;
+        }
+
+        // Generate package structure
+        for module in project.tree() {
+            JavaPackageGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the project's build tool (defaults to "gradle")
+    pub fn build_tool(project: &Project) -> &str {
+        project.build_setting().unwrap_or("gradle")
+    }
+
+    /// Get the project's group id (defaults to "com.example")
+    pub fn group_id(project: &Project) -> &str {
+        project.package_setting().unwrap_or("com.example")
+    }
+
+    /// Get the artifact id from the project name (last path segment, directory name for ".")
+    fn artifact_id(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        if project.name() == "." {
+            return paths::directory_name(fs, project_path, "app");
+        }
+
+        project.name()
+            .rsplit('/')
+            .next()
+            .unwrap_or(project.name())
+            .to_string()
+    }
+
+    /// Generate build.gradle.kts (only if it doesn't exist)
+    pub fn generate_build_gradle<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let build_gradle_path = project_path.as_ref().join("build.gradle.kts");
+
+        if !fs.exists(&build_gradle_path) {
+            fs.write(&build_gradle_path, Self::generate_build_gradle_content(Self::group_id(project)))
+                .with_context(|| format!("Failed to create build.gradle.kts: {}", build_gradle_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate pom.xml (only if it doesn't exist)
+    pub fn generate_pom_xml<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+        let pom_xml_path = project_path.join("pom.xml");
+
+        if !fs.exists(&pom_xml_path) {
+            let content = Self::generate_pom_xml_content(
+                Self::group_id(project),
+                &Self::artifact_id(fs, project_path, project),
+            );
+            fs.write(&pom_xml_path, content)
+                .with_context(|| format!("Failed to create pom.xml: {}", pom_xml_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate build.gradle.kts content
+    fn generate_build_gradle_content(group_id: &str) -> String {
+        format!(
+            r#"plugins {{
+    java
+}}
+
+group = "{}"
+version = "1.0.0"
+
+repositories {{
+    mavenCentral()
+}}
+
+dependencies {{
+    testImplementation("org.junit.jupiter:junit-jupiter:5.10.2")
+    testRuntimeOnly("org.junit.platform:junit-platform-launcher")
+}}
+
+tasks.test {{
+    useJUnitPlatform()
+}}
+"#,
+            group_id
+        )
+    }
+
+    /// Generate pom.xml content
+    fn generate_pom_xml_content(group_id: &str, artifact_id: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0"
+         xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+         xsi:schemaLocation="http://maven.apache.org/POM/4.0.0 http://maven.apache.org/xsd/maven-4.0.0.xsd">
+  <modelVersion>4.0.0</modelVersion>
+
+  <groupId>{}</groupId>
+  <artifactId>{}</artifactId>
+  <version>1.0.0</version>
+
+  <properties>
+    <maven.compiler.release>17</maven.compiler.release>
+    <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>
+  </properties>
+</project>
+"#,
+            group_id, artifact_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::{Module, CodeFile, PackageSetting};
+    use tempfile::TempDir;
+
+    fn make_project(name: &str, build: Option<&str>) -> Project {
+        Project {
+            name: name.to_string(),
+            lang: "java".to_string(),
+            package: Some(PackageSetting::Name("com.acme".to_string())),
+            build: build.map(|b| b.to_string()),
+            tree: vec![Module {
+                name: Some("src".to_string()),
+                tree: vec![Module {
+                    name: Some("main".to_string()),
+                    tree: vec![Module {
+                        name: Some("java".to_string()),
+                        tree: vec![Module {
+                            name: Some("com".to_string()),
+                            tree: vec![Module {
+                                name: Some("acme".to_string()),
+                                file: vec![CodeFile { name: "App".to_string(), ..Default::default() }],
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_gradle_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        JavaBuildHandler::generate_project(&RealFs, base_path, &make_project("api", None)).unwrap();
+
+        let build_gradle = fs::read_to_string(base_path.join("build.gradle.kts")).unwrap();
+        assert!(build_gradle.contains("group = \"com.acme\""));
+        assert!(!base_path.join("pom.xml").exists());
+
+        let app = fs::read_to_string(base_path.join("src/main/java/com/acme/App.java")).unwrap();
+        assert_eq!(app, "package com.acme;\n\npublic class App {\n}\n");
+    }
+
+    #[test]
+    fn test_generate_maven_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        JavaBuildHandler::generate_project(&RealFs, base_path, &make_project("services/api", Some("maven"))).unwrap();
+
+        let pom_xml = fs::read_to_string(base_path.join("pom.xml")).unwrap();
+        assert!(pom_xml.contains("<groupId>com.acme</groupId>"));
+        assert!(pom_xml.contains("<artifactId>api</artifactId>"));
+        assert!(!base_path.join("build.gradle.kts").exists());
+    }
+}
@@ -0,0 +1,120 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{MoliConfig, Project, Module};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::java::package_generator::JavaPackageGenerator;
+use crate::code_generation::language::java::build_handler::JavaBuildHandler;
+use crate::code_generation::core::gradle_settings::GradleSettings;
+use crate::shared::utils::file_system::Fs;
+
+/// Java packages with Maven/Gradle build files
+pub struct JavaGenerator;
+
+impl LanguageGenerator for JavaGenerator {
+    fn name(&self) -> &str {
+        "java"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("java")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["package", "build"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        JavaBuildHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if let Some(build) = project.build_setting() {
+            if !matches!(build, "gradle" | "maven") {
+                errors.push(ValidationError {
+                    message: format!("Unsupported build tool: {} (expected gradle or maven)", build),
+                    path: format!("{}.build", path),
+                });
+            }
+        }
+
+        for (i, module) in project.tree().iter().enumerate() {
+            Self::check_package_names(
+                module,
+                &[],
+                JavaPackageGenerator::SOURCE_DIRS,
+                &format!("{}.tree[{}]", path, i),
+                &mut errors,
+            );
+        }
+
+        errors
+    }
+
+    fn generate_workspace(&self, fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        if GradleSettings::should_generate(config) {
+            GradleSettings::generate(fs, workspace_path, config)?;
+        }
+        Ok(())
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["pom.xml", "build.gradle.kts", "settings.gradle.kts"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec![".gradle"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Maven/Gradle standard: src/main/java/<package path>
+        r#"  tree:
+    - name: src
+      tree:
+        - name: main
+          tree:
+            - name: java
+              tree:
+                - name: com
+                  tree:
+                    - name: example
+                      file:
+                        - name: Main
+"#.to_string()
+    }
+}
+
+impl JavaGenerator {
+    /// Check that directories below a JVM source root are valid package name components
+    pub(crate) fn check_package_names(
+        module: &Module,
+        parent_modules: &[String],
+        source_dirs: &[&str],
+        path: &str,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module.name());
+
+        if JavaPackageGenerator::is_in_source_root(&module_segments, source_dirs)
+            && !JavaPackageGenerator::is_valid_package_segment(&module.name())
+        {
+            errors.push(ValidationError {
+                message: format!("Invalid package directory name: {}", module.name()),
+                path: format!("{}.name", path),
+            });
+        }
+
+        for (i, submodule) in module.subtree().iter().enumerate() {
+            Self::check_package_names(
+                submodule,
+                &module_segments,
+                source_dirs,
+                &format!("{}.tree[{}]", path, i),
+                errors,
+            );
+        }
+    }
+}
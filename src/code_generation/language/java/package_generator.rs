@@ -0,0 +1,170 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile};
+
+/// Java-specific package generator
+pub struct JavaPackageGenerator;
+
+impl JavaPackageGenerator {
+    /// Source directory names that start a package hierarchy below src/<set>/
+    pub const SOURCE_DIRS: &'static [&'static str] = &["java"];
+
+    /// Check if a file is a Java code file (ends with .java)
+    fn is_java_code_file(filename: &str) -> bool {
+        filename.ends_with(".java")
+    }
+
+    /// Generate Java package structure recursively
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module_name.clone());
+        let package_name = Self::package_name(&module_segments, Self::SOURCE_DIRS);
+
+        // Generate code files in this package
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, package_name.as_deref())?;
+        }
+
+        // Process subpackages recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &module_segments)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a code file with a package declaration and class skeleton (only if it doesn't exist)
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        package_name: Option<&str>,
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("java");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            // Only add a class skeleton for Java code files
+            let content = if Self::is_java_code_file(&filename) {
+                let class_name = filename.trim_end_matches(".java");
+                Self::generate_java_file_content(package_name, class_name)
+            } else {
+                String::new() // Non-Java files get no content
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Derive the package name from a directory path relative to the project
+    /// Segments below a `src/<set>/<source dir>` source root form the package; without a
+    /// source root the whole path is used. Returns None for the default package.
+    pub fn package_name(module_segments: &[String], source_dirs: &[&str]) -> Option<String> {
+        let package_segments = Self::source_root_index(module_segments, source_dirs)
+            .map(|index| &module_segments[index + 1..])
+            .unwrap_or(module_segments);
+
+        if package_segments.is_empty() {
+            None
+        } else {
+            Some(package_segments.join("."))
+        }
+    }
+
+    /// Check if a directory path lies below a `src/<set>/<source dir>` source root
+    pub fn is_in_source_root(module_segments: &[String], source_dirs: &[&str]) -> bool {
+        Self::source_root_index(module_segments, source_dirs)
+            .is_some_and(|index| index + 1 < module_segments.len())
+    }
+
+    /// Find the position of the source dir in a `src/<set>/<source dir>` source root
+    /// (e.g. src/main/java, src/test/java, src/commonMain/kotlin)
+    fn source_root_index(module_segments: &[String], source_dirs: &[&str]) -> Option<usize> {
+        (2..module_segments.len())
+            .find(|&i| source_dirs.contains(&module_segments[i].as_str()) && module_segments[i - 2] == "src")
+    }
+
+    /// Check if a path segment is usable as a Java package name component
+    pub fn is_valid_package_segment(segment: &str) -> bool {
+        let mut chars = segment.chars();
+        match chars.next() {
+            Some(first) if first.is_ascii_alphabetic() || first == '_' || first == '$' => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+            }
+            _ => false,
+        }
+    }
+
+    /// Generate Java file content
+    fn generate_java_file_content(package_name: Option<&str>, class_name: &str) -> String {
+        let package_declaration = package_name
+            .map(|name| format!("package {};\n\n", name))
+            .unwrap_or_default();
+
+        format!("{}public class {} {{\n}}\n", package_declaration, class_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn segments(path: &str) -> Vec<String> {
+        path.split('/').map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_package_name() {
+        let roots = JavaPackageGenerator::SOURCE_DIRS;
+        assert_eq!(JavaPackageGenerator::package_name(&segments("src/main/java/com/acme/x"), roots), Some("com.acme.x".to_string()));
+        assert_eq!(JavaPackageGenerator::package_name(&segments("src/test/java/com/acme"), roots), Some("com.acme".to_string()));
+        assert_eq!(JavaPackageGenerator::package_name(&segments("src/main/java"), roots), None);
+        assert_eq!(JavaPackageGenerator::package_name(&segments("com/acme"), roots), Some("com.acme".to_string()));
+    }
+
+    #[test]
+    fn test_generate_module() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let module = Module {
+            name: Some("acme".to_string()),
+            file: vec![
+                CodeFile { name: "UserService".to_string(), ..Default::default() },
+                CodeFile { name: "schema.sql".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let parents = segments("src/main/java/com");
+
+        JavaPackageGenerator::generate_module(&RealFs, base_path, &module, &parents).unwrap();
+
+        let content = fs::read_to_string(base_path.join("acme/UserService.java")).unwrap();
+        assert_eq!(content, "package com.acme;\n\npublic class UserService {\n}\n");
+        assert_eq!(fs::read_to_string(base_path.join("acme/schema.sql")).unwrap(), "");
+
+        // Existing files are never overwritten
+        fs::write(base_path.join("acme/UserService.java"), "custom").unwrap();
+        JavaPackageGenerator::generate_module(&RealFs, base_path, &module, &parents).unwrap();
+        assert_eq!(fs::read_to_string(base_path.join("acme/UserService.java")).unwrap(), "custom");
+    }
+}
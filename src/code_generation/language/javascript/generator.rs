@@ -0,0 +1,70 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::javascript::package_handler::JavaScriptPackageHandler;
+use crate::code_generation::language::javascript::workspace_handler::NodeWorkspaceHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// JavaScript packages with index.js barrels and npm workspaces
+pub struct JavaScriptGenerator;
+
+impl LanguageGenerator for JavaScriptGenerator {
+    fn name(&self) -> &str {
+        "javascript"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("js")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["module", "exports", "workspace", "scope", "barrels", "index"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        JavaScriptPackageHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if let Some(module_system) = project.module_setting() {
+            if !matches!(module_system, "esm" | "commonjs") {
+                errors.push(ValidationError {
+                    message: format!("Unsupported module system: {} (expected esm or commonjs)", module_system),
+                    path: format!("{}.module", path),
+                });
+            }
+        }
+        errors
+    }
+
+    fn generate_workspace(&self, fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        if NodeWorkspaceHandler::should_generate_workspace(config) {
+            NodeWorkspaceHandler::generate_workspace(fs, workspace_path, config)?;
+        }
+        Ok(())
+    }
+
+    fn aggregator_file(&self) -> Option<&str> {
+        Some("index.js")
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["package.json", "package-lock.json", "yarn.lock", "pnpm-lock.yaml", "pnpm-workspace.yaml"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["node_modules"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Modern standard: src/ directory structure
+        r#"  tree:
+    - name: src
+      file:
+        - name: index
+"#.to_string()
+    }
+}
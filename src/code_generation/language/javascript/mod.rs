@@ -1,5 +1,7 @@
 // start auto exported by moli.
 pub mod module_generator;
 pub mod package_handler;
+pub mod workspace_handler;
+pub mod generator;
 // end auto exported by moli.
 
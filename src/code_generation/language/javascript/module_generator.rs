@@ -1,8 +1,10 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Module, CodeFile, Project};
 use crate::shared::utils::content_updater::ContentUpdater;
+use crate::code_generation::language::javascript::workspace_handler::NodeWorkspaceHandler;
+use crate::code_generation::core::barrel_policy::BarrelPolicy;
 
 /// JavaScript-specific module generator
 pub struct JavaScriptModuleGenerator;
@@ -15,19 +17,22 @@ impl JavaScriptModuleGenerator {
 
     /// Generate JavaScript module structure recursively
     pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         module: &Module,
         parent_modules: &[String],
+        project: &Project,
     ) -> Result<()> {
         let module_name = module.name();
         let module_path = base_path.as_ref().join(&module_name);
         
         // Create directory
-        fs::create_dir_all(&module_path)
+        fs.create_dir_all(&module_path)
             .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
 
-        // Collect all submodule names and code file names for index.js
-        let mut export_declarations = Vec::new();
+        // Collect all submodule names and code file names for the barrel file
+        let barrel_filename = BarrelPolicy::filename(project, module, "javascript");
+        let mut export_specifiers = Vec::new();
 
         // Generate code files in this module
         for codefile in module.files() {
@@ -35,13 +40,13 @@ impl JavaScriptModuleGenerator {
             let file_path = module_path.join(&filename);
             
             // Create empty JavaScript file (only if it doesn't exist)
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
 
-            // Add to export declarations if it's a JavaScript code file and not index.js
-            if Self::is_javascript_code_file(&filename) && filename != "index.js" {
+            // Add to export declarations if it's a JavaScript code file and not the barrel itself
+            if Self::is_javascript_code_file(&filename) && filename != barrel_filename {
                 // Use actual filename for ES module resolution (preserve extensions like .jsx)
                 let module_name = if codefile.name().contains('.') {
                     // If the codefile name already has an extension, use the full filename
@@ -59,7 +64,7 @@ impl JavaScriptModuleGenerator {
                     format!("./{}.js", module_name)
                 };
 
-                export_declarations.push(format!("export * from '{}';", import_path));
+                export_specifiers.push(import_path);
             }
         }
 
@@ -68,24 +73,136 @@ impl JavaScriptModuleGenerator {
             let mut new_parent_modules = parent_modules.to_vec();
             new_parent_modules.push(module.name().to_string());
             
-            Self::generate_module(&module_path, submodule, &new_parent_modules)?;
+            Self::generate_module(fs, &module_path, submodule, &new_parent_modules, project)?;
             
-            // Add submodule export declaration
-            export_declarations.push(format!("export * from './{}/index.js';", submodule.name()));
+            // Add submodule export declaration (unless its barrel is turned off)
+            if BarrelPolicy::is_reexported(project, submodule) {
+                let submodule_barrel = BarrelPolicy::filename(project, submodule, "javascript");
+                export_specifiers.push(format!("./{}/{}", submodule.name(), submodule_barrel));
+            }
         }
 
-        // Generate index.js only if explicitly defined in codefile
-        let has_explicit_index = module.files().iter()
-            .any(|f| f.name() == "index" || f.filename_with_extension("javascript") == "index.js");
-
-        if has_explicit_index {
-            let index_js_path = module_path.join("index.js");
-            ContentUpdater::update_js_index_file(&index_js_path, &export_declarations)?;
+        // Generate the barrel file according to the barrel policy
+        if BarrelPolicy::is_maintained(project, module, "javascript") {
+            let barrel_path = module_path.join(&barrel_filename);
+            let export_declarations = Self::generate_export_declarations(&export_specifiers, Self::module_system(project));
+            ContentUpdater::update_js_index_file(fs, &barrel_path, &export_declarations)?;
         }
 
         Ok(())
     }
 
+    /// Get the project's module system (defaults to "esm")
+    pub fn module_system(project: &Project) -> &str {
+        project.module_setting().unwrap_or("esm")
+    }
+
+    /// Build barrel statements for the given module system
+    /// - "esm": `export * from './x.js';`
+    /// - "commonjs": a single `module.exports = { ...require('./x.js') };` object
+    pub fn generate_export_declarations(specifiers: &[String], module_system: &str) -> Vec<String> {
+        if module_system != "commonjs" {
+            return specifiers.iter()
+                .map(|specifier| format!("export * from '{}';", specifier))
+                .collect();
+        }
+
+        if specifiers.is_empty() {
+            return vec!["module.exports = {};".to_string()];
+        }
+
+        let mut declarations = vec!["module.exports = {".to_string()];
+        declarations.extend(specifiers.iter().map(|specifier| format!("  ...require('{}'),", specifier)));
+        declarations.push("};".to_string());
+        declarations
+    }
+
+    /// Collect the package.json `exports` map entries for modules and files marked `pub: yes`
+    /// Subpaths drop a leading src/ or lib/ directory, so `src` itself maps to ".".
+    /// Modules are only exported when their barrel file is actually generated.
+    pub fn collect_package_exports(project: &Project) -> Vec<(String, String)> {
+        let mut entries = Vec::new();
+
+        for module in project.tree() {
+            Self::collect_module_exports(project, module, &[], &mut entries);
+        }
+
+        entries
+    }
+
+    /// Collect every barrel and file path of the tree that an `exports` entry may point at
+    pub fn collect_export_targets(project: &Project) -> Vec<String> {
+        let mut targets = Vec::new();
+
+        for module in project.tree() {
+            Self::collect_module_targets(project, module, ".", &mut targets);
+        }
+
+        targets
+    }
+
+    fn collect_module_targets(project: &Project, module: &Module, parent_path: &str, targets: &mut Vec<String>) {
+        let module_path = format!("{}/{}", parent_path, module.name());
+        targets.push(format!("{}/{}", module_path, BarrelPolicy::filename(project, module, "javascript")));
+
+        for codefile in module.files() {
+            targets.push(format!("{}/{}", module_path, codefile.filename_with_extension("javascript")));
+        }
+
+        for submodule in module.subtree() {
+            Self::collect_module_targets(project, submodule, &module_path, targets);
+        }
+    }
+
+    /// Check if a module's barrel file exists after generation (maintained by moli or listed in `file`)
+    fn has_generated_barrel(project: &Project, module: &Module) -> bool {
+        let barrel = BarrelPolicy::filename(project, module, "javascript");
+        BarrelPolicy::is_maintained(project, module, "javascript")
+            || module.files().iter().any(|f| f.filename_with_extension("javascript") == barrel)
+    }
+
+    fn collect_module_exports(
+        project: &Project,
+        module: &Module,
+        parent_modules: &[String],
+        entries: &mut Vec<(String, String)>,
+    ) {
+        let mut module_path = parent_modules.to_vec();
+        module_path.push(module.name());
+
+        let subpath_segments: &[String] = if matches!(module_path[0].as_str(), "src" | "lib") {
+            &module_path[1..]
+        } else {
+            &module_path
+        };
+        let subpath = |name: Option<&str>| {
+            let mut segments: Vec<&str> = subpath_segments.iter().map(|s| s.as_str()).collect();
+            segments.extend(name);
+            if segments.is_empty() {
+                ".".to_string()
+            } else {
+                format!("./{}", segments.join("/"))
+            }
+        };
+
+        if module.pub_setting() == Some("yes") && Self::has_generated_barrel(project, module) {
+            let barrel = BarrelPolicy::filename(project, module, "javascript");
+            entries.push((subpath(None), format!("./{}/{}", module_path.join("/"), barrel)));
+        }
+
+        for codefile in module.files() {
+            if codefile.pub_setting() == Some("yes") {
+                let filename = codefile.filename_with_extension("javascript");
+                let stem = Self::get_module_name_for_export(codefile);
+                entries.push((subpath(Some(&stem)), format!("./{}/{}", module_path.join("/"), filename)));
+            }
+        }
+
+        for submodule in module.subtree() {
+            Self::collect_module_exports(project, submodule, &module_path, entries);
+        }
+    }
+
     /// Get module name for export statement (removes extension)
     fn get_module_name_for_export(codefile: &CodeFile) -> String {
         let name = codefile.name();
@@ -100,15 +217,16 @@ impl JavaScriptModuleGenerator {
 
     /// Generate package.json for JavaScript project
     pub fn generate_package_json<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
-        project_name: &str,
+        project: &Project,
     ) -> Result<()> {
         let package_json_path = project_path.as_ref().join("package.json");
-        let package_content = Self::generate_package_json_content(project_name);
+        let package_content = Self::generate_package_json_content(&NodeWorkspaceHandler::package_name(project), Self::module_system(project));
         
         // Only create package.json if it doesn't already exist
-        if !package_json_path.exists() {
-            fs::write(&package_json_path, package_content)
+        if !fs.exists(&package_json_path) {
+            fs.write(&package_json_path, package_content)
                 .with_context(|| format!("Failed to create package.json: {}", package_json_path.display()))?;
         }
 
@@ -124,14 +242,16 @@ impl JavaScriptModuleGenerator {
     }
 
     /// Generate package.json content
-    fn generate_package_json_content(project_name: &str) -> String {
+    fn generate_package_json_content(project_name: &str, module_system: &str) -> String {
+        let package_type = if module_system == "commonjs" { "commonjs" } else { "module" };
+
         format!(
             r#"{{
   "name": "{}",
   "version": "1.0.0",
   "description": "",
   "main": "index.js",
-  "type": "module",
+  "type": "{}",
   "scripts": {{
     "start": "node index.js",
     "dev": "node --watch index.js",
@@ -142,20 +262,21 @@ impl JavaScriptModuleGenerator {
   "license": "ISC"
 }}
 "#,
-            project_name
+            project_name, package_type
         )
     }
 
     /// Generate main index.js file for JavaScript project
     pub fn generate_main_index_js<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
     ) -> Result<()> {
         let index_js_path = project_path.as_ref().join("index.js");
         let index_content = Self::generate_main_index_js_content();
         
         // Create main index.js with simple content
-        if !index_js_path.exists() {
-            fs::write(&index_js_path, index_content)
+        if !fs.exists(&index_js_path) {
+            fs.write(&index_js_path, index_content)
                 .with_context(|| format!("Failed to create index.js: {}", index_js_path.display()))?;
         }
 
@@ -197,4 +318,89 @@ impl JavaScriptModuleGenerator {
         
         results
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+    use crate::code_generation::language::javascript::package_handler::JavaScriptPackageHandler;
+
+    fn make_project() -> Project {
+        Project {
+            name: "service".to_string(),
+            root: true,
+            lang: "javascript".to_string(),
+            module: Some("commonjs".to_string()),
+            exports: true,
+            tree: vec![Module {
+                name: Some("src".to_string()),
+                r#pub: Some("yes".to_string()),
+                tree: vec![Module {
+                    name: Some("client".to_string()),
+                    r#pub: Some("yes".to_string()),
+                    file: vec![
+                        CodeFile { name: "index".to_string(), ..Default::default() },
+                        CodeFile { name: "http".to_string(), ..Default::default() },
+                    ],
+                    ..Default::default()
+                }],
+                file: vec![
+                    CodeFile { name: "index".to_string(), ..Default::default() },
+                    CodeFile { name: "errors".to_string(), r#pub: Some("yes".to_string()), ..Default::default() },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_commonjs_barrel() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = make_project();
+
+        JavaScriptModuleGenerator::generate_module(&RealFs, base_path, &project.tree()[0], &[], &project).unwrap();
+
+        let index = fs::read_to_string(base_path.join("src/index.js")).unwrap();
+        assert!(index.contains("module.exports = {\n  ...require('./errors.js'),\n  ...require('./client/index.js'),\n};"));
+        assert!(!index.contains("export *"));
+    }
+
+    #[test]
+    fn test_package_json_exports_map() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let project = make_project();
+
+        JavaScriptPackageHandler::generate_project(&RealFs, base_path, &project).unwrap();
+
+        let package_json = fs::read_to_string(base_path.join("package.json")).unwrap();
+        assert!(package_json.contains("\"type\": \"commonjs\""));
+        assert!(package_json.contains(
+            "\"exports\": {\n    \".\": \"./src/index.js\",\n    \"./errors\": \"./src/errors.js\",\n    \"./client\": \"./src/client/index.js\"\n  },"
+        ));
+
+        // Re-running replaces the map instead of adding a second one
+        JavaScriptPackageHandler::generate_project(&RealFs, base_path, &project).unwrap();
+        let rerun = fs::read_to_string(base_path.join("package.json")).unwrap();
+        assert_eq!(package_json, rerun);
+    }
+
+    #[test]
+    fn test_package_json_exports_only_generated_barrels() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let mut project = make_project();
+        project.tree[0].r#pub = None;
+        project.tree[0].tree[0].file.retain(|f| f.name() != "index");
+
+        JavaScriptPackageHandler::generate_project(&RealFs, base_path, &project).unwrap();
+
+        let package_json: serde_json::Value = serde_json::from_str(&fs::read_to_string(base_path.join("package.json")).unwrap()).unwrap();
+        assert_eq!(package_json["exports"], serde_json::json!({ ".": "./index.js", "./errors": "./src/errors.js" }));
+        assert!(!base_path.join("src/client/index.js").exists());
+    }
+}
@@ -1,7 +1,8 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::Path;
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Project, Module};
+use crate::shared::utils::content_updater::ContentUpdater;
 use super::module_generator::JavaScriptModuleGenerator;
 
 /// JavaScript package handler for generating JavaScript project structure
@@ -10,21 +11,22 @@ pub struct JavaScriptPackageHandler;
 impl JavaScriptPackageHandler {
     /// Generate complete JavaScript project structure
     pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         project: &Project,
     ) -> Result<()> {
         let project_path = project_path.as_ref();
         
         // Create project directory
-        fs::create_dir_all(project_path)
+        fs.create_dir_all(project_path)
             .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
 
         // Generate package.json
-        JavaScriptModuleGenerator::generate_package_json(project_path, project.name())?;
+        JavaScriptModuleGenerator::generate_package_json(fs, project_path, project)?;
         
         // Generate main index.js if needed
         if JavaScriptModuleGenerator::should_generate_main_index_js(project) {
-            JavaScriptModuleGenerator::generate_main_index_js(project_path)?;
+            JavaScriptModuleGenerator::generate_main_index_js(fs, project_path)?;
         }
         
         // Generate project-level code files
@@ -33,15 +35,22 @@ impl JavaScriptPackageHandler {
             let file_path = project_path.join(&filename);
             
             // Create empty JavaScript file (only if it doesn't exist)
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
         }
         
         // Generate module structure
         for module in project.tree() {
-            JavaScriptModuleGenerator::generate_module(project_path, module, &[])?;
+            JavaScriptModuleGenerator::generate_module(fs, project_path, module, &[], project)?;
+        }
+
+        // Maintain package.json exports map for public entry points
+        if project.has_exports_map() {
+            let entries = JavaScriptModuleGenerator::collect_package_exports(project);
+            let managed_targets = JavaScriptModuleGenerator::collect_export_targets(project);
+            ContentUpdater::update_package_json_exports(fs, project_path.join("package.json"), &entries, &managed_targets)?;
         }
 
         Ok(())
@@ -0,0 +1,325 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Value};
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+
+/// npm/yarn/pnpm workspace handler for multi-project JavaScript and TypeScript configs
+pub struct NodeWorkspaceHandler;
+
+impl NodeWorkspaceHandler {
+    /// Check if a project is a JavaScript or TypeScript project
+    fn is_node_project(project: &Project) -> bool {
+        matches!(project.language(), "javascript" | "typescript")
+    }
+
+    /// Check if workspace generation is needed (several JavaScript/TypeScript projects)
+    pub fn should_generate_workspace(config: &MoliConfig) -> bool {
+        config.projects()
+            .iter()
+            .filter(|p| Self::is_node_project(p))
+            .count() > 1
+    }
+
+    /// Get the workspace manager (first project that declares one, defaults to "npm")
+    pub fn workspace_manager(config: &MoliConfig) -> &str {
+        config.projects()
+            .iter()
+            .filter(|p| Self::is_node_project(p))
+            .find_map(|p| p.workspace_setting())
+            .unwrap_or("npm")
+    }
+
+    /// Get the npm package name of a project, scoped when `scope` is set (e.g. "@acme/api")
+    pub fn package_name(project: &Project) -> String {
+        let name = project.name()
+            .rfind('/')
+            .map(|index| &project.name()[index + 1..])
+            .unwrap_or(project.name());
+
+        match project.scope_setting() {
+            Some(scope) => format!("@{}/{}", scope.trim_start_matches('@'), name),
+            None => name.to_string(),
+        }
+    }
+
+    /// Generate workspace-level files: root package.json workspaces (npm/yarn)
+    /// or pnpm-workspace.yaml (pnpm), plus a solution-style root tsconfig.json
+    pub fn generate_workspace<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        workspace_path: P,
+        config: &MoliConfig,
+    ) -> Result<()> {
+        let workspace_path = workspace_path.as_ref();
+
+        // "." is the workspace root itself, not a member
+        let members: Vec<String> = config.projects()
+            .iter()
+            .filter(|p| Self::is_node_project(p) && p.name() != ".")
+            .map(|p| p.name().to_string())
+            .collect();
+
+        let package_json_path = workspace_path.join("package.json");
+        if !fs.exists(&package_json_path) {
+            fs.write(&package_json_path, Self::generate_root_package_json_content(fs, workspace_path))
+                .with_context(|| format!("Failed to create package.json: {}", package_json_path.display()))?;
+        }
+
+        if Self::workspace_manager(config) == "pnpm" {
+            let pnpm_workspace_path = workspace_path.join("pnpm-workspace.yaml");
+            ContentUpdater::update_managed_section(
+                fs,
+                &pnpm_workspace_path,
+                &Self::generate_pnpm_packages_content(&members),
+                "# start auto exported by moli.\n",
+                "# end auto exported by moli.",
+            ).with_context(|| format!("Failed to update pnpm-workspace.yaml: {}", pnpm_workspace_path.display()))?;
+        } else {
+            ContentUpdater::update_json_field(
+                fs,
+                &package_json_path,
+                "workspaces",
+                json!(members),
+            ).with_context(|| format!("Failed to update package.json workspaces: {}", package_json_path.display()))?;
+        }
+
+        Self::generate_root_tsconfig(fs, workspace_path, config)?;
+
+        Ok(())
+    }
+
+    /// Maintain a root tsconfig.json referencing every TypeScript project so `tsc -b` builds them all
+    fn generate_root_tsconfig(fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        let references: Vec<String> = config.projects()
+            .iter()
+            .filter(|p| p.language() == "typescript" && p.name() != ".")
+            .map(|p| p.name().to_string())
+            .collect();
+
+        if references.is_empty() {
+            return Ok(());
+        }
+
+        let tsconfig_path = workspace_path.join("tsconfig.json");
+        if !fs.exists(&tsconfig_path) {
+            fs.write(&tsconfig_path, "{\n  \"files\": []\n}\n")
+                .with_context(|| format!("Failed to create tsconfig.json: {}", tsconfig_path.display()))?;
+        }
+
+        ContentUpdater::update_json_field(fs, &tsconfig_path, "references", Self::references_content(&references))
+            .with_context(|| format!("Failed to update tsconfig.json references: {}", tsconfig_path.display()))
+    }
+
+    /// Make a TypeScript member project composite and point its references at its `depends_on` projects
+    pub fn update_project_references<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+        config: &MoliConfig,
+    ) -> Result<()> {
+        let tsconfig_path = project_path.as_ref().join("tsconfig.json");
+        if !fs.exists(&tsconfig_path) {
+            return Ok(());
+        }
+
+        // Project references require composite builds
+        ContentUpdater::update_json_object(fs, &tsconfig_path, |root| {
+            let compiler_options = root.entry("compilerOptions")
+                .or_insert_with(|| json!({}))
+                .as_object_mut()
+                .ok_or_else(|| anyhow!("\"compilerOptions\" is not an object"))?;
+            if !compiler_options.contains_key("composite") {
+                compiler_options.shift_insert(0, "composite".to_string(), Value::Bool(true));
+            }
+            Ok(())
+        })?;
+
+        // Relative path from this project back to the workspace root
+        let to_root = "../".repeat(project.name().split('/').count());
+        let references: Vec<String> = project.dependencies()
+            .iter()
+            .filter(|dep| config.projects().iter().any(|p| p.name() == dep.as_str() && p.language() == "typescript"))
+            .map(|dep| format!("{}{}", to_root, dep))
+            .collect();
+
+        ContentUpdater::update_json_field(fs, &tsconfig_path, "references", Self::references_content(&references))
+            .with_context(|| format!("Failed to update tsconfig.json references: {}", tsconfig_path.display()))
+    }
+
+    /// Render a tsconfig `references` array
+    fn references_content(paths: &[String]) -> Value {
+        paths.iter()
+            .map(|path| json!({ "path": path }))
+            .collect()
+    }
+
+    /// Generate pnpm-workspace.yaml packages list
+    fn generate_pnpm_packages_content(members: &[String]) -> String {
+        if members.is_empty() {
+            "packages: []".to_string()
+        } else {
+            format!(
+                "packages:\n{}",
+                members.iter()
+                    .map(|m| format!("  - \"{}\"", m))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        }
+    }
+
+    /// Generate root package.json content
+    fn generate_root_package_json_content(fs: &dyn Fs, workspace_path: &Path) -> String {
+        let dir_name = paths::directory_name(fs, workspace_path, "workspace");
+
+        format!(
+            r#"{{
+  "name": "{}",
+  "private": true
+}}
+"#,
+            dir_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn read_json(path: &Path) -> Value {
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    fn make_config(workspace: Option<&str>) -> MoliConfig {
+        MoliConfig {
+            projects: vec![
+                Project {
+                    name: "core".to_string(),
+                    lang: "typescript".to_string(),
+                    scope: Some("@acme".to_string()),
+                    workspace: workspace.map(|w| w.to_string()),
+                    ..Default::default()
+                },
+                Project {
+                    name: "web".to_string(),
+                    lang: "typescript".to_string(),
+                    scope: Some("acme".to_string()),
+                    depends_on: vec!["core".to_string()],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scoped_package_name() {
+        let config = make_config(None);
+        assert_eq!(NodeWorkspaceHandler::package_name(&config.projects()[0]), "@acme/core");
+        assert_eq!(NodeWorkspaceHandler::package_name(&config.projects()[1]), "@acme/web");
+    }
+
+    #[test]
+    fn test_npm_workspaces_and_references() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = make_config(None);
+
+        NodeWorkspaceHandler::generate_workspace(&RealFs, base_path, &config).unwrap();
+
+        let package_json = fs::read_to_string(base_path.join("package.json")).unwrap();
+        assert!(package_json.contains("\"private\": true,\n  \"workspaces\": [\n    \"core\",\n    \"web\"\n  ]"));
+
+        let tsconfig = read_json(&base_path.join("tsconfig.json"));
+        assert_eq!(tsconfig["references"], json!([{ "path": "core" }, { "path": "web" }]));
+
+        let web_path = base_path.join("web");
+        fs::create_dir_all(&web_path).unwrap();
+        fs::write(web_path.join("tsconfig.json"), "{\n  \"compilerOptions\": {\n    \"strict\": true\n  }\n}\n").unwrap();
+        NodeWorkspaceHandler::update_project_references(&RealFs, &web_path, &config.projects()[1], &config).unwrap();
+
+        let web_tsconfig = read_json(&web_path.join("tsconfig.json"));
+        assert_eq!(web_tsconfig["compilerOptions"], json!({ "composite": true, "strict": true }));
+        assert_eq!(web_tsconfig["references"], json!([{ "path": "../core" }]));
+    }
+
+    #[test]
+    fn test_existing_nested_values_are_replaced_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = make_config(None);
+        // Yarn's object form of workspaces, and a nested key with the same name
+        fs::write(base_path.join("package.json"), r#"{
+  "name": "root",
+  "config": { "workspaces": ["keep"] },
+  "workspaces": { "packages": ["old/*"], "nohoist": ["**/x"] }
+}
+"#).unwrap();
+
+        NodeWorkspaceHandler::generate_workspace(&RealFs, base_path, &config).unwrap();
+
+        let content = fs::read_to_string(base_path.join("package.json")).unwrap();
+        assert_eq!(content.matches("\"workspaces\"").count(), 2);
+        let package_json: Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(package_json["workspaces"], json!(["core", "web"]));
+        assert_eq!(package_json["config"], json!({ "workspaces": ["keep"] }));
+    }
+
+    #[test]
+    fn test_workspaces_inserted_after_package_header() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = make_config(None);
+        fs::write(
+            base_path.join("package.json"),
+            r#"{"name":"root","version":"0.1.0","private":true,"scripts":{"build":"tsc -b"}}"#,
+        ).unwrap();
+
+        NodeWorkspaceHandler::generate_workspace(&RealFs, base_path, &config).unwrap();
+
+        let package_json = read_json(&base_path.join("package.json"));
+        let keys: Vec<&String> = package_json.as_object().unwrap().keys().collect();
+        assert_eq!(keys, vec!["name", "version", "private", "workspaces", "scripts"]);
+    }
+
+    #[test]
+    fn test_project_references_with_compact_formatting() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = make_config(None);
+        let web_path = temp_dir.path().join("web");
+        fs::create_dir_all(&web_path).unwrap();
+        fs::write(
+            web_path.join("tsconfig.json"),
+            r#"{"compilerOptions":{"strict":true,"paths":{"@/*":["src/*"]}},"references":[{"path":"../old","prepend":false}]}"#,
+        ).unwrap();
+
+        NodeWorkspaceHandler::update_project_references(&RealFs, &web_path, &config.projects()[1], &config).unwrap();
+
+        let tsconfig = read_json(&web_path.join("tsconfig.json"));
+        assert_eq!(tsconfig["compilerOptions"]["composite"], json!(true));
+        assert_eq!(tsconfig["compilerOptions"]["paths"], json!({ "@/*": ["src/*"] }));
+        assert_eq!(tsconfig["references"], json!([{ "path": "../core" }]));
+    }
+
+    #[test]
+    fn test_pnpm_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = make_config(Some("pnpm"));
+
+        NodeWorkspaceHandler::generate_workspace(&RealFs, base_path, &config).unwrap();
+
+        let pnpm_workspace = fs::read_to_string(base_path.join("pnpm-workspace.yaml")).unwrap();
+        assert!(pnpm_workspace.contains("packages:\n  - \"core\"\n  - \"web\""));
+
+        let package_json = fs::read_to_string(base_path.join("package.json")).unwrap();
+        assert!(!package_json.contains("workspaces"));
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::java::generator::JavaGenerator;
+use crate::code_generation::language::kotlin::package_generator::KotlinPackageGenerator;
+use crate::code_generation::language::kotlin::gradle_handler::KotlinGradleHandler;
+use crate::code_generation::core::gradle_settings::GradleSettings;
+use crate::shared::utils::file_system::Fs;
+
+/// Kotlin packages with Gradle Kotlin DSL build files
+pub struct KotlinGenerator;
+
+impl LanguageGenerator for KotlinGenerator {
+    fn name(&self) -> &str {
+        "kotlin"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("kt")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["package"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        KotlinGradleHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        for (i, module) in project.tree().iter().enumerate() {
+            JavaGenerator::check_package_names(
+                module,
+                &[],
+                KotlinPackageGenerator::SOURCE_DIRS,
+                &format!("{}.tree[{}]", path, i),
+                &mut errors,
+            );
+        }
+        errors
+    }
+
+    fn generate_workspace(&self, fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        if GradleSettings::should_generate(config) {
+            GradleSettings::generate(fs, workspace_path, config)?;
+        }
+        Ok(())
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["build.gradle.kts", "settings.gradle.kts"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec![".gradle"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Gradle standard: src/main/kotlin/<package path>
+        r#"  tree:
+    - name: src
+      tree:
+        - name: main
+          tree:
+            - name: kotlin
+              tree:
+                - name: com
+                  tree:
+                    - name: example
+                      file:
+                        - name: Main
+"#.to_string()
+    }
+}
@@ -0,0 +1,193 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::code_generation::language::java::build_handler::JavaBuildHandler;
+use super::package_generator::KotlinPackageGenerator;
+
+/// Kotlin Gradle handler for generating Kotlin project structure and build files
+pub struct KotlinGradleHandler;
+
+impl KotlinGradleHandler {
+    /// Generate complete Kotlin project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate build.gradle.kts
+        Self::generate_build_gradle(fs, project_path, project)?;
+
+        // Generate project-level code files (default package)
+        for codefile in project.files() {
+            KotlinPackageGenerator::generate_file(fs, project_path, codefile, None)?;
+        }
+
+        // Generate package structure
+        for module in project.tree() {
+            KotlinPackageGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// Check if the project uses Kotlin Multiplatform source sets (src/commonMain)
+    pub fn is_multiplatform(project: &Project) -> bool {
+        project.tree()
+            .iter()
+            .filter(|m| m.name() == "src")
+            .flat_map(|m| m.subtree())
+            .any(|m| m.name() == "commonMain")
+    }
+
+    /// Generate build.gradle.kts (only if it doesn't exist)
+    pub fn generate_build_gradle<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let build_gradle_path = project_path.as_ref().join("build.gradle.kts");
+
+        if !fs.exists(&build_gradle_path) {
+            let content = Self::generate_build_gradle_content(
+                JavaBuildHandler::group_id(project),
+                Self::is_multiplatform(project),
+            );
+            fs.write(&build_gradle_path, content)
+                .with_context(|| format!("Failed to create build.gradle.kts: {}", build_gradle_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate build.gradle.kts content for a JVM or multiplatform project
+    fn generate_build_gradle_content(group_id: &str, multiplatform: bool) -> String {
+        if multiplatform {
+            return format!(
+                r#"plugins {{
+    kotlin("multiplatform") version "2.0.0"
+}}
+
+group = "{}"
+version = "1.0.0"
+
+repositories {{
+    mavenCentral()
+}}
+
+kotlin {{
+    jvm()
+
+    sourceSets {{
+        commonTest.dependencies {{
+            implementation(kotlin("test"))
+        }}
+    }}
+}}
+"#,
+                group_id
+            );
+        }
+
+        format!(
+            r#"plugins {{
+    kotlin("jvm") version "2.0.0"
+}}
+
+group = "{}"
+version = "1.0.0"
+
+repositories {{
+    mavenCentral()
+}}
+
+dependencies {{
+    testImplementation(kotlin("test"))
+}}
+
+tasks.test {{
+    useJUnitPlatform()
+}}
+
+kotlin {{
+    jvmToolchain(17)
+}}
+"#,
+            group_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::{Module, CodeFile};
+    use tempfile::TempDir;
+
+    fn source_set(name: &str) -> Module {
+        Module {
+            name: Some(name.to_string()),
+            tree: vec![Module {
+                name: Some("kotlin".to_string()),
+                tree: vec![Module {
+                    name: Some("app".to_string()),
+                    file: vec![CodeFile { name: "Main".to_string(), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn make_project(source_sets: &[&str]) -> Project {
+        Project {
+            name: "api".to_string(),
+            lang: "kotlin".to_string(),
+            tree: vec![Module {
+                name: Some("src".to_string()),
+                tree: source_sets.iter().map(|name| source_set(name)).collect(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_jvm_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        KotlinGradleHandler::generate_project(&RealFs, base_path, &make_project(&["main"])).unwrap();
+
+        let build_gradle = fs::read_to_string(base_path.join("build.gradle.kts")).unwrap();
+        assert!(build_gradle.contains("kotlin(\"jvm\")"));
+        assert!(build_gradle.contains("group = \"com.example\""));
+
+        let main = fs::read_to_string(base_path.join("src/main/kotlin/app/Main.kt")).unwrap();
+        assert_eq!(main, "package app\n\n");
+    }
+
+    #[test]
+    fn test_generate_multiplatform_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        KotlinGradleHandler::generate_project(&RealFs, base_path, &make_project(&["commonMain", "jvmMain"])).unwrap();
+
+        let build_gradle = fs::read_to_string(base_path.join("build.gradle.kts")).unwrap();
+        assert!(build_gradle.contains("kotlin(\"multiplatform\")"));
+
+        let common = fs::read_to_string(base_path.join("src/commonMain/kotlin/app/Main.kt")).unwrap();
+        assert_eq!(common, "package app\n\n");
+        assert!(base_path.join("src/jvmMain/kotlin/app/Main.kt").exists());
+    }
+}
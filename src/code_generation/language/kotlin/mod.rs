@@ -0,0 +1,5 @@
+// start auto exported by moli.
+pub mod package_generator;
+pub mod gradle_handler;
+pub mod generator;
+// end auto exported by moli.
@@ -0,0 +1,124 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile};
+use crate::code_generation::language::java::package_generator::JavaPackageGenerator;
+
+/// Kotlin-specific package generator
+pub struct KotlinPackageGenerator;
+
+impl KotlinPackageGenerator {
+    /// Source directory names that start a package hierarchy below src/<source set>/
+    /// (src/main/kotlin, src/commonMain/kotlin, src/jvmMain/kotlin, mixed src/main/java, ...)
+    pub const SOURCE_DIRS: &'static [&'static str] = &["kotlin", "java"];
+
+    /// Check if a file is a Kotlin code file (ends with .kt)
+    fn is_kotlin_code_file(filename: &str) -> bool {
+        filename.ends_with(".kt")
+    }
+
+    /// Generate Kotlin package structure recursively
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module_name.clone());
+
+        // src, source set (commonMain, jvmMain, test, ...) and kotlin directories are not packages
+        let package_name = JavaPackageGenerator::package_name(&module_segments, Self::SOURCE_DIRS);
+
+        // Generate code files in this package
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, package_name.as_deref())?;
+        }
+
+        // Process subpackages recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &module_segments)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a code file with a package declaration (only if it doesn't exist)
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        package_name: Option<&str>,
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("kotlin");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            // Only add a package declaration for Kotlin code files
+            let content = match package_name {
+                Some(name) if Self::is_kotlin_code_file(&filename) => format!("package {}\n\n", name),
+                _ => String::new(),
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn segments(path: &str) -> Vec<String> {
+        path.split('/').map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_multiplatform_source_sets_are_not_packages() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let module = Module {
+            name: Some("acme".to_string()),
+            file: vec![CodeFile { name: "Greeting".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+
+        KotlinPackageGenerator::generate_module(&RealFs, base_path.join("common"), &module, &segments("src/commonMain/kotlin/com")).unwrap();
+        KotlinPackageGenerator::generate_module(&RealFs, base_path.join("jvm"), &module, &segments("src/jvmMain/kotlin/com")).unwrap();
+
+        for dir in ["common", "jvm"] {
+            let content = fs::read_to_string(base_path.join(dir).join("acme/Greeting.kt")).unwrap();
+            assert_eq!(content, "package com.acme\n\n");
+        }
+    }
+
+    #[test]
+    fn test_default_package_file_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let module = Module {
+            name: Some("kotlin".to_string()),
+            file: vec![CodeFile { name: "Main".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+
+        KotlinPackageGenerator::generate_module(&RealFs, base_path, &module, &segments("src/main")).unwrap();
+
+        assert_eq!(fs::read_to_string(base_path.join("kotlin/Main.kt")).unwrap(), "");
+    }
+}
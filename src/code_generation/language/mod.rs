@@ -4,6 +4,19 @@ pub mod go;
 pub mod python;
 pub mod javascript;
 pub mod typescript;
+pub mod java;
+pub mod kotlin;
+pub mod c;
+pub mod csharp;
+pub mod dart;
+pub mod swift;
+pub mod zig;
+pub mod php;
+pub mod ruby;
+pub mod elixir;
+pub mod haskell;
 pub mod any;
+pub mod custom;
+pub mod plugin;
 // end auto exported by moli.
 
@@ -0,0 +1,176 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+use super::namespace_generator::PhpNamespaceGenerator;
+
+/// Composer handler for generating PHP project structure
+pub struct PhpComposerHandler;
+
+impl PhpComposerHandler {
+    /// Generate complete PHP project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+        let root_namespace = PhpNamespaceGenerator::root_namespace(project);
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate project-level files (outside of any PSR-4 root)
+        for codefile in project.files() {
+            PhpNamespaceGenerator::generate_file(fs, project_path, codefile, None)?;
+        }
+
+        // Generate each top-level tree entry as a PSR-4 root
+        for module in project.tree() {
+            let namespace = PhpNamespaceGenerator::top_level_namespace(root_namespace, &module.name());
+            PhpNamespaceGenerator::generate_module(fs, project_path, module, &namespace)?;
+        }
+
+        // Generate composer.json and keep autoload.psr-4 in sync with the tree
+        Self::generate_composer_json(fs, project_path, project)?;
+
+        Ok(())
+    }
+
+    /// Generate composer.json (created once) and update its managed `autoload.psr-4` map
+    pub fn generate_composer_json<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+        let composer_json_path = project_path.join("composer.json");
+        let root_namespace = PhpNamespaceGenerator::root_namespace(project);
+
+        if !fs.exists(&composer_json_path) {
+            let package_name = Self::package_name(fs, project_path, project, root_namespace);
+            fs.write(&composer_json_path, Self::generate_composer_json_content(&package_name))
+                .with_context(|| format!("Failed to create composer.json: {}", composer_json_path.display()))?;
+        }
+
+        let entries = Self::psr4_entries(project, root_namespace);
+        ContentUpdater::update_composer_autoload(fs, &composer_json_path, &entries)
+            .with_context(|| format!("Failed to update composer.json autoload: {}", composer_json_path.display()))
+    }
+
+    /// Build the `autoload.psr-4` entries (namespace prefix, directory) for each top-level tree entry
+    pub fn psr4_entries(project: &Project, root_namespace: &str) -> Vec<(String, String)> {
+        project.tree().iter()
+            .map(|module| {
+                let namespace = PhpNamespaceGenerator::top_level_namespace(root_namespace, &module.name());
+                (format!("{}\\", namespace), format!("{}/", module.name()))
+            })
+            .collect()
+    }
+
+    /// Get the Composer package name: <vendor>/<project> in lowercase (App + my_shop -> app/my-shop)
+    fn package_name(fs: &dyn Fs, project_path: &Path, project: &Project, root_namespace: &str) -> String {
+        let project_name = if project.name() == "." {
+            paths::directory_name(fs, project_path, "project")
+        } else {
+            project.name().rsplit('/').next().unwrap_or(project.name()).to_string()
+        };
+        let vendor = root_namespace.split('\\').next().unwrap_or(PhpNamespaceGenerator::DEFAULT_ROOT_NAMESPACE);
+
+        format!("{}/{}", Self::composer_segment(vendor), Self::composer_segment(&project_name))
+    }
+
+    /// Lowercase a name and replace characters Composer does not allow in package names
+    fn composer_segment(name: &str) -> String {
+        name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c.to_ascii_lowercase() } else { '-' })
+            .collect()
+    }
+
+    /// Generate composer.json content
+    fn generate_composer_json_content(package_name: &str) -> String {
+        format!(
+            r#"{{
+  "name": "{}",
+  "type": "project",
+  "require": {{
+    "php": ">=8.2"
+  }},
+  "autoload": {{
+    "psr-4": {{}}
+  }}
+}}
+"#,
+            package_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::{CodeFile, Module, PackageSetting};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = Project {
+            name: "my_shop".to_string(),
+            lang: "php".to_string(),
+            package: Some(PackageSetting::Name("Acme\\Shop".to_string())),
+            file: vec![CodeFile { name: "index".to_string(), ..Default::default() }],
+            tree: vec![
+                Module {
+                    name: Some("src".to_string()),
+                    file: vec![CodeFile { name: "Kernel".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+                Module { name: Some("tests".to_string()), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        PhpComposerHandler::generate_project(&RealFs, temp_dir.path(), &project).unwrap();
+
+        let composer_json = fs::read_to_string(temp_dir.path().join("composer.json")).unwrap();
+        assert!(composer_json.contains("\"name\": \"acme/my-shop\""));
+        assert!(composer_json.contains(
+            "\"psr-4\": {\n      \"Acme\\\\Shop\\\\\": \"src/\",\n      \"Acme\\\\Shop\\\\Tests\\\\\": \"tests/\"\n    }"
+        ));
+
+        let kernel = fs::read_to_string(temp_dir.path().join("src/Kernel.php")).unwrap();
+        assert!(kernel.contains("namespace Acme\\Shop;\n\nclass Kernel"));
+
+        let index = fs::read_to_string(temp_dir.path().join("index.php")).unwrap();
+        assert!(index.contains("require __DIR__ . '/vendor/autoload.php';"));
+    }
+
+    #[test]
+    fn test_autoload_added_to_existing_composer_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let composer_json_path = temp_dir.path().join("composer.json");
+        fs::write(&composer_json_path, "{\n  \"name\": \"app/legacy\",\n  \"autoload\": {\n    \"files\": [\"helpers.php\"]\n  }\n}\n").unwrap();
+
+        let project = Project {
+            name: ".".to_string(),
+            lang: "php".to_string(),
+            tree: vec![Module { name: Some("src".to_string()), ..Default::default() }],
+            ..Default::default()
+        };
+        PhpComposerHandler::generate_composer_json(&RealFs, temp_dir.path(), &project).unwrap();
+
+        let composer_json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&composer_json_path).unwrap()).unwrap();
+        assert_eq!(composer_json["autoload"], serde_json::json!({
+            "psr-4": { "App\\": "src/" },
+            "files": ["helpers.php"]
+        }));
+        let autoload_keys: Vec<&String> = composer_json["autoload"].as_object().unwrap().keys().collect();
+        assert_eq!(autoload_keys, vec!["psr-4", "files"]);
+    }
+}
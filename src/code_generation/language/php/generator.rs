@@ -0,0 +1,85 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{Project, Module};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::php::namespace_generator::PhpNamespaceGenerator;
+use crate::code_generation::language::php::composer_handler::PhpComposerHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// PHP projects with PSR-4 namespaces and composer autoload
+pub struct PhpGenerator;
+
+impl LanguageGenerator for PhpGenerator {
+    fn name(&self) -> &str {
+        "php"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("php")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["package"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        PhpComposerHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if let Some(root_namespace) = project.package_setting() {
+            if !root_namespace.trim_matches('\\').split('\\').all(PhpNamespaceGenerator::is_valid_namespace_segment) {
+                errors.push(ValidationError {
+                    message: format!("Invalid root namespace: {}", root_namespace),
+                    path: format!("{}.package", path),
+                });
+            }
+        }
+
+        // Top-level entries are mapped to a namespace by composer.json, so only nested directories are checked
+        for (i, module) in project.tree().iter().enumerate() {
+            for (j, submodule) in module.subtree().iter().enumerate() {
+                Self::check_namespace_dirs(submodule, &format!("{}.tree[{}].tree[{}]", path, i, j), &mut errors);
+            }
+        }
+
+        errors
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["composer.json", "composer.lock"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["vendor"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Composer standard: PSR-4 src/ and tests/ with a front controller
+        r#"  file:
+    - name: index
+  tree:
+    - name: src
+    - name: tests
+"#.to_string()
+    }
+}
+
+impl PhpGenerator {
+    /// Check that a directory and everything below it are valid namespace segments
+    fn check_namespace_dirs(module: &Module, path: &str, errors: &mut Vec<ValidationError>) {
+        if !PhpNamespaceGenerator::is_valid_namespace_segment(&module.name()) {
+            errors.push(ValidationError {
+                message: format!("Invalid namespace directory name: {}", module.name()),
+                path: format!("{}.name", path),
+            });
+        }
+
+        for (i, submodule) in module.subtree().iter().enumerate() {
+            Self::check_namespace_dirs(submodule, &format!("{}.tree[{}]", path, i), errors);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+// start auto exported by moli.
+pub mod namespace_generator;
+pub mod composer_handler;
+pub mod generator;
+// end auto exported by moli.
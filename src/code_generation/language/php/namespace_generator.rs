@@ -0,0 +1,171 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile, Project};
+
+/// PHP-specific PSR-4 namespace generator
+pub struct PhpNamespaceGenerator;
+
+impl PhpNamespaceGenerator {
+    /// Root namespace used when the project has no `package` setting
+    pub const DEFAULT_ROOT_NAMESPACE: &'static str = "App";
+
+    /// Check if a file is a PHP code file (ends with .php)
+    fn is_php_code_file(filename: &str) -> bool {
+        filename.ends_with(".php")
+    }
+
+    /// Get the root namespace (`package: Acme\Shop`, defaults to "App")
+    pub fn root_namespace(project: &Project) -> &str {
+        project.package_setting()
+            .map(|name| name.trim_matches('\\'))
+            .unwrap_or(Self::DEFAULT_ROOT_NAMESPACE)
+    }
+
+    /// Get the namespace a top-level tree entry is mapped to in composer.json
+    /// src/ maps to the root namespace, other directories add their StudlyCaps name (tests -> App\Tests)
+    pub fn top_level_namespace(root_namespace: &str, module_name: &str) -> String {
+        if module_name == "src" {
+            root_namespace.to_string()
+        } else {
+            format!("{}\\{}", root_namespace, Self::studly_case(module_name))
+        }
+    }
+
+    /// Generate PHP directory structure recursively
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        namespace: &str,
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        // Generate code files in this namespace
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, Some(namespace))?;
+        }
+
+        // PSR-4: each subdirectory adds a namespace segment (Domain/User -> App\Domain\User)
+        for submodule in module.subtree() {
+            let sub_namespace = format!("{}\\{}", namespace, submodule.name());
+            Self::generate_module(fs, &module_path, submodule, &sub_namespace)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a code file with a namespace declaration (only if it doesn't exist)
+    /// StudlyCaps files get a class skeleton; project-level files have no namespace
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        namespace: Option<&str>,
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("php");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            let content = if Self::is_php_code_file(&filename) {
+                Self::generate_php_file_content(namespace, filename.trim_end_matches(".php"))
+            } else {
+                String::new() // Non-PHP files get no content
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Check if a name is a valid PHP namespace segment
+    pub fn is_valid_namespace_segment(segment: &str) -> bool {
+        segment.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    /// Convert a directory name to StudlyCaps (database-seeders -> DatabaseSeeders)
+    fn studly_case(name: &str) -> String {
+        name.split(['-', '_', '.'])
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// Generate PHP file content
+    fn generate_php_file_content(namespace: Option<&str>, name: &str) -> String {
+        let mut content = String::from("<?php\n\ndeclare(strict_types=1);\n");
+
+        match namespace {
+            Some(namespace) => {
+                content.push_str(&format!("\nnamespace {};\n", namespace));
+                if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    content.push_str(&format!("\nclass {}\n{{\n}}\n", name));
+                }
+            }
+            None if name == "index" => {
+                content.push_str("\nrequire __DIR__ . '/vendor/autoload.php';\n");
+            }
+            None => {}
+        }
+
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_module_namespaces() {
+        let temp_dir = TempDir::new().unwrap();
+        let module = Module {
+            name: Some("src".to_string()),
+            tree: vec![Module {
+                name: Some("Domain".to_string()),
+                tree: vec![Module {
+                    name: Some("User".to_string()),
+                    file: vec![
+                        CodeFile { name: "User".to_string(), ..Default::default() },
+                        CodeFile { name: "helpers".to_string(), ..Default::default() },
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        PhpNamespaceGenerator::generate_module(&RealFs, temp_dir.path(), &module, "App").unwrap();
+
+        let user = fs::read_to_string(temp_dir.path().join("src/Domain/User/User.php")).unwrap();
+        assert_eq!(user, "<?php\n\ndeclare(strict_types=1);\n\nnamespace App\\Domain\\User;\n\nclass User\n{\n}\n");
+
+        let helpers = fs::read_to_string(temp_dir.path().join("src/Domain/User/helpers.php")).unwrap();
+        assert!(helpers.ends_with("namespace App\\Domain\\User;\n"));
+    }
+
+    #[test]
+    fn test_top_level_namespace() {
+        assert_eq!(PhpNamespaceGenerator::top_level_namespace("App", "src"), "App");
+        assert_eq!(PhpNamespaceGenerator::top_level_namespace("Acme\\Shop", "tests"), "Acme\\Shop\\Tests");
+        assert_eq!(PhpNamespaceGenerator::top_level_namespace("App", "database-seeders"), "App\\DatabaseSeeders");
+    }
+}
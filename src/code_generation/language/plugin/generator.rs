@@ -0,0 +1,275 @@
+use std::env;
+use std::ffi::OsStr;
+use crate::shared::utils::file_system::Fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use anyhow::{bail, Context, Result};
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::code_generation::language::plugin::protocol::{PluginRequest, PluginResponse, PROTOCOL_VERSION};
+use crate::project_management::config::models::{Project, Module};
+
+/// Prefix of languages served by external generator plugins (`lang: x-foo`)
+pub const PLUGIN_LANGUAGE_PREFIX: &str = "x-";
+
+/// Prefix of plugin executables looked up on PATH (`moli-gen-x-foo`)
+pub const PLUGIN_EXECUTABLE_PREFIX: &str = "moli-gen-";
+
+/// Generator that delegates to an external `moli-gen-<lang>` executable
+///
+/// The plugin receives the project subtree as JSON on stdin and answers with file operations on stdout.
+pub struct PluginGenerator {
+    language: String,
+    executable: PathBuf,
+}
+
+impl PluginGenerator {
+    /// Create a generator running a specific executable
+    pub fn new(language: &str, executable: PathBuf) -> Self {
+        Self { language: language.to_string(), executable }
+    }
+
+    /// Check if a language is served by a plugin (`x-` prefix)
+    pub fn is_plugin_language(language: &str) -> bool {
+        language.len() > PLUGIN_LANGUAGE_PREFIX.len() && language.starts_with(PLUGIN_LANGUAGE_PREFIX)
+    }
+
+    /// Get the plugin executable name for a language
+    pub fn executable_name(language: &str) -> String {
+        format!("{}{}", PLUGIN_EXECUTABLE_PREFIX, language)
+    }
+
+    /// Find the plugin for a language on PATH
+    pub fn resolve(language: &str) -> Option<Self> {
+        let search_path = env::var_os("PATH")?;
+        Self::resolve_in(language, &search_path)
+    }
+
+    /// Find the plugin for a language in a PATH-style list of directories
+    pub fn resolve_in(language: &str, search_path: &OsStr) -> Option<Self> {
+        if !Self::is_plugin_language(language) {
+            return None;
+        }
+        let executable_name = Self::executable_name(language);
+        env::split_paths(search_path)
+            .map(|dir| dir.join(&executable_name))
+            .find(|candidate| candidate.is_file())
+            .map(|executable| Self::new(language, executable))
+    }
+
+    /// Run the plugin for a project and parse its response
+    fn run(&self, project_path: &Path, project: &Project) -> Result<PluginResponse> {
+        let absolute_path = std::path::absolute(project_path)
+            .with_context(|| format!("Failed to resolve project path: {}", project_path.display()))?;
+        let request = PluginRequest {
+            version: PROTOCOL_VERSION,
+            language: &self.language,
+            project_path: absolute_path.display().to_string(),
+            project,
+        };
+        let request_json = serde_json::to_string(&request)
+            .context("Failed to serialize plugin request")?;
+
+        // The plugin inherits moli's working directory (the workspace root): the project directory
+        // may not exist on disk yet, since `moli up` records a plan before writing anything
+        let mut child = Command::new(&self.executable)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .with_context(|| format!("Failed to run generator plugin: {}", self.executable.display()))?;
+
+        child.stdin.take()
+            .context("Failed to open plugin stdin")?
+            .write_all(request_json.as_bytes())
+            .context("Failed to write plugin request")?;
+
+        let output = child.wait_with_output()
+            .with_context(|| format!("Failed to wait for generator plugin: {}", self.executable.display()))?;
+        if !output.status.success() {
+            bail!("Generator plugin {} failed with {}", self.executable.display(), output.status);
+        }
+
+        serde_json::from_slice(&output.stdout)
+            .with_context(|| format!("Invalid response from generator plugin: {}", self.executable.display()))
+    }
+
+    /// Create the directories declared in the module tree
+    fn create_directories(fs: &dyn Fs, parent_path: &Path, modules: &[Module]) -> Result<()> {
+        for module in modules {
+            let module_path = parent_path.join(module.name());
+            fs.create_dir_all(&module_path)
+                .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+            Self::create_directories(fs, &module_path, module.subtree())?;
+        }
+        Ok(())
+    }
+}
+
+impl LanguageGenerator for PluginGenerator {
+    fn name(&self) -> &str {
+        &self.language
+    }
+
+    fn extension(&self) -> Option<&str> {
+        None
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        Self::create_directories(fs, project_path, project.tree())?;
+
+        let response = self.run(project_path, project)?;
+
+        // Check every path before writing anything
+        for operation in &response.operations {
+            operation.resolve_path(project_path)?;
+        }
+        for operation in &response.operations {
+            operation.apply(fs, project_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::TempDir;
+    use std::sync::Arc;
+    use crate::code_generation::core::generator::CodeGenerator;
+    use crate::code_generation::core::plan::{Plan, PlannedOperation};
+    use crate::code_generation::core::language_registry::LanguageRegistry;
+    use crate::project_management::config::models::{CodeFile, MoliConfig};
+
+    /// Write a fake plugin that saves its request next to itself and prints a fixed response
+    fn fake_plugin(dir: &Path, language: &str, response: &str) -> PathBuf {
+        let path = dir.join(PluginGenerator::executable_name(language));
+        let script = format!("#!/bin/sh\ncat > \"$(dirname \"$0\")/request.json\"\ncat <<'JSON'\n{}\nJSON\n", response);
+        fs::write(&path, script).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    fn project() -> Project {
+        Project {
+            name: "schema".to_string(),
+            root: true,
+            lang: "x-foo".to_string(),
+            tree: vec![Module {
+                name: Some("api".to_string()),
+                file: vec![CodeFile { name: "user".to_string(), ..Default::default() }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve_on_search_path() {
+        let bin_dir = TempDir::new().unwrap();
+        fake_plugin(bin_dir.path(), "x-foo", "{}");
+
+        assert!(PluginGenerator::resolve_in("x-foo", bin_dir.path().as_os_str()).is_some());
+        assert!(PluginGenerator::resolve_in("x-bar", bin_dir.path().as_os_str()).is_none());
+        assert!(PluginGenerator::resolve_in("foo", bin_dir.path().as_os_str()).is_none());
+    }
+
+    #[test]
+    fn test_apply_plugin_operations() {
+        let bin_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let base_path = project_dir.path();
+        fs::create_dir_all(base_path.join("api")).unwrap();
+        fs::write(base_path.join("api/user.foo"), "user code\n").unwrap();
+        fs::write(base_path.join("api/index.foo"), "custom header\n").unwrap();
+
+        let executable = fake_plugin(bin_dir.path(), "x-foo", r#"{"operations": [
+            {"op": "create_if_missing", "path": "api/user.foo", "content": "generated\n"},
+            {"op": "create_if_missing", "path": "api/order.foo"},
+            {"op": "write_once", "path": "foo.toml", "content": "name = \"schema\"\n"},
+            {"op": "update_managed_section", "path": "api/index.foo", "content": "use user\n", "comment": "--"}
+        ]}"#);
+
+        PluginGenerator::new("x-foo", executable).generate_project(&RealFs, base_path, &project()).unwrap();
+
+        let request = fs::read_to_string(bin_dir.path().join("request.json")).unwrap();
+        assert!(request.contains("\"language\":\"x-foo\""));
+        assert!(request.contains(&format!("\"project_path\":\"{}\"", base_path.display())));
+        assert!(request.contains("\"name\":\"user\""));
+
+        assert_eq!(fs::read_to_string(base_path.join("api/user.foo")).unwrap(), "user code\n");
+        assert!(base_path.join("api/order.foo").exists());
+        assert_eq!(fs::read_to_string(base_path.join("foo.toml")).unwrap(), "name = \"schema\"\n");
+        assert_eq!(
+            fs::read_to_string(base_path.join("api/index.foo")).unwrap(),
+            "-- start auto exported by moli.\nuse user\n-- end auto exported by moli.\n\ncustom header"
+        );
+    }
+
+    #[test]
+    fn test_plugin_runs_in_workspace_root_through_up() {
+        let bin_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let base_path = project_dir.path();
+
+        // Reports its working directory back as a file
+        let executable = bin_dir.path().join(PluginGenerator::executable_name("x-cwd"));
+        fs::write(&executable, "#!/bin/sh\ncat > /dev/null\nprintf '{\"operations\": [{\"op\": \"create_if_missing\", \"path\": \"cwd.txt\", \"content\": \"%s\"}]}' \"$(pwd)\"\n").unwrap();
+        fs::set_permissions(&executable, fs::Permissions::from_mode(0o755)).unwrap();
+        let _registration = LanguageRegistry::register_scoped(Arc::new(PluginGenerator::new("x-cwd", executable)));
+
+        let config = MoliConfig {
+            projects: vec![Project { lang: "x-cwd".to_string(), ..project() }],
+            ..Default::default()
+        };
+        CodeGenerator::generate_transactional(&RealFs, base_path, &config).unwrap();
+
+        // Runs in moli's working directory rather than the project directory
+        assert!(base_path.join("api").is_dir());
+        assert_eq!(
+            fs::read_to_string(base_path.join("cwd.txt")).unwrap(),
+            env::current_dir().unwrap().display().to_string()
+        );
+    }
+
+    #[test]
+    fn test_plugin_operations_are_only_planned_in_preview() {
+        let bin_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let base_path = project_dir.path();
+        let executable = fake_plugin(bin_dir.path(), "x-foo", r#"{"operations": [
+            {"op": "create_if_missing", "path": "foo.toml", "content": "name = \"schema\"\n"}
+        ]}"#);
+        let generator = PluginGenerator::new("x-foo", executable);
+
+        // `moli up --dry-run`/`--check` run the plugin, but its operations only end up in the plan
+        let plan = Plan::record(&RealFs, |fs| generator.generate_project(fs, base_path, &project())).unwrap();
+
+        assert!(bin_dir.path().join("request.json").exists());
+        assert!(plan.operations().contains(&PlannedOperation::CreateFile {
+            path: base_path.join("foo.toml"),
+            content: "name = \"schema\"\n".to_string(),
+        }));
+        assert!(!base_path.join("foo.toml").exists());
+        assert!(!base_path.join("api").exists());
+    }
+
+    #[test]
+    fn test_reject_paths_outside_project() {
+        let bin_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let executable = fake_plugin(bin_dir.path(), "x-foo", r#"{"operations": [
+            {"op": "create_if_missing", "path": "ok.foo"},
+            {"op": "create_if_missing", "path": "../escape.foo"}
+        ]}"#);
+
+        let result = PluginGenerator::new("x-foo", executable).generate_project(&RealFs, project_dir.path(), &project());
+
+        assert!(result.is_err());
+        assert!(!project_dir.path().join("ok.foo").exists());
+    }
+}
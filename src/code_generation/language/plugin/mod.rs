@@ -0,0 +1,4 @@
+// start auto exported by moli.
+pub mod protocol;
+pub mod generator;
+// end auto exported by moli.
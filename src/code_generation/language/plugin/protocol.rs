@@ -0,0 +1,100 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::{Component, Path, PathBuf};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::content_updater::ContentUpdater;
+
+/// Version of the JSON protocol spoken with generator plugins
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Request written to the plugin's stdin
+#[derive(Debug, Serialize)]
+pub struct PluginRequest<'a> {
+    pub version: u32,
+    pub language: &'a str,
+    /// Absolute project directory (the plugin itself runs in the workspace root)
+    pub project_path: String,
+    /// Project subtree from moli.yml
+    pub project: &'a Project,
+}
+
+/// Response read from the plugin's stdout
+#[derive(Debug, Default, Deserialize)]
+pub struct PluginResponse {
+    #[serde(default)]
+    pub operations: Vec<PluginOperation>,
+}
+
+/// File operation returned by a plugin, applied with the built-in protection rules
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PluginOperation {
+    /// Create a code or configuration file; existing files are never overwritten
+    #[serde(alias = "write_once")]
+    CreateIfMissing {
+        path: String,
+        #[serde(default)]
+        content: String,
+    },
+    /// Replace the content between the moli markers, keeping everything else
+    UpdateManagedSection {
+        path: String,
+        #[serde(default)]
+        content: String,
+        /// Line comment prefix of the markers (defaults to "//")
+        #[serde(default)]
+        comment: Option<String>,
+    },
+}
+
+impl PluginOperation {
+    /// Get the project-relative path the operation writes
+    pub fn path(&self) -> &str {
+        match self {
+            PluginOperation::CreateIfMissing { path, .. }
+            | PluginOperation::UpdateManagedSection { path, .. } => path,
+        }
+    }
+
+    /// Resolve the operation's path inside the project, rejecting paths that leave it
+    pub fn resolve_path(&self, project_path: &Path) -> Result<PathBuf> {
+        let relative = Path::new(self.path());
+        let stays_inside = !self.path().is_empty()
+            && relative.components().all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+        if !stays_inside {
+            bail!("Plugin path must stay inside the project: {}", self.path());
+        }
+        Ok(project_path.join(relative))
+    }
+
+    /// Apply the operation below the project directory
+    pub fn apply(&self, fs: &dyn Fs, project_path: &Path) -> Result<()> {
+        let file_path = self.resolve_path(project_path)?;
+        if let Some(parent) = file_path.parent() {
+            fs.create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        match self {
+            PluginOperation::CreateIfMissing { content, .. } => {
+                if !fs.exists(&file_path) {
+                    fs.write(&file_path, content)
+                        .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+                }
+            }
+            PluginOperation::UpdateManagedSection { content, comment, .. } => {
+                let comment = comment.as_deref().unwrap_or("//");
+                ContentUpdater::update_managed_section(
+                    fs,
+                    &file_path,
+                    content.trim_end_matches('\n'),
+                    &format!("{} start auto exported by moli.\n", comment),
+                    &format!("{} end auto exported by moli.", comment),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
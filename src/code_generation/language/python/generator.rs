@@ -0,0 +1,101 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{Project, Module};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::python::package_generator::PythonPackageGenerator;
+use crate::code_generation::language::python::init_handler::PythonInitHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Python packages with managed __init__.py exports
+pub struct PythonGenerator;
+
+impl LanguageGenerator for PythonGenerator {
+    fn name(&self) -> &str {
+        "python"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("py")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["package", "namespace", "backend", "layout", "typed", "init"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        PythonInitHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if let Some(backend) = project.backend_setting() {
+            if PythonPackageGenerator::build_system(backend).is_none() {
+                errors.push(ValidationError {
+                    message: format!("Unsupported build backend: {} (expected setuptools, hatchling, flit or pdm)", backend),
+                    path: format!("{}.backend", path),
+                });
+            }
+        }
+
+        if let Some(layout) = project.layout.as_deref() {
+            if !matches!(layout, "flat" | "src") {
+                errors.push(ValidationError {
+                    message: format!("Unsupported layout: {} (expected flat or src)", layout),
+                    path: format!("{}.layout", path),
+                });
+            }
+        }
+
+        if !matches!(project.init_setting(), "star" | "all" | "submodules") {
+            errors.push(ValidationError {
+                message: format!("Unsupported init style: {} (expected star, all or submodules)", project.init_setting()),
+                path: format!("{}.init", path),
+            });
+        }
+
+        for (i, module) in project.tree().iter().enumerate() {
+            Self::validate_module_packages(module, &format!("{}.tree[{}]", path, i), &mut errors);
+        }
+
+        errors
+    }
+
+    fn aggregator_file(&self) -> Option<&str> {
+        Some("__init__.py")
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["pyproject.toml", "setup.py", "setup.cfg", "py.typed"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["__pycache__", ".venv", "venv"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Modern standard: src/ directory structure
+        r#"  tree:
+    - name: src
+      file:
+        - name: main
+"#.to_string()
+    }
+}
+
+impl PythonGenerator {
+    /// Check that `package` is used as an on/off flag in a module and its subtree
+    fn validate_module_packages(module: &Module, path: &str, errors: &mut Vec<ValidationError>) {
+        if module.package_setting().is_some() {
+            errors.push(ValidationError {
+                message: "Python modules accept 'package: true' or 'package: false', not a package name".to_string(),
+                path: format!("{}.package", path),
+            });
+        }
+
+        for (i, submodule) in module.subtree().iter().enumerate() {
+            Self::validate_module_packages(submodule, &format!("{}.tree[{}]", path, i), errors);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::Path;
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Project, Module};
@@ -10,24 +10,30 @@ pub struct PythonInitHandler;
 impl PythonInitHandler {
     /// Generate complete Python project structure
     pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         project: &Project,
     ) -> Result<()> {
         let project_path = project_path.as_ref();
         
         // Create project directory
-        fs::create_dir_all(project_path)
+        fs.create_dir_all(project_path)
             .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
 
-        // Generate requirements.txt
-        PythonPackageGenerator::generate_requirements_txt(project_path)?;
-        
-        // Generate setup.py
-        PythonPackageGenerator::generate_setup_py(project_path, project.name())?;
+        if project.backend_setting().is_some() {
+            // Generate PEP 621 pyproject.toml
+            PythonPackageGenerator::generate_pyproject_toml(fs, project_path, project)?;
+        } else {
+            // Generate requirements.txt
+            PythonPackageGenerator::generate_requirements_txt(fs, project_path)?;
+
+            // Generate setup.py
+            PythonPackageGenerator::generate_setup_py(fs, project_path, project.name())?;
+        }
         
         // Generate main.py if needed
         if PythonPackageGenerator::should_generate_main_py(project) {
-            PythonPackageGenerator::generate_main_py(project_path)?;
+            PythonPackageGenerator::generate_main_py(fs, project_path)?;
         }
         
         // Generate project-level code files
@@ -36,15 +42,15 @@ impl PythonInitHandler {
             let file_path = project_path.join(&filename);
             
             // Create empty Python file (only if it doesn't exist)
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
         }
         
         // Generate module structure
         for module in project.tree() {
-            PythonPackageGenerator::generate_module(project_path, module, &[])?;
+            PythonPackageGenerator::generate_module(fs, project_path, module, &[], project)?;
         }
 
         Ok(())
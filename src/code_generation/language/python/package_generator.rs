@@ -1,9 +1,20 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Module, CodeFile, Project};
 use crate::shared::utils::content_updater::ContentUpdater;
 
+/// How a Python directory is treated on import
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PythonPackageKind {
+    /// Regular package with a moli managed __init__.py
+    Regular,
+    /// PEP 420 namespace package (importable, no __init__.py)
+    Namespace,
+    /// Plain directory that is not imported as a package (tests, scripts, data)
+    Plain,
+}
+
 /// Python-specific package generator
 pub struct PythonPackageGenerator;
 
@@ -15,19 +26,34 @@ impl PythonPackageGenerator {
 
     /// Generate Python module structure recursively
     pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+        project: &Project,
+    ) -> Result<()> {
+        Self::generate_module_with_kind(fs, base_path, module, parent_modules, project, Self::default_package_kind(project))
+    }
+
+    /// Generate Python module structure, inheriting the package kind of the parent directory
+    fn generate_module_with_kind<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         module: &Module,
         parent_modules: &[String],
+        project: &Project,
+        inherited_kind: PythonPackageKind,
     ) -> Result<()> {
         let module_name = module.name();
+        let kind = Self::package_kind(module, inherited_kind);
         let module_path = base_path.as_ref().join(&module_name);
         
         // Create directory
-        fs::create_dir_all(&module_path)
+        fs.create_dir_all(&module_path)
             .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
 
-        // Collect import statements for __init__.py
-        let mut import_statements = Vec::new();
+        // Collect exported names for __init__.py
+        let mut exported_names = Vec::new();
 
         // Generate code files in this module
         for codefile in module.files() {
@@ -35,15 +61,14 @@ impl PythonPackageGenerator {
             let file_path = module_path.join(&filename);
             
             // Create empty Python file (only if it doesn't exist)
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
 
-            // Add to import statements if it's a Python code file and not __init__.py
+            // Add to exported names if it's a Python code file and not __init__.py
             if Self::is_python_code_file(&filename) && filename != "__init__.py" {
-                let module_name = Self::get_module_name_for_import(codefile);
-                import_statements.push(format!("from .{} import *", module_name));
+                exported_names.push(Self::get_module_name_for_import(codefile));
             }
         }
 
@@ -52,15 +77,131 @@ impl PythonPackageGenerator {
             let mut new_parent_modules = parent_modules.to_vec();
             new_parent_modules.push(module.name().to_string());
             
-            Self::generate_module(&module_path, submodule, &new_parent_modules)?;
+            Self::generate_module_with_kind(fs, &module_path, submodule, &new_parent_modules, project, Self::child_package_kind(kind, project))?;
             
-            // Add submodule import statement
-            import_statements.push(format!("from .{} import *", submodule.name()));
+            // Add submodule to exported names (plain directories are not importable)
+            if Self::package_kind(submodule, Self::child_package_kind(kind, project)) != PythonPackageKind::Plain {
+                exported_names.push(submodule.name());
+            }
+        }
+
+        // The src directory of a src layout only contains packages, it is not one itself
+        if project.is_src_layout() && parent_modules.is_empty() && module_name == "src" {
+            return Ok(());
         }
 
-        // Generate __init__.py (always create to mark as Python package)
+        // Namespace packages and plain directories have no __init__.py
+        if kind == PythonPackageKind::Plain {
+            return Ok(());
+        }
+        if kind == PythonPackageKind::Namespace {
+            if project.is_typed() && Self::is_top_level_package(parent_modules, project) {
+                Self::generate_py_typed(fs, &module_path)?;
+            }
+            return Ok(());
+        }
+
+        // Generate __init__.py to mark as regular Python package
         let init_py_path = module_path.join("__init__.py");
-        ContentUpdater::update_python_init_file(&init_py_path, &import_statements)?;
+        let import_statements = Self::generate_import_statements(&exported_names, project.init_setting());
+        ContentUpdater::update_python_init_file(fs, &init_py_path, &import_statements)?;
+
+        // Mark top-level packages as typed (PEP 561)
+        if project.is_typed() && Self::is_top_level_package(parent_modules, project) {
+            Self::generate_py_typed(fs, &module_path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the package kind directories of a project default to
+    pub fn default_package_kind(project: &Project) -> PythonPackageKind {
+        if project.package_flag() == Some(false) {
+            PythonPackageKind::Plain
+        } else if project.is_namespace() {
+            PythonPackageKind::Namespace
+        } else {
+            PythonPackageKind::Regular
+        }
+    }
+
+    /// Resolve the package kind of a module
+    /// Priority: `package: false` > `namespace` > `package: true` > inherited kind
+    pub fn package_kind(module: &Module, inherited_kind: PythonPackageKind) -> PythonPackageKind {
+        if module.package_flag() == Some(false) {
+            return PythonPackageKind::Plain;
+        }
+        match module.namespace_setting() {
+            Some(true) => return PythonPackageKind::Namespace,
+            Some(false) => return PythonPackageKind::Regular,
+            None => {}
+        }
+        if module.package_flag() == Some(true) {
+            return PythonPackageKind::Regular;
+        }
+        inherited_kind
+    }
+
+    /// Get the kind subdirectories inherit: plain directories stay plain
+    /// (tests/unit), everything else falls back to the project default
+    pub fn child_package_kind(kind: PythonPackageKind, project: &Project) -> PythonPackageKind {
+        if kind == PythonPackageKind::Plain {
+            PythonPackageKind::Plain
+        } else {
+            Self::default_package_kind(project)
+        }
+    }
+
+    /// Build __init__.py import statements for the given export style
+    /// - "star": `from .x import *`
+    /// - "submodules": `from . import x`
+    /// - "all": `from . import x` followed by an explicit `__all__` list
+    pub fn generate_import_statements(names: &[String], init_setting: &str) -> Vec<String> {
+        match init_setting {
+            "submodules" => names.iter()
+                .map(|name| format!("from . import {}", name))
+                .collect(),
+            "all" => {
+                if names.is_empty() {
+                    // No annotation: `list[str]` is not subscriptable at runtime before Python 3.9
+                    return vec!["__all__ = []".to_string()];
+                }
+                let mut statements: Vec<String> = names.iter()
+                    .map(|name| format!("from . import {}", name))
+                    .collect();
+                statements.push(String::new());
+                statements.push("__all__ = [".to_string());
+                statements.extend(names.iter().map(|name| format!("    \"{}\",", name)));
+                statements.push("]".to_string());
+                statements
+            }
+            _ => names.iter()
+                .map(|name| format!("from .{} import *", name))
+                .collect(),
+        }
+    }
+
+    /// Check if a module at this depth is a distribution's top-level package
+    fn is_top_level_package(parent_modules: &[String], project: &Project) -> bool {
+        if project.is_src_layout() {
+            parent_modules.len() == 1 && parent_modules[0] == "src"
+        } else {
+            parent_modules.is_empty()
+        }
+    }
+
+    /// Generate py.typed marker for a package
+    pub fn generate_py_typed<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        package_path: P,
+    ) -> Result<()> {
+        let py_typed_path = package_path.as_ref().join("py.typed");
+
+        // Only create py.typed if it doesn't already exist
+        if !fs.exists(&py_typed_path) {
+            fs.write(&py_typed_path, "")
+                .with_context(|| format!("Failed to create py.typed: {}", py_typed_path.display()))?;
+        }
 
         Ok(())
     }
@@ -78,14 +219,15 @@ impl PythonPackageGenerator {
 
     /// Generate requirements.txt for Python project
     pub fn generate_requirements_txt<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
     ) -> Result<()> {
         let requirements_path = project_path.as_ref().join("requirements.txt");
         let requirements_content = Self::generate_requirements_content();
         
         // Only create requirements.txt if it doesn't already exist
-        if !requirements_path.exists() {
-            fs::write(&requirements_path, requirements_content)
+        if !fs.exists(&requirements_path) {
+            fs.write(&requirements_path, requirements_content)
                 .with_context(|| format!("Failed to create requirements.txt: {}", requirements_path.display()))?;
         }
 
@@ -94,6 +236,7 @@ impl PythonPackageGenerator {
 
     /// Generate setup.py for Python project
     pub fn generate_setup_py<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         project_name: &str,
     ) -> Result<()> {
@@ -101,24 +244,54 @@ impl PythonPackageGenerator {
         let setup_content = Self::generate_setup_py_content(project_name);
         
         // Only create setup.py if it doesn't already exist
-        if !setup_py_path.exists() {
-            fs::write(&setup_py_path, setup_content)
+        if !fs.exists(&setup_py_path) {
+            fs.write(&setup_py_path, setup_content)
                 .with_context(|| format!("Failed to create setup.py: {}", setup_py_path.display()))?;
         }
 
         Ok(())
     }
 
+    /// Generate PEP 621 pyproject.toml for Python project
+    pub fn generate_pyproject_toml<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let pyproject_path = project_path.as_ref().join("pyproject.toml");
+        let pyproject_content = Self::generate_pyproject_content(project);
+
+        // Only create pyproject.toml if it doesn't already exist
+        if !fs.exists(&pyproject_path) {
+            fs.write(&pyproject_path, pyproject_content)
+                .with_context(|| format!("Failed to create pyproject.toml: {}", pyproject_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get `requires` and `build-backend` values for a supported build backend
+    pub fn build_system(backend: &str) -> Option<(&'static str, &'static str)> {
+        match backend {
+            "setuptools" => Some(("setuptools>=61.0", "setuptools.build_meta")),
+            "hatchling" => Some(("hatchling", "hatchling.build")),
+            "flit" => Some(("flit_core>=3.4", "flit_core.buildapi")),
+            "pdm" => Some(("pdm-backend", "pdm.backend")),
+            _ => None,
+        }
+    }
+
     /// Generate main.py file for Python project
     pub fn generate_main_py<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
     ) -> Result<()> {
         let main_py_path = project_path.as_ref().join("main.py");
         let main_content = Self::generate_main_py_content();
         
         // Only create main.py if it doesn't already exist
-        if !main_py_path.exists() {
-            fs::write(&main_py_path, main_content)
+        if !fs.exists(&main_py_path) {
+            fs.write(&main_py_path, main_content)
                 .with_context(|| format!("Failed to create main.py: {}", main_py_path.display()))?;
         }
 
@@ -171,6 +344,59 @@ setup(
         )
     }
 
+    /// Generate pyproject.toml content
+    fn generate_pyproject_content(project: &Project) -> String {
+        let backend = project.backend_setting().unwrap_or("setuptools");
+        let (requires, build_backend) = Self::build_system(backend)
+            .unwrap_or(("setuptools>=61.0", "setuptools.build_meta"));
+
+        let mut content = format!(
+            r#"[build-system]
+requires = ["{}"]
+build-backend = "{}"
+
+[project]
+name = "{}"
+version = "0.1.0"
+description = ""
+requires-python = ">=3.8"
+dependencies = []
+"#,
+            requires, build_backend, project.name()
+        );
+
+        // flit and pdm discover src layouts on their own
+        if project.is_src_layout() {
+            match backend {
+                "setuptools" => {
+                    content.push_str("\n[tool.setuptools.packages.find]\nwhere = [\"src\"]\n");
+                }
+                "hatchling" => {
+                    let packages: Vec<String> = Self::src_packages(project)
+                        .iter()
+                        .map(|p| format!("\"src/{}\"", p))
+                        .collect();
+                    content.push_str(&format!(
+                        "\n[tool.hatch.build.targets.wheel]\npackages = [{}]\n",
+                        packages.join(", ")
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        content
+    }
+
+    /// Get package names under the src directory of a src layout project
+    fn src_packages(project: &Project) -> Vec<String> {
+        project.tree().iter()
+            .filter(|m| m.name() == "src")
+            .flat_map(|m| m.subtree())
+            .map(|m| m.name())
+            .collect()
+    }
+
     /// Generate main.py content
     fn generate_main_py_content() -> String {
         r#"#!/usr/bin/env python3
@@ -214,4 +440,125 @@ if __name__ == "__main__":
         
         results
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+    use crate::project_management::config::models::PackageSetting;
+
+    fn make_project(tree: Vec<Module>) -> Project {
+        Project {
+            name: "app".to_string(),
+            root: true,
+            lang: "python".to_string(),
+            tree,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_import_statements_all_style() {
+        let names = vec!["model".to_string(), "service".to_string()];
+        let statements = PythonPackageGenerator::generate_import_statements(&names, "all");
+
+        assert_eq!(statements, vec![
+            "from . import model",
+            "from . import service",
+            "",
+            "__all__ = [",
+            "    \"model\",",
+            "    \"service\",",
+            "]",
+        ]);
+    }
+
+    #[test]
+    fn test_import_statements_all_style_without_names() {
+        let statements = PythonPackageGenerator::generate_import_statements(&[], "all");
+
+        assert_eq!(statements, vec!["__all__ = []"]);
+    }
+
+    #[test]
+    fn test_src_layout_with_typed_marker() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let src = Module {
+            name: Some("src".to_string()),
+            tree: vec![Module {
+                name: Some("mypkg".to_string()),
+                file: vec![CodeFile { name: "model".to_string(), ..Default::default() }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut project = make_project(vec![src.clone()]);
+        project.layout = Some("src".to_string());
+        project.typed = true;
+        project.init = Some("submodules".to_string());
+
+        PythonPackageGenerator::generate_module(&RealFs, base_path, &src, &[], &project).unwrap();
+
+        assert!(!base_path.join("src/__init__.py").exists());
+        assert!(base_path.join("src/mypkg/py.typed").exists());
+
+        let init = fs::read_to_string(base_path.join("src/mypkg/__init__.py")).unwrap();
+        assert!(init.contains("from . import model"));
+        assert!(!init.contains("import *"));
+    }
+
+    #[test]
+    fn test_non_package_and_namespace_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let app = Module {
+            name: Some("app".to_string()),
+            tree: vec![
+                Module {
+                    name: Some("plugins".to_string()),
+                    namespace: Some(true),
+                    ..Default::default()
+                },
+                Module {
+                    name: Some("migrations".to_string()),
+                    package: Some(PackageSetting::Flag(false)),
+                    tree: vec![Module { name: Some("versions".to_string()), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let project = make_project(vec![app.clone()]);
+
+        PythonPackageGenerator::generate_module(&RealFs, base_path, &app, &[], &project).unwrap();
+
+        assert!(!base_path.join("app/plugins/__init__.py").exists());
+        assert!(!base_path.join("app/migrations/__init__.py").exists());
+        assert!(!base_path.join("app/migrations/versions/__init__.py").exists());
+
+        let init = fs::read_to_string(base_path.join("app/__init__.py")).unwrap();
+        assert!(init.contains("from .plugins import *"));
+        assert!(!init.contains("migrations"));
+    }
+
+    #[test]
+    fn test_pyproject_hatchling_src_layout() {
+        let mut project = make_project(vec![Module {
+            name: Some("src".to_string()),
+            tree: vec![Module { name: Some("mypkg".to_string()), ..Default::default() }],
+            ..Default::default()
+        }]);
+        project.backend = Some("hatchling".to_string());
+        project.layout = Some("src".to_string());
+
+        let content = PythonPackageGenerator::generate_pyproject_content(&project);
+
+        assert!(content.contains("build-backend = \"hatchling.build\""));
+        assert!(content.contains("packages = [\"src/mypkg\"]"));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile, Project};
+
+/// Ruby-specific gem layout generator
+pub struct RubyGemGenerator;
+
+impl RubyGemGenerator {
+    /// Check if a file is a Ruby code file (ends with .rb)
+    fn is_ruby_code_file(filename: &str) -> bool {
+        filename.ends_with(".rb")
+    }
+
+    /// Generate Ruby directory structure recursively
+    /// Files below lib/ are wrapped in one `module` per directory (lib/my_gem/models -> MyGem::Models)
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module_name);
+
+        // Generate code files in this directory
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, &module_segments)?;
+        }
+
+        // Process subdirectories recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &module_segments)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a code file (only if it doesn't exist)
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        module_segments: &[String],
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("ruby");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            let content = if !Self::is_ruby_code_file(&filename) {
+                String::new() // Non-Ruby files get no content
+            } else if module_segments.first().map(String::as_str) == Some("lib") {
+                let wrappers: Vec<String> = module_segments[1..].iter()
+                    .map(|segment| Self::constant_name(segment))
+                    .collect();
+                Self::generate_lib_file_content(&wrappers, &Self::constant_name(filename.trim_end_matches(".rb")))
+            } else {
+                "# frozen_string_literal: true\n".to_string()
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Collect `require_relative` paths (relative to lib/) of every Ruby file below the lib module
+    /// The entry file lib/<gem>.rb itself is skipped
+    pub fn collect_requires(lib_module: &Module, entry_filename: &str) -> Vec<String> {
+        let mut requires = Vec::new();
+
+        for codefile in lib_module.files() {
+            let filename = codefile.filename_with_extension("ruby");
+            if Self::is_ruby_code_file(&filename) && filename != entry_filename {
+                requires.push(filename.trim_end_matches(".rb").to_string());
+            }
+        }
+
+        for submodule in lib_module.subtree() {
+            Self::collect_module_requires(submodule, "", &mut requires);
+        }
+
+        requires
+    }
+
+    fn collect_module_requires(module: &Module, dir_path: &str, requires: &mut Vec<String>) {
+        let module_dir = format!("{}{}/", dir_path, module.name());
+
+        for codefile in module.files() {
+            let filename = codefile.filename_with_extension("ruby");
+            if Self::is_ruby_code_file(&filename) {
+                requires.push(format!("{}{}", module_dir, filename.trim_end_matches(".rb")));
+            }
+        }
+
+        for submodule in module.subtree() {
+            Self::collect_module_requires(submodule, &module_dir, requires);
+        }
+    }
+
+    /// Derive the gem name from a project name (last path segment, my-gem -> my_gem)
+    pub fn gem_name(project_name: &str) -> String {
+        project_name.rsplit('/').next().unwrap_or(project_name).replace('-', "_")
+    }
+
+    /// Get the entry file path (lib/<gem>.rb) that moli maintains without a `file` entry
+    pub fn collect_entry_path(project: &Project) -> Option<String> {
+        if project.name() == "." || !project.tree().iter().any(|m| m.name() == "lib") {
+            return None;
+        }
+
+        Some(format!("lib/{}.rb", Self::gem_name(project.name())))
+    }
+
+    /// Check if a file or directory name follows Zeitwerk naming (snake_case, user_service -> UserService)
+    pub fn is_zeitwerk_name(name: &str) -> bool {
+        name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+            && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            && !name.contains("__")
+            && !name.ends_with('_')
+    }
+
+    /// Convert a snake_case name to its constant name (user_service -> UserService)
+    pub fn constant_name(name: &str) -> String {
+        name.split('_')
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// Generate a lib/ file with nested module wrappers around a class skeleton
+    fn generate_lib_file_content(wrappers: &[String], class_name: &str) -> String {
+        let mut content = String::from("# frozen_string_literal: true\n\n");
+
+        for (depth, wrapper) in wrappers.iter().enumerate() {
+            content.push_str(&format!("{}module {}\n", "  ".repeat(depth), wrapper));
+        }
+
+        let depth = wrappers.len();
+        content.push_str(&format!("{}class {}\n", "  ".repeat(depth), class_name));
+        content.push_str(&format!("{}end\n", "  ".repeat(depth)));
+
+        for depth in (0..wrappers.len()).rev() {
+            content.push_str(&format!("{}end\n", "  ".repeat(depth)));
+        }
+
+        content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_lib() -> Module {
+        Module {
+            name: Some("lib".to_string()),
+            file: vec![CodeFile { name: "my_gem".to_string(), ..Default::default() }],
+            tree: vec![Module {
+                name: Some("my_gem".to_string()),
+                file: vec![CodeFile { name: "version".to_string(), ..Default::default() }],
+                tree: vec![Module {
+                    name: Some("models".to_string()),
+                    file: vec![CodeFile { name: "user_service".to_string(), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_module_wrappers() {
+        let temp_dir = TempDir::new().unwrap();
+
+        RubyGemGenerator::generate_module(&RealFs, temp_dir.path(), &make_lib(), &[]).unwrap();
+
+        let user_service = fs::read_to_string(temp_dir.path().join("lib/my_gem/models/user_service.rb")).unwrap();
+        assert_eq!(
+            user_service,
+            "# frozen_string_literal: true\n\nmodule MyGem\n  module Models\n    class UserService\n    end\n  end\nend\n"
+        );
+    }
+
+    #[test]
+    fn test_collect_requires() {
+        assert_eq!(
+            RubyGemGenerator::collect_requires(&make_lib(), "my_gem.rb"),
+            vec!["my_gem/version".to_string(), "my_gem/models/user_service".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_zeitwerk_names() {
+        assert!(RubyGemGenerator::is_zeitwerk_name("user_service"));
+        assert!(RubyGemGenerator::is_zeitwerk_name("v2"));
+        assert!(!RubyGemGenerator::is_zeitwerk_name("UserService"));
+        assert!(!RubyGemGenerator::is_zeitwerk_name("user-service"));
+        assert_eq!(RubyGemGenerator::constant_name("user_service"), "UserService");
+    }
+}
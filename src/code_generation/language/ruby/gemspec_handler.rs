@@ -0,0 +1,170 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+use super::gem_generator::RubyGemGenerator;
+
+/// Ruby gem handler for generating the gem layout, .gemspec and Gemfile
+pub struct RubyGemspecHandler;
+
+impl RubyGemspecHandler {
+    /// Generate complete Ruby gem structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+        let gem_name = Self::gem_name(fs, project_path, project);
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate project-level files
+        for codefile in project.files() {
+            RubyGemGenerator::generate_file(fs, project_path, codefile, &[])?;
+        }
+
+        // The entry file is written before the tree so it gets a module instead of a class skeleton
+        let lib_module = project.tree().iter().find(|m| m.name() == "lib");
+        if lib_module.is_some() {
+            Self::generate_entry_file(fs, project_path, &gem_name)?;
+        }
+
+        // Generate lib/, spec/, ... with module wrappers below lib/
+        for module in project.tree() {
+            RubyGemGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        // Keep require_relative lines of lib/<gem>.rb in sync with the tree
+        if let Some(lib_module) = lib_module {
+            let entry_filename = format!("{}.rb", gem_name);
+            let requires: Vec<String> = RubyGemGenerator::collect_requires(lib_module, &entry_filename)
+                .iter()
+                .map(|path| format!("require_relative \"{}\"", path))
+                .collect();
+
+            let entry_path = project_path.join("lib").join(&entry_filename);
+            ContentUpdater::update_managed_section(
+                fs,
+                &entry_path,
+                &requires.join("\n"),
+                "# start auto exported by moli.\n",
+                "# end auto exported by moli.",
+            ).with_context(|| format!("Failed to update requires: {}", entry_path.display()))?;
+        }
+
+        // Generate <gem>.gemspec and Gemfile (created once)
+        let gemspec_path = project_path.join(format!("{}.gemspec", gem_name));
+        if !fs.exists(&gemspec_path) {
+            fs.write(&gemspec_path, Self::generate_gemspec_content(&gem_name))
+                .with_context(|| format!("Failed to create gemspec: {}", gemspec_path.display()))?;
+        }
+
+        let gemfile_path = project_path.join("Gemfile");
+        if !fs.exists(&gemfile_path) {
+            fs.write(&gemfile_path, Self::generate_gemfile_content())
+                .with_context(|| format!("Failed to create Gemfile: {}", gemfile_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the gem name (directory name for ".")
+    fn gem_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        if project.name() == "." {
+            return RubyGemGenerator::gem_name(&paths::directory_name(fs, project_path, "app"));
+        }
+
+        RubyGemGenerator::gem_name(project.name())
+    }
+
+    /// Create lib/<gem>.rb with empty require markers and the top-level module (only if it doesn't exist)
+    fn generate_entry_file(fs: &dyn Fs, project_path: &Path, gem_name: &str) -> Result<()> {
+        let lib_path = project_path.join("lib");
+        let entry_path = lib_path.join(format!("{}.rb", gem_name));
+
+        if !fs.exists(&entry_path) {
+            fs.create_dir_all(&lib_path)
+                .with_context(|| format!("Failed to create directory: {}", lib_path.display()))?;
+            let content = format!(
+                "# frozen_string_literal: true\n\n# start auto exported by moli.\n# end auto exported by moli.\n\nmodule {}\nend\n",
+                RubyGemGenerator::constant_name(gem_name)
+            );
+            fs.write(&entry_path, content)
+                .with_context(|| format!("Failed to create file: {}", entry_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate <gem>.gemspec content
+    fn generate_gemspec_content(gem_name: &str) -> String {
+        format!(
+            r#"# frozen_string_literal: true
+
+Gem::Specification.new do |spec|
+  spec.name = "{0}"
+  spec.version = "0.1.0"
+  spec.summary = "{0}"
+  spec.authors = []
+  spec.files = Dir["lib/**/*.rb"]
+  spec.require_paths = ["lib"]
+  spec.required_ruby_version = ">= 3.1"
+end
+"#,
+            gem_name
+        )
+    }
+
+    /// Generate Gemfile content
+    fn generate_gemfile_content() -> String {
+        "# frozen_string_literal: true\n\nsource \"https://rubygems.org\"\n\ngemspec\n".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::{CodeFile, Module};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = Project {
+            name: "my-gem".to_string(),
+            lang: "ruby".to_string(),
+            tree: vec![Module {
+                name: Some("lib".to_string()),
+                tree: vec![Module {
+                    name: Some("my_gem".to_string()),
+                    file: vec![CodeFile { name: "client".to_string(), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        RubyGemspecHandler::generate_project(&RealFs, temp_dir.path(), &project).unwrap();
+
+        let entry = fs::read_to_string(temp_dir.path().join("lib/my_gem.rb")).unwrap();
+        assert_eq!(
+            entry,
+            "# frozen_string_literal: true\n\n# start auto exported by moli.\nrequire_relative \"my_gem/client\"\n# end auto exported by moli.\n\nmodule MyGem\nend\n"
+        );
+
+        let client = fs::read_to_string(temp_dir.path().join("lib/my_gem/client.rb")).unwrap();
+        assert!(client.contains("module MyGem\n  class Client\n  end\nend\n"));
+
+        let gemspec = fs::read_to_string(temp_dir.path().join("my_gem.gemspec")).unwrap();
+        assert!(gemspec.contains("spec.name = \"my_gem\""));
+        assert!(temp_dir.path().join("Gemfile").exists());
+    }
+}
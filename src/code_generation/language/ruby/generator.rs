@@ -0,0 +1,80 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{Project, Module};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::ruby::gem_generator::RubyGemGenerator;
+use crate::code_generation::language::ruby::gemspec_handler::RubyGemspecHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Ruby gems with module wrappers and require_relative entry files
+pub struct RubyGenerator;
+
+impl LanguageGenerator for RubyGenerator {
+    fn name(&self) -> &str {
+        "ruby"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("rb")
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        RubyGemspecHandler::generate_project(fs, project_path, project)
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        // Zeitwerk naming below lib/ (user_service.rb -> UserService)
+        let mut errors = Vec::new();
+        for (i, module) in project.tree().iter().enumerate() {
+            if module.name() == "lib" {
+                Self::check_zeitwerk_names(module, &format!("{}.tree[{}]", path, i), &mut errors);
+            }
+        }
+        errors
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["Gemfile", "Gemfile.lock"]
+    }
+
+    fn excluded_extensions(&self) -> Vec<&str> {
+        vec![".gemspec"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Gem layout: lib/<gem>.rb is maintained by moli, specs live in spec/
+        r#"  tree:
+    - name: lib
+    - name: spec
+"#.to_string()
+    }
+}
+
+impl RubyGenerator {
+    /// Check that Ruby files and directories below lib/ map to constants the Zeitwerk way
+    fn check_zeitwerk_names(module: &Module, path: &str, errors: &mut Vec<ValidationError>) {
+        for (i, codefile) in module.files().iter().enumerate() {
+            let filename = codefile.filename_with_extension("ruby");
+            if let Some(stem) = filename.strip_suffix(".rb") {
+                if !RubyGemGenerator::is_zeitwerk_name(stem) {
+                    errors.push(ValidationError {
+                        message: format!("File name does not map to a constant: {} (expected snake_case)", filename),
+                        path: format!("{}.file[{}].name", path, i),
+                    });
+                }
+            }
+        }
+
+        for (i, submodule) in module.subtree().iter().enumerate() {
+            let submodule_path = format!("{}.tree[{}]", path, i);
+            if !RubyGemGenerator::is_zeitwerk_name(&submodule.name()) {
+                errors.push(ValidationError {
+                    message: format!("Directory name does not map to a constant: {} (expected snake_case)", submodule.name()),
+                    path: format!("{}.name", submodule_path),
+                });
+            }
+            Self::check_zeitwerk_names(submodule, &submodule_path, errors);
+        }
+    }
+}
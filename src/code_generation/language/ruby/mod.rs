@@ -0,0 +1,5 @@
+// start auto exported by moli.
+pub mod gem_generator;
+pub mod gemspec_handler;
+pub mod generator;
+// end auto exported by moli.
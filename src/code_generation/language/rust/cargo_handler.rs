@@ -1,4 +1,4 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::Path;
 use anyhow::{Context, Result};
 use crate::project_management::config::models::Project;
@@ -9,6 +9,7 @@ pub struct CargoHandler;
 impl CargoHandler {
     /// Generate Cargo.toml for individual Rust project
     pub fn generate_cargo_toml<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         project: &Project,
     ) -> Result<()> {
@@ -16,8 +17,8 @@ impl CargoHandler {
         let cargo_content = Self::generate_cargo_toml_content(project);
         
         // Only create Cargo.toml if it doesn't already exist
-        if !cargo_toml_path.exists() {
-            fs::write(&cargo_toml_path, cargo_content)
+        if !fs.exists(&cargo_toml_path) {
+            fs.write(&cargo_toml_path, cargo_content)
                 .with_context(|| format!("Failed to create Cargo.toml: {}", cargo_toml_path.display()))?;
         }
 
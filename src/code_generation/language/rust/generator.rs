@@ -0,0 +1,95 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{MoliConfig, Project};
+use crate::code_generation::language::rust::module_generator::RustModuleGenerator;
+use crate::code_generation::language::rust::workspace_handler::WorkspaceHandler;
+use crate::code_generation::language::rust::cargo_handler::CargoHandler;
+
+/// Rust crates with managed mod.rs declarations and Cargo workspaces
+pub struct RustGenerator;
+
+impl LanguageGenerator for RustGenerator {
+    fn name(&self) -> &str {
+        "rust"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("rs")
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        // Generate Cargo.toml
+        CargoHandler::generate_cargo_toml(fs, project_path, project)?;
+
+        // Create src directory
+        let src_path = project_path.join("src");
+        fs.create_dir_all(&src_path)
+            .with_context(|| format!("Failed to create src directory: {}", src_path.display()))?;
+
+        // Generate project-level code files (only if they don't exist)
+        for codefile in project.files() {
+            let filename = codefile.filename_with_extension("rust");
+            let file_path = project_path.join(&filename);
+
+            // Only create file if it doesn't already exist
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
+                    .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+            }
+        }
+
+        // Generate module structure
+        for module in project.tree() {
+            RustModuleGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        // Generate main.rs or lib.rs
+        let src_modules: Vec<_> = project.tree().iter()
+            .filter(|m| m.name() == "src")
+            .cloned()
+            .collect();
+
+        if RustModuleGenerator::should_generate_main_rs(project) {
+            RustModuleGenerator::generate_main_rs(fs, project_path, &src_modules)?;
+        } else if RustModuleGenerator::should_generate_lib_rs(project) {
+            RustModuleGenerator::generate_lib_rs(fs, project_path, &src_modules)?;
+        }
+        // If neither main nor lib is specified, don't generate either (e.g., DDD with src/bin/ structure)
+
+        Ok(())
+    }
+
+    fn generate_workspace(&self, fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        if WorkspaceHandler::should_generate_workspace(config) {
+            WorkspaceHandler::generate_workspace_cargo_toml(fs, workspace_path, config)?;
+            WorkspaceHandler::generate_makefile_toml(fs, workspace_path, config)?;
+        }
+        Ok(())
+    }
+
+    fn aggregator_file(&self) -> Option<&str> {
+        Some("mod.rs")
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["Cargo.toml", "Cargo.lock"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["target"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, is_root: bool) -> String {
+        // Rust standard: src/main.rs or src/lib.rs
+        format!(
+            r#"  tree:
+    - name: src
+      file:
+        - name: {}
+"#,
+            if is_root { "main" } else { "lib" }
+        )
+    }
+}
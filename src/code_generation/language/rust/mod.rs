@@ -2,5 +2,6 @@
 pub mod module_generator;
 pub mod workspace_handler;
 pub mod cargo_handler;
+pub mod generator;
 // end auto exported by moli.
 
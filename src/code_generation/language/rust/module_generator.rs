@@ -1,4 +1,4 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Module, CodeFile, Project};
@@ -34,6 +34,7 @@ impl RustModuleGenerator {
     }
     /// Generate Rust module structure recursively
     pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         module: &Module,
         parent_modules: &[String],
@@ -42,7 +43,7 @@ impl RustModuleGenerator {
         let module_path = base_path.as_ref().join(&module_name);
 
         // Create directory
-        fs::create_dir_all(&module_path)
+        fs.create_dir_all(&module_path)
             .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
 
         // Collect all submodule names and code file names for mod.rs
@@ -54,8 +55,8 @@ impl RustModuleGenerator {
             let file_path = module_path.join(&filename);
 
             // Create empty file (only if it doesn't exist)
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
 
@@ -72,7 +73,7 @@ impl RustModuleGenerator {
             let mut new_parent_modules = parent_modules.to_vec();
             new_parent_modules.push(module_name.clone());
 
-            Self::generate_module(&module_path, submodule, &new_parent_modules)?;
+            Self::generate_module(fs, &module_path, submodule, &new_parent_modules)?;
 
             // Add submodule declaration using the submodule's pub setting
             let visibility = Self::get_visibility_prefix(submodule.pub_setting(), "mod");
@@ -82,7 +83,7 @@ impl RustModuleGenerator {
         // Generate mod.rs for all modules except src (src modules use main.rs or lib.rs instead)
         if module_name != "src" {
             let mod_rs_path = module_path.join("mod.rs");
-            ContentUpdater::update_rust_module_file(&mod_rs_path, &module_declarations, None)?;
+            ContentUpdater::update_rust_module_file(fs, &mod_rs_path, &module_declarations, None)?;
         }
 
         Ok(())
@@ -90,6 +91,7 @@ impl RustModuleGenerator {
 
     /// Generate main.rs content for root project
     pub fn generate_main_rs<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         src_modules: &[Module],
     ) -> Result<()> {
@@ -119,13 +121,14 @@ impl RustModuleGenerator {
             }
         }
 
-        ContentUpdater::update_rust_module_file(&main_rs_path, &module_declarations, None)?;
+        ContentUpdater::update_rust_module_file(fs, &main_rs_path, &module_declarations, None)?;
 
         Ok(())
     }
 
     /// Generate lib.rs content for library project
     pub fn generate_lib_rs<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
         src_modules: &[Module],
     ) -> Result<()> {
@@ -155,7 +158,7 @@ impl RustModuleGenerator {
             }
         }
 
-        ContentUpdater::update_rust_module_file(&lib_rs_path, &module_declarations, None)?;
+        ContentUpdater::update_rust_module_file(fs, &lib_rs_path, &module_declarations, None)?;
 
         Ok(())
     }
@@ -227,6 +230,8 @@ impl RustModuleGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
     use tempfile::TempDir;
     use crate::project_management::config::models::*;
 
@@ -241,12 +246,13 @@ mod tests {
             r#pub: None,
             tree: vec![],
             file: vec![
-                CodeFile { name: "model".to_string(), r#pub: None },
-                CodeFile { name: "repository".to_string(), r#pub: None },
+                CodeFile { name: "model".to_string(), r#pub: None, ..Default::default() },
+                CodeFile { name: "repository".to_string(), r#pub: None, ..Default::default() },
             ],
+            ..Default::default()
         };
 
-        RustModuleGenerator::generate_module(base_path, &module, &[]).unwrap();
+        RustModuleGenerator::generate_module(&RealFs, base_path, &module, &[]).unwrap();
 
         // Check directory exists
         assert!(base_path.join("domain").exists());
@@ -277,13 +283,15 @@ mod tests {
                     from: None,
                     r#pub: None,
                     tree: vec![],
-                    file: vec![CodeFile { name: "model".to_string(), r#pub: None }],
+                    file: vec![CodeFile { name: "model".to_string(), r#pub: None, ..Default::default() }],
+                    ..Default::default()
                 },
             ],
             file: vec![],
+            ..Default::default()
         };
 
-        RustModuleGenerator::generate_module(base_path, &module, &[]).unwrap();
+        RustModuleGenerator::generate_module(&RealFs, base_path, &module, &[]).unwrap();
 
         // Check nested structure
         assert!(base_path.join("src").exists());
@@ -317,13 +325,15 @@ mod tests {
                         r#pub: None,
                         tree: vec![],
                         file: vec![],
+                        ..Default::default()
                     },
                 ],
                 file: vec![],
+                ..Default::default()
             },
         ];
 
-        RustModuleGenerator::generate_main_rs(project_path, &modules).unwrap();
+        RustModuleGenerator::generate_main_rs(&RealFs, project_path, &modules).unwrap();
 
         let main_rs_path = project_path.join("src/main.rs");
         assert!(main_rs_path.exists());
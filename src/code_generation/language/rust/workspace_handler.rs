@@ -1,8 +1,9 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::Path;
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{MoliConfig, Project};
 use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
 
 /// Rust workspace handler for generating workspace Cargo.toml and Makefile.toml
 pub struct WorkspaceHandler;
@@ -10,6 +11,7 @@ pub struct WorkspaceHandler;
 impl WorkspaceHandler {
     /// Generate workspace Cargo.toml for multi-project configuration with Rust projects
     pub fn generate_workspace_cargo_toml<P: AsRef<Path>>(
+        fs: &dyn Fs,
         workspace_path: P,
         config: &MoliConfig,
     ) -> Result<()> {
@@ -42,7 +44,7 @@ impl WorkspaceHandler {
                 .first()
                 .map(|p| {
                     (
-                        Self::extract_package_name_from_path(workspace_path.as_ref()),
+                        Self::extract_package_name_from_path(fs, workspace_path.as_ref()),
                         p
                     )
                 })
@@ -50,7 +52,7 @@ impl WorkspaceHandler {
             None
         };
 
-        ContentUpdater::update_workspace_cargo_toml(&cargo_toml_path, &members, package_info)
+        ContentUpdater::update_workspace_cargo_toml(fs, &cargo_toml_path, &members, package_info)
             .with_context(|| format!("Failed to update workspace Cargo.toml: {}", cargo_toml_path.display()))?;
 
         Ok(())
@@ -58,16 +60,8 @@ impl WorkspaceHandler {
 
     /// Extract package name from workspace path (use directory name)
     /// Sanitizes the name to be a valid Rust crate name
-    fn extract_package_name_from_path(path: &Path) -> String {
-        // Convert to absolute path to get the actual directory name
-        let absolute_path = path.canonicalize()
-            .unwrap_or_else(|_| path.to_path_buf());
-
-        let dir_name = absolute_path.file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("workspace");
-
-        Self::sanitize_crate_name(dir_name)
+    fn extract_package_name_from_path(fs: &dyn Fs, path: &Path) -> String {
+        Self::sanitize_crate_name(&paths::directory_name(fs, path, "workspace"))
     }
 
     /// Sanitize name to be a valid Rust crate name
@@ -98,15 +92,16 @@ impl WorkspaceHandler {
 
     /// Generate Makefile.toml for cargo-make
     pub fn generate_makefile_toml<P: AsRef<Path>>(
+        fs: &dyn Fs,
         workspace_path: P,
         config: &MoliConfig,
     ) -> Result<()> {
         let makefile_toml_path = workspace_path.as_ref().join("Makefile.toml");
         
         // Only create if file doesn't exist (protect existing configuration)
-        if !makefile_toml_path.exists() {
+        if !fs.exists(&makefile_toml_path) {
             let makefile_content = Self::generate_makefile_toml_content(config);
-            fs::write(&makefile_toml_path, makefile_content)
+            fs.write(&makefile_toml_path, makefile_content)
                 .with_context(|| format!("Failed to create Makefile.toml: {}", makefile_toml_path.display()))?;
         }
 
@@ -0,0 +1,46 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::Project;
+use crate::code_generation::language::swift::package_handler::SwiftPackageHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Swift packages with SwiftPM targets
+pub struct SwiftGenerator;
+
+impl LanguageGenerator for SwiftGenerator {
+    fn name(&self) -> &str {
+        "swift"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("swift")
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        SwiftPackageHandler::generate_project(fs, project_path, project)
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["Package.swift", "Package.resolved"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec![".build", ".swiftpm"]
+    }
+
+    fn new_skeleton(&self, project_name: &str, _is_root: bool) -> String {
+        // SwiftPM standard: Sources/<Target> and Tests/<Target>Tests
+        format!(
+            r#"  tree:
+    - name: Sources
+      tree:
+        - name: {}
+          file:
+            - name: main
+    - name: Tests
+"#,
+            project_name
+        )
+    }
+}
@@ -0,0 +1,5 @@
+// start auto exported by moli.
+pub mod target_generator;
+pub mod package_handler;
+pub mod generator;
+// end auto exported by moli.
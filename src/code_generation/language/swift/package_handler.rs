@@ -0,0 +1,171 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::content_updater::ContentUpdater;
+use crate::shared::utils::paths;
+use super::target_generator::{SwiftTarget, SwiftTargetGenerator};
+
+/// Swift Package Manager handler for generating Swift project structure
+pub struct SwiftPackageHandler;
+
+impl SwiftPackageHandler {
+    /// Generate complete Swift package structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate project-level files
+        for codefile in project.files() {
+            SwiftTargetGenerator::generate_file(fs, project_path, codefile, &[])?;
+        }
+
+        // Generate Sources/ and Tests/ trees
+        for module in project.tree() {
+            SwiftTargetGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        // Generate Package.swift and keep its targets in sync with the tree
+        Self::generate_package_swift(fs, project_path, project)?;
+
+        Ok(())
+    }
+
+    /// Generate Package.swift (created once) and update its managed `targets:` entries
+    pub fn generate_package_swift<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+        let package_swift_path = project_path.join("Package.swift");
+
+        if !fs.exists(&package_swift_path) {
+            let package_name = Self::package_name(fs, project_path, project);
+            fs.write(&package_swift_path, Self::generate_package_swift_content(&package_name))
+                .with_context(|| format!("Failed to create Package.swift: {}", package_swift_path.display()))?;
+        }
+
+        let targets = SwiftTargetGenerator::collect_targets(project.tree());
+        ContentUpdater::update_managed_section(
+            fs,
+            &package_swift_path,
+            &Self::generate_targets_content(&targets),
+            "        // start auto exported by moli.\n",
+            "        // end auto exported by moli.",
+        ).with_context(|| format!("Failed to update Package.swift targets: {}", package_swift_path.display()))
+    }
+
+    /// Get the package name (last path segment, directory name for ".")
+    fn package_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        if project.name() == "." {
+            return paths::directory_name(fs, project_path, "App");
+        }
+
+        project.name().rsplit('/').next().unwrap_or(project.name()).to_string()
+    }
+
+    /// Generate the managed `targets:` entries
+    /// Test targets depend on the target they are named after (CoreTests -> Core) when it exists
+    fn generate_targets_content(targets: &[SwiftTarget]) -> String {
+        let source_targets: Vec<&str> = targets.iter()
+            .filter_map(|target| match target {
+                SwiftTarget::Library(name) | SwiftTarget::Executable(name) => Some(name.as_str()),
+                SwiftTarget::Test(_) => None,
+            })
+            .collect();
+
+        targets.iter()
+            .map(|target| match target {
+                SwiftTarget::Library(name) => format!("        .target(name: \"{}\"),", name),
+                SwiftTarget::Executable(name) => format!("        .executableTarget(name: \"{}\"),", name),
+                SwiftTarget::Test(name) => match name.strip_suffix("Tests").filter(|tested| source_targets.contains(tested)) {
+                    Some(tested) => format!("        .testTarget(name: \"{}\", dependencies: [\"{}\"]),", name, tested),
+                    None => format!("        .testTarget(name: \"{}\"),", name),
+                },
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Generate Package.swift content with an empty managed targets section
+    fn generate_package_swift_content(package_name: &str) -> String {
+        format!(
+            r#"// swift-tools-version:5.9
+import PackageDescription
+
+let package = Package(
+    name: "{}",
+    targets: [
+        // start auto exported by moli.
+        // end auto exported by moli.
+    ]
+)
+"#,
+            package_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::{Module, CodeFile};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_package_swift_targets() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let mut project = Project {
+            name: "Toolkit".to_string(),
+            lang: "swift".to_string(),
+            tree: vec![
+                Module {
+                    name: Some("Sources".to_string()),
+                    tree: vec![Module {
+                        name: Some("Core".to_string()),
+                        file: vec![CodeFile { name: "Parser".to_string(), ..Default::default() }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+                Module {
+                    name: Some("Tests".to_string()),
+                    tree: vec![Module { name: Some("CoreTests".to_string()), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        SwiftPackageHandler::generate_project(&RealFs, base_path, &project).unwrap();
+
+        let package_swift = fs::read_to_string(base_path.join("Package.swift")).unwrap();
+        assert!(package_swift.contains("name: \"Toolkit\""));
+        assert!(package_swift.contains(
+            "        // start auto exported by moli.\n        .target(name: \"Core\"),\n        .testTarget(name: \"CoreTests\", dependencies: [\"Core\"]),\n        // end auto exported by moli.\n    ]\n)"
+        ));
+
+        // A new target added to moli.yml shows up on the next run
+        project.tree[0].tree.push(Module {
+            name: Some("cli".to_string()),
+            file: vec![CodeFile { name: "main".to_string(), ..Default::default() }],
+            ..Default::default()
+        });
+        SwiftPackageHandler::generate_project(&RealFs, base_path, &project).unwrap();
+
+        let package_swift = fs::read_to_string(base_path.join("Package.swift")).unwrap();
+        assert!(package_swift.contains(".executableTarget(name: \"cli\"),"));
+        assert_eq!(package_swift.matches(".target(name: \"Core\")").count(), 1);
+    }
+}
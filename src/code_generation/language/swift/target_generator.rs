@@ -0,0 +1,184 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile};
+
+/// Kind of SwiftPM target a Sources/ or Tests/ subdirectory maps to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SwiftTarget {
+    Library(String),
+    Executable(String),
+    Test(String),
+}
+
+/// Swift-specific target generator
+pub struct SwiftTargetGenerator;
+
+impl SwiftTargetGenerator {
+    /// Check if a file is a Swift code file (ends with .swift)
+    fn is_swift_code_file(filename: &str) -> bool {
+        filename.ends_with(".swift")
+    }
+
+    /// Generate Swift directory structure recursively
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let mut module_segments = parent_modules.to_vec();
+        module_segments.push(module_name);
+
+        // Generate code files in this directory
+        for codefile in module.files() {
+            Self::generate_file(fs, &module_path, codefile, &module_segments)?;
+        }
+
+        // Process subdirectories recursively
+        for submodule in module.subtree() {
+            Self::generate_module(fs, &module_path, submodule, &module_segments)?;
+        }
+
+        Ok(())
+    }
+
+    /// Create a Swift file (only if it doesn't exist)
+    /// main.swift gets an entry point, files in a test target an XCTestCase skeleton
+    pub fn generate_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        dir_path: P,
+        codefile: &CodeFile,
+        module_segments: &[String],
+    ) -> Result<()> {
+        let filename = codefile.filename_with_extension("swift");
+        let file_path = dir_path.as_ref().join(&filename);
+
+        if !fs.exists(&file_path) {
+            let content = if !Self::is_swift_code_file(&filename) {
+                String::new() // Non-Swift files get no content
+            } else if filename == "main.swift" {
+                "print(\"Hello, world!\")\n".to_string()
+            } else if module_segments.len() > 1 && module_segments[0] == "Tests" {
+                let class_name = filename.trim_end_matches(".swift");
+                Self::generate_test_content(&module_segments[1], class_name)
+            } else {
+                String::new()
+            };
+
+            fs.write(&file_path, content)
+                .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Collect SwiftPM targets from Sources/<Target> and Tests/<Target>Tests directories
+    pub fn collect_targets(tree: &[Module]) -> Vec<SwiftTarget> {
+        let mut targets = Vec::new();
+
+        for root in tree.iter().filter(|m| m.name() == "Sources") {
+            for target in root.subtree() {
+                if Self::has_main(target) {
+                    targets.push(SwiftTarget::Executable(target.name()));
+                } else {
+                    targets.push(SwiftTarget::Library(target.name()));
+                }
+            }
+        }
+
+        for root in tree.iter().filter(|m| m.name() == "Tests") {
+            for target in root.subtree() {
+                targets.push(SwiftTarget::Test(target.name()));
+            }
+        }
+
+        targets
+    }
+
+    /// Check if a target declares main.swift
+    fn has_main(module: &Module) -> bool {
+        module.files().iter().any(|f| f.filename_with_extension("swift") == "main.swift")
+    }
+
+    /// Generate XCTest file content
+    fn generate_test_content(test_target: &str, class_name: &str) -> String {
+        let tested_module = test_target.strip_suffix("Tests").unwrap_or(test_target);
+
+        format!(
+            "import XCTest\n@testable import {}\n\nfinal class {}: XCTestCase {{\n}}\n",
+            tested_module, class_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_tree() -> Vec<Module> {
+        vec![
+            Module {
+                name: Some("Sources".to_string()),
+                tree: vec![
+                    Module {
+                        name: Some("Core".to_string()),
+                        file: vec![CodeFile { name: "Parser".to_string(), ..Default::default() }],
+                        ..Default::default()
+                    },
+                    Module {
+                        name: Some("cli".to_string()),
+                        file: vec![CodeFile { name: "main".to_string(), ..Default::default() }],
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+            Module {
+                name: Some("Tests".to_string()),
+                tree: vec![Module {
+                    name: Some("CoreTests".to_string()),
+                    file: vec![CodeFile { name: "ParserTests".to_string(), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_collect_targets() {
+        assert_eq!(SwiftTargetGenerator::collect_targets(&make_tree()), vec![
+            SwiftTarget::Library("Core".to_string()),
+            SwiftTarget::Executable("cli".to_string()),
+            SwiftTarget::Test("CoreTests".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_generate_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        for module in make_tree() {
+            SwiftTargetGenerator::generate_module(&RealFs, base_path, &module, &[]).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(base_path.join("Sources/Core/Parser.swift")).unwrap(), "");
+        assert_eq!(fs::read_to_string(base_path.join("Sources/cli/main.swift")).unwrap(), "print(\"Hello, world!\")\n");
+        assert_eq!(
+            fs::read_to_string(base_path.join("Tests/CoreTests/ParserTests.swift")).unwrap(),
+            "import XCTest\n@testable import Core\n\nfinal class ParserTests: XCTestCase {\n}\n"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::{MoliConfig, Project, Module};
+use crate::project_management::config::validator::ValidationError;
+use crate::code_generation::language::typescript::module_generator::TypeScriptModuleGenerator;
+use crate::code_generation::language::javascript::workspace_handler::NodeWorkspaceHandler;
+
+/// TypeScript packages with index.ts barrels and project references
+pub struct TypeScriptGenerator;
+
+impl LanguageGenerator for TypeScriptGenerator {
+    fn name(&self) -> &str {
+        "typescript"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("ts")
+    }
+
+    fn options(&self) -> Vec<&str> {
+        vec!["module", "workspace", "scope", "barrels", "index", "export"]
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        // Generate package.json and tsconfig.json
+        TypeScriptModuleGenerator::generate_package_json(fs, project_path, project)?;
+        TypeScriptModuleGenerator::generate_tsconfig_json(fs, project_path, project)?;
+
+        // Generate project-level code files (only if they don't exist)
+        for codefile in project.files() {
+            let filename = codefile.filename_with_extension("typescript");
+            let file_path = project_path.join(&filename);
+
+            // Only create file if it doesn't already exist
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
+                    .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+            }
+        }
+
+        // Generate module structure
+        for module in project.tree() {
+            TypeScriptModuleGenerator::generate_module(fs, project_path, module, &[], project)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_project(&self, project: &Project, path: &str) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+
+        if let Some(module_system) = project.module_setting() {
+            if !matches!(module_system, "esm" | "commonjs" | "nodenext") {
+                errors.push(ValidationError {
+                    message: format!("Unsupported module system: {} (expected esm, commonjs or nodenext)", module_system),
+                    path: format!("{}.module", path),
+                });
+            }
+        }
+
+        for (i, module) in project.tree().iter().enumerate() {
+            Self::validate_export_settings(module, &format!("{}.tree[{}]", path, i), &mut errors);
+        }
+
+        errors
+    }
+
+    fn generate_workspace(&self, fs: &dyn Fs, workspace_path: &Path, config: &MoliConfig) -> Result<()> {
+        if NodeWorkspaceHandler::should_generate_workspace(config) {
+            NodeWorkspaceHandler::generate_workspace(fs, workspace_path, config)?;
+        }
+        Ok(())
+    }
+
+    fn link_project(&self, fs: &dyn Fs, workspace_path: &Path, project: &Project, config: &MoliConfig) -> Result<()> {
+        // Link TypeScript project references between workspace members
+        if NodeWorkspaceHandler::should_generate_workspace(config) {
+            NodeWorkspaceHandler::update_project_references(fs, workspace_path.join(project.name()), project, config)?;
+        }
+        Ok(())
+    }
+
+    fn aggregator_file(&self) -> Option<&str> {
+        Some("index.ts")
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["package.json", "package-lock.json", "yarn.lock", "pnpm-lock.yaml", "pnpm-workspace.yaml"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec!["node_modules"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Modern standard: src/ directory structure
+        r#"  tree:
+    - name: src
+      file:
+        - name: index
+"#.to_string()
+    }
+}
+
+impl TypeScriptGenerator {
+    /// Validate `export` settings of the files in a module and its subtree
+    fn validate_export_settings(module: &Module, path: &str, errors: &mut Vec<ValidationError>) {
+        for (i, codefile) in module.files().iter().enumerate() {
+            if let Some(export) = codefile.export_setting() {
+                if !matches!(export, "star" | "namespace" | "default-as-named" | "none") {
+                    errors.push(ValidationError {
+                        message: format!(
+                            "Unsupported export style: {} (expected star, namespace, default-as-named or none)",
+                            export
+                        ),
+                        path: format!("{}.file[{}].export", path, i),
+                    });
+                }
+            }
+        }
+
+        for (i, submodule) in module.subtree().iter().enumerate() {
+            Self::validate_export_settings(submodule, &format!("{}.tree[{}]", path, i), errors);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 // start auto exported by moli.
 pub mod module_generator;
+pub mod generator;
 // end auto exported by moli.
 
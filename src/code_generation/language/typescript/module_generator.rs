@@ -1,8 +1,10 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
 use crate::project_management::config::models::{Module, CodeFile, Project};
 use crate::shared::utils::content_updater::ContentUpdater;
+use crate::code_generation::language::javascript::workspace_handler::NodeWorkspaceHandler;
+use crate::code_generation::core::barrel_policy::BarrelPolicy;
 
 /// TypeScript-specific module generator
 pub struct TypeScriptModuleGenerator;
@@ -13,20 +15,29 @@ impl TypeScriptModuleGenerator {
         filename.ends_with(".ts") || filename.ends_with(".tsx")
     }
 
+    /// Get the project's module system (defaults to "commonjs")
+    pub fn module_system(project: &Project) -> &str {
+        project.module_setting().unwrap_or("commonjs")
+    }
+
     /// Generate TypeScript module structure recursively
     pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
         base_path: P,
         module: &Module,
         parent_modules: &[String],
+        project: &Project,
     ) -> Result<()> {
+        let module_system = Self::module_system(project);
         let module_name = module.name();
         let module_path = base_path.as_ref().join(&module_name);
         
         // Create directory
-        fs::create_dir_all(&module_path)
+        fs.create_dir_all(&module_path)
             .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
 
-        // Collect all submodule names and code file names for index.ts
+        // Collect all submodule names and code file names for the barrel file
+        let barrel_filename = BarrelPolicy::filename(project, module, "typescript");
         let mut export_declarations = Vec::new();
 
         // Generate code files in this module
@@ -35,16 +46,16 @@ impl TypeScriptModuleGenerator {
             let file_path = module_path.join(&filename);
 
             // Create empty file (only if it doesn't exist)
-            if !file_path.exists() {
-                fs::write(&file_path, "")
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
                     .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
             }
 
-            // Add to export declarations only if it's a TypeScript code file and not index.ts
-            if Self::is_typescript_code_file(&filename) && filename != "index.ts" {
-                // Use name without extension for TypeScript module resolution
-                let module_name = Self::get_module_name_for_export(codefile);
-                export_declarations.push(format!("export * from './{}';", module_name));
+            // Add to export declarations (the barrel itself is never re-exported)
+            if filename != barrel_filename {
+                if let Some(statement) = Self::export_statement(codefile, &filename, module_system) {
+                    export_declarations.push(statement);
+                }
             }
         }
 
@@ -53,24 +64,101 @@ impl TypeScriptModuleGenerator {
             let mut new_parent_modules = parent_modules.to_vec();
             new_parent_modules.push(module.name().to_string());
             
-            Self::generate_module(&module_path, submodule, &new_parent_modules)?;
+            Self::generate_module(fs, &module_path, submodule, &new_parent_modules, project)?;
             
-            // Add submodule export declaration
-            export_declarations.push(format!("export * from './{}';", submodule.name()));
+            // Add submodule export declaration (unless its barrel is turned off)
+            if BarrelPolicy::is_reexported(project, submodule) {
+                let submodule_barrel = BarrelPolicy::filename(project, submodule, "typescript");
+                export_declarations.push(format!(
+                    "export * from '{}';",
+                    Self::directory_specifier(&submodule.name(), &submodule_barrel, module_system)
+                ));
+            }
         }
 
-        // Generate index.ts only if explicitly defined in codefile
-        let has_explicit_index = module.files().iter()
-            .any(|f| f.name() == "index" || f.filename_with_extension("typescript") == "index.ts");
-
-        if has_explicit_index {
-            let index_ts_path = module_path.join("index.ts");
-            ContentUpdater::update_js_index_file(&index_ts_path, &export_declarations)?;
+        // Generate the barrel file according to the barrel policy
+        if BarrelPolicy::is_maintained(project, module, "typescript") {
+            let barrel_path = module_path.join(&barrel_filename);
+            ContentUpdater::update_js_index_file(fs, &barrel_path, &export_declarations)?;
         }
 
         Ok(())
     }
 
+    /// Build the barrel export statement for a code file based on its `export` setting
+    /// - "star": `export * from './x';` (default for .ts/.tsx)
+    /// - "namespace": `export * as x from './x';`
+    /// - "default-as-named": `export { default as Button } from './Button.vue';`
+    /// - "none": not exported (default for other files such as .vue)
+    fn export_statement(codefile: &CodeFile, filename: &str, module_system: &str) -> Option<String> {
+        let default_style = if Self::is_typescript_code_file(filename) { "star" } else { "none" };
+        let style = codefile.export_setting().unwrap_or(default_style);
+        let specifier = Self::file_specifier(codefile, filename, module_system);
+        let identifier = Self::to_identifier(&Self::get_module_name_for_export(codefile));
+
+        match style {
+            "star" => Some(format!("export * from '{}';", specifier)),
+            "namespace" => Some(format!("export * as {} from '{}';", identifier, specifier)),
+            "default-as-named" => Some(format!("export {{ default as {} }} from '{}';", identifier, specifier)),
+            _ => None,
+        }
+    }
+
+    /// Get the import specifier for a code file
+    /// TypeScript sources drop their extension, except under NodeNext which requires `.js`.
+    /// Other files (.vue, .js, ...) are imported by their full file name.
+    fn file_specifier(codefile: &CodeFile, filename: &str, module_system: &str) -> String {
+        if Self::is_typescript_code_file(filename) {
+            let module_name = Self::get_module_name_for_export(codefile);
+            if module_system == "nodenext" {
+                format!("./{}.js", module_name)
+            } else {
+                format!("./{}", module_name)
+            }
+        } else {
+            format!("./{}", filename)
+        }
+    }
+
+    /// Get the import specifier for a submodule directory
+    /// Directory imports only resolve to index.ts, so other barrel names are spelled out.
+    fn directory_specifier(directory: &str, barrel_filename: &str, module_system: &str) -> String {
+        let barrel_stem = barrel_filename
+            .strip_suffix(".tsx")
+            .or_else(|| barrel_filename.strip_suffix(".ts"))
+            .unwrap_or(barrel_filename);
+
+        if module_system == "nodenext" {
+            format!("./{}/{}.js", directory, barrel_stem)
+        } else if barrel_stem == "index" {
+            format!("./{}", directory)
+        } else {
+            format!("./{}/{}", directory, barrel_stem)
+        }
+    }
+
+    /// Convert a file stem into a valid identifier (e.g. "user-card" -> "userCard")
+    fn to_identifier(stem: &str) -> String {
+        let mut identifier = String::new();
+        for (i, part) in stem.split(|c: char| !c.is_alphanumeric()).filter(|p| !p.is_empty()).enumerate() {
+            if i == 0 {
+                identifier.push_str(part);
+            } else {
+                let mut chars = part.chars();
+                if let Some(first) = chars.next() {
+                    identifier.extend(first.to_uppercase());
+                    identifier.push_str(chars.as_str());
+                }
+            }
+        }
+
+        if identifier.chars().next().is_none_or(|c| c.is_numeric()) {
+            identifier = format!("_{}", identifier);
+        }
+
+        identifier
+    }
+
     /// Get module name for export statement (removes extension)
     fn get_module_name_for_export(codefile: &CodeFile) -> String {
         let name = codefile.name();
@@ -85,15 +173,16 @@ impl TypeScriptModuleGenerator {
 
     /// Generate package.json for TypeScript project
     pub fn generate_package_json<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
-        project_name: &str,
+        project: &Project,
     ) -> Result<()> {
         let package_json_path = project_path.as_ref().join("package.json");
-        let package_content = Self::generate_package_json_content(project_name);
+        let package_content = Self::generate_package_json_content(&NodeWorkspaceHandler::package_name(project), Self::module_system(project));
         
         // Only create package.json if it doesn't already exist
-        if !package_json_path.exists() {
-            fs::write(&package_json_path, package_content)
+        if !fs.exists(&package_json_path) {
+            fs.write(&package_json_path, package_content)
                 .with_context(|| format!("Failed to create package.json: {}", package_json_path.display()))?;
         }
 
@@ -102,14 +191,16 @@ impl TypeScriptModuleGenerator {
 
     /// Generate tsconfig.json for TypeScript project
     pub fn generate_tsconfig_json<P: AsRef<Path>>(
+        fs: &dyn Fs,
         project_path: P,
+        project: &Project,
     ) -> Result<()> {
         let tsconfig_path = project_path.as_ref().join("tsconfig.json");
-        let tsconfig_content = Self::generate_tsconfig_content();
+        let tsconfig_content = Self::generate_tsconfig_content(Self::module_system(project));
         
         // Only create tsconfig.json if it doesn't already exist
-        if !tsconfig_path.exists() {
-            fs::write(&tsconfig_path, tsconfig_content)
+        if !fs.exists(&tsconfig_path) {
+            fs.write(&tsconfig_path, tsconfig_content)
                 .with_context(|| format!("Failed to create tsconfig.json: {}", tsconfig_path.display()))?;
         }
 
@@ -125,13 +216,20 @@ impl TypeScriptModuleGenerator {
     }
 
     /// Generate package.json content
-    fn generate_package_json_content(project_name: &str) -> String {
+    fn generate_package_json_content(project_name: &str, module_system: &str) -> String {
+        // ESM output needs "type": "module" so Node treats dist/*.js as ES modules
+        let type_field = if module_system == "commonjs" {
+            ""
+        } else {
+            "\n  \"type\": \"module\","
+        };
+
         format!(
             r#"{{
   "name": "{}",
   "version": "1.0.0",
   "description": "",
-  "main": "dist/index.js",
+  "main": "dist/index.js",{}
   "scripts": {{
     "build": "tsc",
     "dev": "tsc --watch",
@@ -146,16 +244,28 @@ impl TypeScriptModuleGenerator {
   "license": "ISC"
 }}
 "#,
-            project_name
+            project_name, type_field
         )
     }
 
+    /// Get tsconfig "module" and "moduleResolution" values for a module system
+    fn compiler_module_options(module_system: &str) -> (&'static str, &'static str) {
+        match module_system {
+            "esm" => ("ESNext", "Bundler"),
+            "nodenext" => ("NodeNext", "NodeNext"),
+            _ => ("commonjs", "node"),
+        }
+    }
+
     /// Generate tsconfig.json content
-    fn generate_tsconfig_content() -> String {
-        r#"{
-  "compilerOptions": {
+    fn generate_tsconfig_content(module_system: &str) -> String {
+        let (module, module_resolution) = Self::compiler_module_options(module_system);
+        format!(
+            r#"{{
+  "compilerOptions": {{
     "target": "ES2020",
-    "module": "commonjs",
+    "module": "{}",
+    "moduleResolution": "{}",
     "outDir": "./dist",
     "rootDir": "./src",
     "strict": true,
@@ -165,10 +275,124 @@ impl TypeScriptModuleGenerator {
     "declaration": true,
     "declarationMap": true,
     "sourceMap": true
-  },
+  }},
   "include": ["src/**/*"],
   "exclude": ["node_modules", "dist"]
+}}
+"#,
+            module, module_resolution
+        )
+    }
 }
-"#.to_string()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project(module: Option<&str>) -> Project {
+        Project {
+            name: "web".to_string(),
+            root: true,
+            lang: "typescript".to_string(),
+            module: module.map(|m| m.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn components_module() -> Module {
+        Module {
+            name: Some("components".to_string()),
+            tree: vec![Module {
+                name: Some("forms".to_string()),
+                file: vec![CodeFile { name: "index".to_string(), ..Default::default() }],
+                ..Default::default()
+            }],
+            file: vec![
+                CodeFile { name: "index".to_string(), ..Default::default() },
+                CodeFile { name: "utils".to_string(), ..Default::default() },
+                CodeFile { name: "Button.vue".to_string(), export: Some("default-as-named".to_string()), ..Default::default() },
+                CodeFile { name: "icon-set".to_string(), export: Some("namespace".to_string()), ..Default::default() },
+                CodeFile { name: "Modal.vue".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_nodenext_specifiers() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        TypeScriptModuleGenerator::generate_module(&RealFs, base_path, &components_module(), &[], &make_project(Some("nodenext"))).unwrap();
+
+        let index = fs::read_to_string(base_path.join("components/index.ts")).unwrap();
+        assert!(index.contains("export * from './utils.js';"));
+        assert!(index.contains("export { default as Button } from './Button.vue';"));
+        assert!(index.contains("export * as iconSet from './icon-set.js';"));
+        assert!(index.contains("export * from './forms/index.js';"));
+        assert!(!index.contains("Modal"));
+    }
+
+    #[test]
+    fn test_commonjs_specifiers() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        TypeScriptModuleGenerator::generate_module(&RealFs, base_path, &components_module(), &[], &make_project(None)).unwrap();
+
+        let index = fs::read_to_string(base_path.join("components/index.ts")).unwrap();
+        assert!(index.contains("export * from './utils';"));
+        assert!(index.contains("export * from './forms';"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_auto_barrels_with_custom_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        let mut project = make_project(None);
+        project.barrels = Some("auto".to_string());
+        project.index = Some("mod.ts".to_string());
+
+        let module = Module {
+            name: Some("lib".to_string()),
+            tree: vec![
+                Module {
+                    name: Some("http".to_string()),
+                    file: vec![CodeFile { name: "client".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+                Module {
+                    name: Some("internal".to_string()),
+                    barrels: Some("off".to_string()),
+                    file: vec![CodeFile { name: "secret".to_string(), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            file: vec![CodeFile { name: "types".to_string(), ..Default::default() }],
+            ..Default::default()
+        };
+
+        TypeScriptModuleGenerator::generate_module(&RealFs, base_path, &module, &[], &project).unwrap();
+
+        let barrel = fs::read_to_string(base_path.join("lib/mod.ts")).unwrap();
+        assert!(barrel.contains("export * from './types';"));
+        assert!(barrel.contains("export * from './http/mod';"));
+        assert!(!barrel.contains("internal"));
+        assert!(base_path.join("lib/http/mod.ts").exists());
+        assert!(!base_path.join("lib/internal/mod.ts").exists());
+        assert!(!base_path.join("lib/index.ts").exists());
+    }
+
+    #[test]
+    fn test_tsconfig_module_options() {
+        let content = TypeScriptModuleGenerator::generate_tsconfig_content("nodenext");
+        assert!(content.contains("\"module\": \"NodeNext\""));
+        assert!(content.contains("\"moduleResolution\": \"NodeNext\""));
+
+        let package_json = TypeScriptModuleGenerator::generate_package_json_content("web", "esm");
+        assert!(package_json.contains("\"type\": \"module\""));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::Project;
+use crate::shared::utils::paths;
+use super::module_generator::ZigModuleGenerator;
+
+/// Zig build handler for generating build.zig / build.zig.zon and the src tree
+pub struct ZigBuildHandler;
+
+impl ZigBuildHandler {
+    /// Generate complete Zig project structure
+    pub fn generate_project<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        project_path: P,
+        project: &Project,
+    ) -> Result<()> {
+        let project_path = project_path.as_ref();
+
+        // Create project directory
+        fs.create_dir_all(project_path)
+            .with_context(|| format!("Failed to create project directory: {}", project_path.display()))?;
+
+        // Generate project-level code files (only if they don't exist)
+        for codefile in project.files() {
+            let filename = codefile.filename_with_extension("zig");
+            let file_path = project_path.join(&filename);
+
+            if !fs.exists(&file_path) {
+                fs.write(&file_path, "")
+                    .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+            }
+        }
+
+        // Generate module structure with @import aggregators
+        for module in project.tree() {
+            ZigModuleGenerator::generate_module(fs, project_path, module, &[])?;
+        }
+
+        // Generate build.zig and build.zig.zon (created once)
+        let package_name = Self::package_name(fs, project_path, project);
+        let build_zig_path = project_path.join("build.zig");
+        if !fs.exists(&build_zig_path) {
+            fs.write(&build_zig_path, Self::generate_build_zig_content(&package_name, project))
+                .with_context(|| format!("Failed to create build.zig: {}", build_zig_path.display()))?;
+        }
+
+        let build_zig_zon_path = project_path.join("build.zig.zon");
+        if !fs.exists(&build_zig_zon_path) {
+            fs.write(&build_zig_zon_path, Self::generate_build_zig_zon_content(&package_name))
+                .with_context(|| format!("Failed to create build.zig.zon: {}", build_zig_zon_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the package name (last path segment, directory name for ".")
+    fn package_name(fs: &dyn Fs, project_path: &Path, project: &Project) -> String {
+        let name = if project.name() == "." {
+            paths::directory_name(fs, project_path, "app")
+        } else {
+            project.name().rsplit('/').next().unwrap_or(project.name()).to_string()
+        };
+
+        name.replace('-', "_")
+    }
+
+    /// Get the root source file of src/ (root.zig or main.zig), if the project has a src module
+    fn src_root_file(project: &Project) -> Option<&'static str> {
+        project.tree().iter()
+            .find(|m| m.name() == "src")
+            .map(ZigModuleGenerator::root_filename)
+    }
+
+    /// Generate build.zig content
+    /// An executable is added for src/main.zig, otherwise a static library for src/root.zig
+    fn generate_build_zig_content(package_name: &str, project: &Project) -> String {
+        let root_file = Self::src_root_file(project).unwrap_or("root.zig");
+
+        let artifact = if root_file == "main.zig" {
+            format!(
+                r#"    const exe = b.addExecutable(.{{
+        .name = "{}",
+        .root_source_file = b.path("src/main.zig"),
+        .target = target,
+        .optimize = optimize,
+    }});
+    b.installArtifact(exe);
+
+    const run_cmd = b.addRunArtifact(exe);
+    run_cmd.step.dependOn(b.getInstallStep());
+    if (b.args) |args| {{
+        run_cmd.addArgs(args);
+    }}
+
+    const run_step = b.step("run", "Run the app");
+    run_step.dependOn(&run_cmd.step);
+"#,
+                package_name
+            )
+        } else {
+            format!(
+                r#"    const lib = b.addStaticLibrary(.{{
+        .name = "{}",
+        .root_source_file = b.path("src/root.zig"),
+        .target = target,
+        .optimize = optimize,
+    }});
+    b.installArtifact(lib);
+"#,
+                package_name
+            )
+        };
+
+        format!(
+            r#"const std = @import("std");
+
+pub fn build(b: *std.Build) void {{
+    const target = b.standardTargetOptions(.{{}});
+    const optimize = b.standardOptimizeOption(.{{}});
+
+{}
+    const unit_tests = b.addTest(.{{
+        .root_source_file = b.path("src/{}"),
+        .target = target,
+        .optimize = optimize,
+    }});
+
+    const run_unit_tests = b.addRunArtifact(unit_tests);
+    const test_step = b.step("test", "Run unit tests");
+    test_step.dependOn(&run_unit_tests.step);
+}}
+"#,
+            artifact, root_file
+        )
+    }
+
+    /// Generate build.zig.zon content
+    fn generate_build_zig_zon_content(package_name: &str) -> String {
+        format!(
+            r#".{{
+    .name = "{}",
+    .version = "0.1.0",
+    .minimum_zig_version = "0.13.0",
+    .dependencies = .{{}},
+    .paths = .{{
+        "build.zig",
+        "build.zig.zon",
+        "src",
+    }},
+}}
+"#,
+            package_name
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use crate::project_management::config::models::{CodeFile, Module};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generate_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = Project {
+            name: "my-tool".to_string(),
+            lang: "zig".to_string(),
+            tree: vec![Module {
+                name: Some("src".to_string()),
+                file: vec![
+                    CodeFile { name: "main".to_string(), ..Default::default() },
+                    CodeFile { name: "parser".to_string(), ..Default::default() },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        ZigBuildHandler::generate_project(&RealFs, temp_dir.path(), &project).unwrap();
+
+        let build_zig = fs::read_to_string(temp_dir.path().join("build.zig")).unwrap();
+        assert!(build_zig.contains(".name = \"my_tool\""));
+        assert!(build_zig.contains("b.addExecutable"));
+        assert!(build_zig.contains("b.path(\"src/main.zig\")"));
+
+        let zon = fs::read_to_string(temp_dir.path().join("build.zig.zon")).unwrap();
+        assert!(zon.contains(".name = \"my_tool\""));
+
+        let main_zig = fs::read_to_string(temp_dir.path().join("src/main.zig")).unwrap();
+        assert!(main_zig.contains("pub const parser = @import(\"parser.zig\");"));
+
+        // build.zig is only created once
+        fs::write(temp_dir.path().join("build.zig"), "// custom\n").unwrap();
+        ZigBuildHandler::generate_project(&RealFs, temp_dir.path(), &project).unwrap();
+        assert_eq!(fs::read_to_string(temp_dir.path().join("build.zig")).unwrap(), "// custom\n");
+    }
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+use anyhow::Result;
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::project_management::config::models::Project;
+use crate::code_generation::language::zig::build_handler::ZigBuildHandler;
+use crate::shared::utils::file_system::Fs;
+
+/// Zig projects with @import aggregators and build.zig
+pub struct ZigGenerator;
+
+impl LanguageGenerator for ZigGenerator {
+    fn name(&self) -> &str {
+        "zig"
+    }
+
+    fn extension(&self) -> Option<&str> {
+        Some("zig")
+    }
+
+    fn generate_project(&self, fs: &dyn Fs, project_path: &Path, project: &Project) -> Result<()> {
+        ZigBuildHandler::generate_project(fs, project_path, project)
+    }
+
+    fn aggregator_file(&self) -> Option<&str> {
+        Some("mod.zig")
+    }
+
+    fn excluded_files(&self) -> Vec<&str> {
+        vec!["build.zig", "build.zig.zon"]
+    }
+
+    fn excluded_dirs(&self) -> Vec<&str> {
+        vec![".zig-cache", "zig-cache", "zig-out"]
+    }
+
+    fn new_skeleton(&self, _project_name: &str, _is_root: bool) -> String {
+        // Modern standard: src/ directory structure
+        r#"  tree:
+    - name: src
+      file:
+        - name: main
+"#.to_string()
+    }
+}
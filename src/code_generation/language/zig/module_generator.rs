@@ -0,0 +1,227 @@
+use crate::shared::utils::file_system::Fs;
+use std::path::Path;
+use anyhow::{Context, Result};
+use crate::project_management::config::models::{Module, CodeFile, Project};
+use crate::shared::utils::content_updater::ContentUpdater;
+
+/// Zig-specific module generator
+pub struct ZigModuleGenerator;
+
+impl ZigModuleGenerator {
+    /// Check if a file is a Zig code file (ends with .zig)
+    fn is_zig_code_file(filename: &str) -> bool {
+        filename.ends_with(".zig")
+    }
+
+    /// Generate Zig module structure recursively
+    /// Every directory except src gets a mod.zig aggregator (src uses root.zig or main.zig instead)
+    pub fn generate_module<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        base_path: P,
+        module: &Module,
+        parent_modules: &[String],
+    ) -> Result<()> {
+        let module_name = module.name();
+        let module_path = base_path.as_ref().join(&module_name);
+
+        // Create directory
+        fs.create_dir_all(&module_path)
+            .with_context(|| format!("Failed to create directory: {}", module_path.display()))?;
+
+        let is_src_root = module_name == "src" && parent_modules.is_empty();
+        let aggregator_filename = if is_src_root { Self::root_filename(module) } else { "mod.zig" };
+        let aggregator_path = module_path.join(aggregator_filename);
+        let is_new_aggregator = !fs.exists(&aggregator_path);
+
+        // Generate code files in this module (the aggregator is written below)
+        for codefile in module.files() {
+            let filename = codefile.filename_with_extension("zig");
+            let file_path = module_path.join(&filename);
+
+            // Create file (only if it doesn't exist)
+            if filename != aggregator_filename && !fs.exists(&file_path) {
+                let content = if filename == "main.zig" {
+                    Self::generate_main_zig_content()
+                } else {
+                    String::new()
+                };
+
+                fs.write(&file_path, content)
+                    .with_context(|| format!("Failed to create file: {}", file_path.display()))?;
+            }
+        }
+
+        // Process subtree recursively
+        for submodule in module.subtree() {
+            let mut new_parent_modules = parent_modules.to_vec();
+            new_parent_modules.push(module_name.clone());
+
+            Self::generate_module(fs, &module_path, submodule, &new_parent_modules)?;
+        }
+
+        let import_declarations = Self::generate_import_declarations(module.files(), module.subtree());
+
+        ContentUpdater::update_rust_module_file(fs, &aggregator_path, &import_declarations, None)?;
+
+        // A new main.zig gets its main function after the managed section
+        if is_new_aggregator && aggregator_filename == "main.zig" {
+            let content = fs.read_to_string(&aggregator_path)
+                .with_context(|| format!("Failed to read file: {}", aggregator_path.display()))?;
+            fs.write(&aggregator_path, format!("{}{}", content, Self::generate_main_zig_content()))
+                .with_context(|| format!("Failed to write file: {}", aggregator_path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the file that aggregates src/: root.zig if listed, else main.zig if listed, else root.zig
+    pub fn root_filename(src_module: &Module) -> &'static str {
+        let has_file = |name: &str| src_module.files().iter()
+            .any(|f| f.filename_with_extension("zig") == name);
+
+        if !has_file("root.zig") && has_file("main.zig") {
+            "main.zig"
+        } else {
+            "root.zig"
+        }
+    }
+
+    /// Build `pub const foo = @import("foo.zig");` declarations for files and subdirectories
+    pub fn generate_import_declarations(files: &[CodeFile], submodules: &[Module]) -> Vec<String> {
+        let mut declarations = Vec::new();
+
+        for codefile in files {
+            let filename = codefile.filename_with_extension("zig");
+            if Self::is_zig_code_file(&filename)
+                && !matches!(filename.as_str(), "mod.zig" | "root.zig" | "main.zig" | "build.zig")
+            {
+                let name = filename.trim_end_matches(".zig");
+                declarations.push(Self::import_declaration(name, &filename, codefile.pub_setting()));
+            }
+        }
+
+        for submodule in submodules {
+            let import_path = format!("{}/mod.zig", submodule.name());
+            declarations.push(Self::import_declaration(&submodule.name(), &import_path, submodule.pub_setting()));
+        }
+
+        declarations
+    }
+
+    /// Build one import declaration (public unless `pub: no`)
+    fn import_declaration(name: &str, import_path: &str, pub_setting: Option<&str>) -> String {
+        let visibility = if pub_setting == Some("no") { "" } else { "pub " };
+        format!("{}const {} = @import(\"{}\");", visibility, Self::identifier(name), import_path)
+    }
+
+    /// Quote names that are not valid Zig identifiers (my-util -> @"my-util")
+    fn identifier(name: &str) -> String {
+        let is_valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if is_valid {
+            name.to_string()
+        } else {
+            format!("@\"{}\"", name)
+        }
+    }
+
+    /// Collect aggregator paths (relative to the project) that moli writes without a `file` entry
+    pub fn collect_aggregator_paths(project: &Project) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        for module in project.tree() {
+            if module.name() == "src" {
+                paths.push(format!("src/{}", Self::root_filename(module)));
+                for submodule in module.subtree() {
+                    Self::collect_module_aggregator_paths(submodule, "src/", &mut paths);
+                }
+            } else {
+                Self::collect_module_aggregator_paths(module, "", &mut paths);
+            }
+        }
+
+        paths
+    }
+
+    fn collect_module_aggregator_paths(module: &Module, dir_path: &str, paths: &mut Vec<String>) {
+        let module_dir = format!("{}{}/", dir_path, module.name());
+        paths.push(format!("{}mod.zig", module_dir));
+
+        for submodule in module.subtree() {
+            Self::collect_module_aggregator_paths(submodule, &module_dir, paths);
+        }
+    }
+
+    /// Generate main.zig content
+    fn generate_main_zig_content() -> String {
+        r#"const std = @import("std");
+
+pub fn main() !void {
+    std.debug.print("Hello, world!\n", .{});
+}
+"#.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_src() -> Module {
+        Module {
+            name: Some("src".to_string()),
+            file: vec![
+                CodeFile { name: "main".to_string(), ..Default::default() },
+                CodeFile { name: "config".to_string(), ..Default::default() },
+                CodeFile { name: "secret".to_string(), r#pub: Some("no".to_string()), ..Default::default() },
+            ],
+            tree: vec![Module {
+                name: Some("net".to_string()),
+                file: vec![CodeFile { name: "http-client".to_string(), ..Default::default() }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_module() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+
+        ZigModuleGenerator::generate_module(&RealFs, base_path, &make_src(), &[]).unwrap();
+
+        let main_zig = fs::read_to_string(base_path.join("src/main.zig")).unwrap();
+        assert!(main_zig.starts_with(
+            "// start auto exported by moli.\npub const config = @import(\"config.zig\");\nconst secret = @import(\"secret.zig\");\npub const net = @import(\"net/mod.zig\");\n// end auto exported by moli."
+        ));
+        assert!(main_zig.contains("pub fn main() !void {"));
+        assert!(!base_path.join("src/root.zig").exists());
+
+        let mod_zig = fs::read_to_string(base_path.join("src/net/mod.zig")).unwrap();
+        assert!(mod_zig.contains("pub const @\"http-client\" = @import(\"http-client.zig\");"));
+
+        // Rerunning keeps one managed section
+        ZigModuleGenerator::generate_module(&RealFs, base_path, &make_src(), &[]).unwrap();
+        let rerun = fs::read_to_string(base_path.join("src/main.zig")).unwrap();
+        assert_eq!(main_zig, rerun);
+    }
+
+    #[test]
+    fn test_collect_aggregator_paths() {
+        let project = Project {
+            lang: "zig".to_string(),
+            tree: vec![make_src()],
+            ..Default::default()
+        };
+
+        assert_eq!(ZigModuleGenerator::collect_aggregator_paths(&project), vec![
+            "src/main.zig".to_string(),
+            "src/net/mod.zig".to_string(),
+        ]);
+    }
+}
@@ -0,0 +1,14 @@
+// start auto exported by moli.
+pub mod project_management;
+pub mod code_generation;
+pub mod shared;
+pub mod cli;
+// end auto exported by moli.
+
+// Public library API: register a language, then generate from a loaded moli.yml
+pub use code_generation::core::generator::CodeGenerator;
+pub use code_generation::core::language_generator::LanguageGenerator;
+pub use code_generation::core::language_registry::LanguageRegistry;
+pub use project_management::config::models::{MoliConfig, Project, Module, CodeFile};
+pub use shared::utils::file_system::{Fs, RealFs};
+pub use shared::utils::memory_fs::MemoryFs;
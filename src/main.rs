@@ -1,12 +1,5 @@
-// start auto exported by moli.
-mod project_management;
-mod code_generation;
-mod shared;
-mod cli;
-// end auto exported by moli.
-
 use clap::Command;
-use crate::cli::command;
+use moli::cli::command;
 
 fn main() -> anyhow::Result<()> {
 
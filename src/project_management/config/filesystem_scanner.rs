@@ -1,9 +1,10 @@
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use anyhow::{Context, Result};
-use ignore::WalkBuilder;
 use crate::project_management::config::models::MoliConfig;
 use crate::project_management::config::path_collector::PathCollector;
+use crate::code_generation::core::language_registry::LanguageRegistry;
+use crate::shared::utils::file_system::Fs;
 
 /// Represents a file or directory on the filesystem that is NOT managed by moli.yml
 #[derive(Debug, Clone)]
@@ -16,102 +17,86 @@ pub struct UnmanagedEntry {
     pub is_directory: bool,
 }
 
-/// Files that moli manages automatically (should not be shown to users)
-const MANAGED_FILES: &[&str] = &[
-    "mod.rs",
-    "__init__.py",
-    "index.ts",
-    "index.js",
-];
-
-/// Config/meta files that should be excluded from load candidates
+/// Files excluded from scan results regardless of language
+/// (language manifests and lock files come from the language registry)
 const EXCLUDED_FILES: &[&str] = &[
     "moli.yml",
-    "Cargo.toml",
-    "Cargo.lock",
-    "package.json",
-    "package-lock.json",
-    "yarn.lock",
-    "pnpm-lock.yaml",
-    "go.mod",
-    "go.sum",
-    "pyproject.toml",
-    "setup.py",
-    "setup.cfg",
     ".gitignore",
     ".gitattributes",
 ];
 
-/// Directories that should always be excluded
+/// Directories never descended into regardless of language
+/// (build output and dependency directories come from the language registry)
 const EXCLUDED_DIRS: &[&str] = &[
     ".git",
-    "node_modules",
-    "target",
-    "__pycache__",
-    ".venv",
-    "venv",
 ];
 
 pub struct FilesystemScanner;
 
 impl FilesystemScanner {
     /// Scan the filesystem and return entries not managed by moli.yml
-    pub fn scan(config: &MoliConfig) -> Result<Vec<UnmanagedEntry>> {
+    pub fn scan(fs: &dyn Fs, config: &MoliConfig) -> Result<Vec<UnmanagedEntry>> {
         // Collect all managed paths from moli.yml
         let managed_entries = PathCollector::collect_all_entries(config);
-        let managed_paths: HashSet<String> = managed_entries
+        let mut managed_paths: HashSet<String> = managed_entries
             .iter()
             .map(|e| e.display_path.clone())
             .collect();
+        managed_paths.extend(PathCollector::collect_companion_paths(config));
+        let init_free_dirs: HashSet<String> = PathCollector::collect_init_free_dirs(config)
+            .into_iter()
+            .collect();
 
         let mut entries = Vec::new();
-        let excluded_files: HashSet<&str> = EXCLUDED_FILES.iter().copied().collect();
-        let managed_files: HashSet<&str> = MANAGED_FILES.iter().copied().collect();
-        let excluded_dirs: HashSet<&str> = EXCLUDED_DIRS.iter().copied().collect();
+        // Manifests and build directories only count for the languages the projects use
+        let registry = LanguageRegistry::global()
+            .subset(config.projects().iter().map(|p| p.language()));
+        let excluded_files: HashSet<String> = EXCLUDED_FILES.iter()
+            .map(|f| f.to_string())
+            .chain(registry.excluded_files())
+            .collect();
+        let excluded_extensions = registry.excluded_extensions();
+        let managed_files: HashSet<String> = registry.managed_files().into_iter().collect();
+        let excluded_dirs: HashSet<String> = EXCLUDED_DIRS.iter()
+            .map(|d| d.to_string())
+            .chain(registry.excluded_dirs())
+            .collect();
 
         // Walk the filesystem respecting .gitignore
-        let walker = WalkBuilder::new(".")
-            .hidden(true)       // skip hidden files
-            .git_ignore(true)   // respect .gitignore
-            .git_global(true)   // respect global gitignore
-            .git_exclude(true)  // respect .git/info/exclude
-            .build();
-
-        for result in walker {
-            let entry = result.context("Failed to read directory entry")?;
-            let path = entry.path();
-
-            // Skip the root directory itself
-            if path == Path::new(".") {
-                continue;
-            }
+        let walked = fs.walk(".")
+            .context("Failed to read directory entry")?;
+
+        for entry in walked {
+            let path = entry.path.as_path();
 
             // Get relative path (strip leading ./)
             let relative = path.strip_prefix("./").unwrap_or(path);
             let relative_str = relative.to_string_lossy();
 
-            // Skip excluded directories
-            if entry.file_type().map_or(false, |ft| ft.is_dir()) {
-                if let Some(name) = relative.file_name() {
-                    if excluded_dirs.contains(name.to_string_lossy().as_ref()) {
-                        continue;
-                    }
-                }
+            // Skip excluded directories and everything below them
+            let directory = if entry.is_dir { Some(relative) } else { relative.parent() };
+            if directory.is_some_and(|dir| {
+                dir.components().any(|c| excluded_dirs.contains(c.as_os_str().to_string_lossy().as_ref()))
+            }) {
+                continue;
             }
 
             // Skip excluded files
             if let Some(file_name) = relative.file_name() {
                 let name = file_name.to_string_lossy();
-                if excluded_files.contains(name.as_ref()) {
+                if excluded_files.contains(name.as_ref())
+                    || excluded_extensions.iter().any(|ext| name.ends_with(ext.as_str())) {
                     continue;
                 }
-                // Skip moli-managed module files
-                if managed_files.contains(name.as_ref()) {
+                // Skip moli-managed module files (an __init__.py in a directory moli keeps
+                // init-free was written by the user and is reported like any other file)
+                if managed_files.contains(name.as_ref())
+                    && !(name == "__init__.py" && Self::is_in_init_free_dir(relative, &init_free_dirs)) {
                     continue;
                 }
             }
 
-            let is_dir = entry.file_type().map_or(false, |ft| ft.is_dir());
+            let is_dir = entry.is_dir;
             let display_path = if is_dir {
                 format!("{}/", relative_str)
             } else {
@@ -158,32 +143,31 @@ impl FilesystemScanner {
         Ok(entries)
     }
 
+    /// Check if a file's parent directory is one that moli manages without __init__.py
+    fn is_in_init_free_dir(relative: &Path, init_free_dirs: &HashSet<String>) -> bool {
+        relative.parent()
+            .map(|parent| format!("{}/", parent.to_string_lossy()))
+            .is_some_and(|dir| init_free_dirs.contains(&dir))
+    }
+
     /// Strip standard language extension from a filename, returning the stem if applicable
     fn strip_standard_extension(path: &str) -> Option<String> {
-        let standard_extensions = [".rs", ".go", ".py", ".ts", ".js"];
-        for ext in &standard_extensions {
-            if path.ends_with(ext) {
-                return Some(path[..path.len() - ext.len()].to_string());
-            }
-        }
-        None
+        // Longest first so ".cpp" wins over any shorter extension it ends with
+        let mut standard_extensions = LanguageRegistry::global().extensions();
+        standard_extensions.sort_by_key(|ext| std::cmp::Reverse(ext.len()));
+        standard_extensions.iter()
+            .find(|ext| path.ends_with(ext.as_str()))
+            .map(|ext| path[..path.len() - ext.len()].to_string())
     }
 
     /// Remove the standard language extension from a filename for moli.yml entry
     pub fn filename_without_standard_extension(filename: &str, language: &str) -> String {
-        let ext = match language {
-            "rust" => ".rs",
-            "go" => ".go",
-            "python" => ".py",
-            "typescript" => ".ts",
-            "javascript" => ".js",
-            _ => return filename.to_string(),
+        let Some(ext) = LanguageRegistry::global().extension(language) else {
+            return filename.to_string();
         };
-        if filename.ends_with(ext) {
-            filename[..filename.len() - ext.len()].to_string()
-        } else {
-            filename.to_string()
-        }
+        filename.strip_suffix(&format!(".{}", ext))
+            .unwrap_or(filename)
+            .to_string()
     }
 }
 
@@ -234,4 +218,40 @@ mod tests {
             "config.yaml"
         );
     }
+
+    #[test]
+    fn test_scan_in_memory() {
+        use crate::project_management::config::models::*;
+        use crate::shared::utils::memory_fs::MemoryFs;
+
+        let config = MoliConfig {
+            projects: vec![Project {
+                name: "demo".to_string(),
+                root: true,
+                lang: "rust".to_string(),
+                tree: vec![Module {
+                    name: Some("src".to_string()),
+                    file: vec![CodeFile { name: "main".to_string(), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let memory = MemoryFs::new()
+            .with_file("moli.yml", "")
+            .with_file("Cargo.toml", "")
+            .with_file("src/main.rs", "")
+            .with_file("src/mod.rs", "")
+            .with_file("src/extra.rs", "")
+            .with_file("target/debug/demo", "")
+            .with_file("docs/guide.md", "")
+            .with_file("docs/package.json", "");
+
+        let entries = FilesystemScanner::scan(&memory, &config).unwrap();
+
+        // package.json is only a manifest for JavaScript/TypeScript projects
+        let paths: Vec<&str> = entries.iter().map(|e| e.display_path.as_str()).collect();
+        assert_eq!(paths, vec!["docs/", "docs/guide.md", "docs/package.json", "src/extra.rs"]);
+    }
 }
@@ -1,19 +1,104 @@
 use serde::{Deserialize, Serialize};
+use crate::code_generation::core::language_registry::LanguageRegistry;
 
 /// v2 moli.yml configuration root
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MoliConfig {
     #[serde(rename = "$value")]
     pub projects: Vec<Project>,
+    /// Custom languages declared in a `- languages:` entry
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<LanguageDefinition>,
+}
+
+/// `- languages:` entry of moli.yml
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LanguagesSection {
+    pub languages: Vec<LanguageDefinition>,
+}
+
+/// Custom language usable as `lang:` (Terraform modules, Protobuf packages, Nix files, ...)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LanguageDefinition {
+    pub name: String,
+    /// Extension appended to file names given without one (e.g. "tf")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+    /// Aggregation file maintained in the project root and every directory (e.g. "main.tf")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregator: Option<String>,
+    /// Aggregator line per child; `{name}` is the child name without extension, `{file}` the file or directory name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// Aggregator line per child file, overriding `line` ("" leaves files out)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_line: Option<String>,
+    /// Line comment prefix of the managed-section markers (defaults to "#")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Files ignored by `moli scan`/`sync`; entries ending with "/" are directories
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
 }
 
 /// Individual project configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Project {
     pub name: String,
     #[serde(default, skip_serializing_if = "is_false")]
     pub root: bool,
     pub lang: String,
+    /// Package name for project-level files (Go: defaults to "main"),
+    /// group id (Java/Kotlin: defaults to "com.example"),
+    /// root namespace (PHP: defaults to "App"),
+    /// or default package flag for directories (Python: `package: false`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<PackageSetting>,
+    /// Treat directories as PEP 420 namespace packages by default (Python)
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub namespace: bool,
+    /// PEP 517 build backend; generates pyproject.toml instead of setup.py (Python)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend: Option<String>,
+    /// Source layout: "flat" (default) or "src" (Python)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<String>,
+    /// Ship a py.typed marker in each package (Python)
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub typed: bool,
+    /// __init__.py export style: "star" (default), "all" or "submodules" (Python)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init: Option<String>,
+    /// Module system: "esm", "commonjs" or "nodenext" (TypeScript), "esm" or "commonjs" (JavaScript)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub module: Option<String>,
+    /// Maintain a package.json `exports` map for modules marked `pub: yes` (JavaScript)
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub exports: bool,
+    /// Build tool: "gradle" (default) or "maven" (Java)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<String>,
+    /// Header guard style: "ifndef" (C default) or "pragma" (C++ default) (C/C++)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guard: Option<String>,
+    /// Generate a Flutter pubspec.yaml and flutter_test skeletons (Dart)
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub flutter: bool,
+    /// Workspace manager for multi-project configs: "npm" (default), "yarn" or "pnpm" (JavaScript/TypeScript)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<String>,
+    /// Package scope, e.g. "@acme" (JavaScript/TypeScript)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Names of other projects in moli.yml this project depends on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Barrel maintenance policy: "auto", "explicit" (default) or "off" (JavaScript/TypeScript)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub barrels: Option<String>,
+    /// Barrel file name, e.g. "mod.ts" or "public-api" (defaults to "index")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub file: Vec<CodeFile>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -25,7 +110,7 @@ fn is_false(b: &bool) -> bool {
 }
 
 /// Module or directory structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Module {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -33,18 +118,66 @@ pub struct Module {
     pub from: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub r#pub: Option<String>,
+    /// Package name override (Go: defaults to the directory name),
+    /// or whether the directory is a package at all (Python: `package: false`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<PackageSetting>,
+    /// PEP 420 namespace package without __init__.py (Python)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<bool>,
+    /// Barrel maintenance policy override (JavaScript/TypeScript)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub barrels: Option<String>,
+    /// Barrel file name override (JavaScript/TypeScript)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tree: Vec<Module>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub file: Vec<CodeFile>,
 }
 
+/// `package:` value: either a package name or a package on/off flag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PackageSetting {
+    Flag(bool),
+    Name(String),
+}
+
+impl PackageSetting {
+    /// Get the package name, if this setting is a name
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            PackageSetting::Name(name) => Some(name),
+            PackageSetting::Flag(_) => None,
+        }
+    }
+
+    /// Get the package flag, if this setting is a flag
+    pub fn flag(&self) -> Option<bool> {
+        match self {
+            PackageSetting::Flag(flag) => Some(*flag),
+            PackageSetting::Name(_) => None,
+        }
+    }
+}
+
 /// Individual code file
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CodeFile {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub r#pub: Option<String>,
+    /// Package name override (Go: must match the other files in the directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    /// Test file scaffolding (Go: "internal" or "external", Elixir: "no" skips the paired test)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<String>,
+    /// Barrel export style: "star", "namespace", "default-as-named" or "none" (TypeScript)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export: Option<String>,
 }
 
 impl MoliConfig {
@@ -67,6 +200,52 @@ impl MoliConfig {
     pub fn is_single_project(&self) -> bool {
         self.root_project().is_some()
     }
+
+    /// Get custom language definitions
+    pub fn languages(&self) -> &[LanguageDefinition] {
+        &self.languages
+    }
+}
+
+impl LanguageDefinition {
+    /// Get the default extension without a leading dot
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+            .map(|ext| ext.trim_start_matches('.'))
+            .filter(|ext| !ext.is_empty())
+    }
+
+    /// Get the line comment prefix of the managed-section markers
+    pub fn comment(&self) -> &str {
+        self.comment.as_deref().unwrap_or("#")
+    }
+
+    /// Get the aggregator line template for child directories
+    pub fn dir_line(&self) -> Option<&str> {
+        self.line.as_deref().filter(|line| !line.is_empty())
+    }
+
+    /// Get the aggregator line template for child files
+    pub fn file_line(&self) -> Option<&str> {
+        self.file_line.as_deref()
+            .or(self.line.as_deref())
+            .filter(|line| !line.is_empty())
+    }
+
+    /// Get the ignored file names
+    pub fn ignored_files(&self) -> Vec<&str> {
+        self.ignore.iter()
+            .filter(|entry| !entry.ends_with('/'))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Get the ignored directory names (entries ending with "/")
+    pub fn ignored_dirs(&self) -> Vec<&str> {
+        self.ignore.iter()
+            .filter_map(|entry| entry.strip_suffix('/'))
+            .collect()
+    }
 }
 
 impl Project {
@@ -94,6 +273,112 @@ impl Project {
     pub fn files(&self) -> &[CodeFile] {
         &self.file
     }
+
+    /// Get package name for project-level files
+    pub fn package_setting(&self) -> Option<&str> {
+        self.package.as_ref().and_then(|p| p.name())
+    }
+
+    /// Get default package flag for directories
+    pub fn package_flag(&self) -> Option<bool> {
+        self.package.as_ref().and_then(|p| p.flag())
+    }
+
+    /// Check if directories default to namespace packages
+    pub fn is_namespace(&self) -> bool {
+        self.namespace
+    }
+
+    /// Get build backend setting
+    pub fn backend_setting(&self) -> Option<&str> {
+        self.backend.as_deref()
+    }
+
+    /// Check if the project uses a src layout
+    pub fn is_src_layout(&self) -> bool {
+        self.layout.as_deref() == Some("src")
+    }
+
+    /// Check if the project ships type information (py.typed)
+    pub fn is_typed(&self) -> bool {
+        self.typed
+    }
+
+    /// Get __init__.py export style (defaults to "star")
+    pub fn init_setting(&self) -> &str {
+        self.init.as_deref().unwrap_or("star")
+    }
+
+    /// Get module system setting
+    pub fn module_setting(&self) -> Option<&str> {
+        self.module.as_deref()
+    }
+
+    /// Check if the project maintains a package.json exports map
+    pub fn has_exports_map(&self) -> bool {
+        self.exports
+    }
+
+    /// Get build tool setting
+    pub fn build_setting(&self) -> Option<&str> {
+        self.build.as_deref()
+    }
+
+    /// Get header guard style setting
+    pub fn guard_setting(&self) -> Option<&str> {
+        self.guard.as_deref()
+    }
+
+    /// Check if the project is a Flutter app
+    pub fn is_flutter(&self) -> bool {
+        self.flutter
+    }
+
+    /// Get workspace manager setting
+    pub fn workspace_setting(&self) -> Option<&str> {
+        self.workspace.as_deref()
+    }
+
+    /// Get package scope setting
+    pub fn scope_setting(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    /// Get names of projects this project depends on
+    pub fn dependencies(&self) -> &[String] {
+        &self.depends_on
+    }
+
+    /// Get barrel policy setting
+    pub fn barrels_setting(&self) -> Option<&str> {
+        self.barrels.as_deref()
+    }
+
+    /// Get barrel file name setting
+    pub fn index_setting(&self) -> Option<&str> {
+        self.index.as_deref()
+    }
+    /// Get the names of the language-specific options set on the project
+    pub fn language_options(&self) -> Vec<&'static str> {
+        let options = [
+            ("package", self.package.is_some()),
+            ("namespace", self.namespace),
+            ("backend", self.backend.is_some()),
+            ("layout", self.layout.is_some()),
+            ("typed", self.typed),
+            ("init", self.init.is_some()),
+            ("module", self.module.is_some()),
+            ("exports", self.exports),
+            ("build", self.build.is_some()),
+            ("guard", self.guard.is_some()),
+            ("flutter", self.flutter),
+            ("workspace", self.workspace.is_some()),
+            ("scope", self.scope.is_some()),
+            ("barrels", self.barrels.is_some()),
+            ("index", self.index.is_some()),
+        ];
+        options.into_iter().filter(|(_, set)| *set).map(|(name, _)| name).collect()
+    }
 }
 
 impl Module {
@@ -156,6 +441,41 @@ impl Module {
     pub fn pub_setting(&self) -> Option<&str> {
         self.r#pub.as_deref()
     }
+
+    /// Get package name override for the module
+    pub fn package_setting(&self) -> Option<&str> {
+        self.package.as_ref().and_then(|p| p.name())
+    }
+
+    /// Get package flag for the module
+    pub fn package_flag(&self) -> Option<bool> {
+        self.package.as_ref().and_then(|p| p.flag())
+    }
+
+    /// Get namespace package setting for the module
+    pub fn namespace_setting(&self) -> Option<bool> {
+        self.namespace
+    }
+
+    /// Get barrel policy override for the module
+    pub fn barrels_setting(&self) -> Option<&str> {
+        self.barrels.as_deref()
+    }
+
+    /// Get barrel file name override for the module
+    pub fn index_setting(&self) -> Option<&str> {
+        self.index.as_deref()
+    }
+    /// Get the names of the language-specific options set on the module
+    pub fn language_options(&self) -> Vec<&'static str> {
+        let options = [
+            ("package", self.package.is_some()),
+            ("namespace", self.namespace.is_some()),
+            ("barrels", self.barrels.is_some()),
+            ("index", self.index.is_some()),
+        ];
+        options.into_iter().filter(|(_, set)| *set).map(|(name, _)| name).collect()
+    }
 }
 
 impl CodeFile {
@@ -172,13 +492,10 @@ impl CodeFile {
         } else {
             // Add language-specific extension
             let extension = match language {
-                "rust" => "rs",
-                "go" => "go",
-                "python" => "py",
-                "javascript" => "js",
-                "typescript" => "ts",
-                "markdown" => "md",
-                _ => "txt", // fallback
+                "markdown" => "md".to_string(),
+                _ => LanguageRegistry::global()
+                    .extension(language)
+                    .unwrap_or_else(|| "txt".to_string()), // fallback
             };
             format!("{}.{}", self.name, extension)
         }
@@ -188,4 +505,29 @@ impl CodeFile {
     pub fn pub_setting(&self) -> Option<&str> {
         self.r#pub.as_deref()
     }
+
+    /// Get package override for the file
+    pub fn package_setting(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
+
+    /// Get test scaffolding setting
+    pub fn test_setting(&self) -> Option<&str> {
+        self.test.as_deref()
+    }
+
+    /// Get barrel export style setting
+    pub fn export_setting(&self) -> Option<&str> {
+        self.export.as_deref()
+    }
+
+    /// Get the names of the language-specific options set on the file
+    pub fn language_options(&self) -> Vec<&'static str> {
+        let options = [
+            ("package", self.package.is_some()),
+            ("test", self.test.is_some()),
+            ("export", self.export.is_some()),
+        ];
+        options.into_iter().filter(|(_, set)| *set).map(|(name, _)| name).collect()
+    }
 }
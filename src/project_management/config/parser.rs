@@ -1,7 +1,11 @@
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 use anyhow::{Context, Result};
-use crate::project_management::config::models::MoliConfig;
+use crate::project_management::config::models::{MoliConfig, Project, LanguagesSection};
+use crate::code_generation::core::language_generator::LanguageGenerator;
+use crate::code_generation::core::language_registry::LanguageRegistry;
+use crate::code_generation::language::custom::generator::CustomLanguageGenerator;
 
 /// Config parser for v2 moli.yml format
 pub struct ConfigParser;
@@ -16,12 +20,53 @@ impl ConfigParser {
     }
 
     /// Parse moli.yml from string content
+    ///
+    /// Custom languages declared in a `- languages:` entry are returned in the config;
+    /// call `register_languages` to make them available to the generators.
     pub fn parse_string(content: &str) -> Result<MoliConfig> {
-        let projects: Vec<crate::project_management::config::models::Project> =
+        let entries: Vec<serde_yaml::Value> = serde_yaml::from_str(content)
+            .with_context(|| "Failed to parse YAML content")?;
+        let (language_entries, project_entries): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|entry| entry.get("languages").is_some());
+
+        let projects: Vec<Project> = if language_entries.is_empty() {
+            // Parse from the source so errors keep their line numbers
             serde_yaml::from_str(content)
-                .with_context(|| "Failed to parse YAML content")?;
+                .with_context(|| "Failed to parse YAML content")?
+        } else {
+            project_entries.into_iter()
+                .enumerate()
+                .map(|(i, entry)| serde_yaml::from_value(entry)
+                    .with_context(|| format!("Failed to parse project #{}", i + 1)))
+                .collect::<Result<_>>()?
+        };
+
+        let mut languages = Vec::new();
+        for entry in language_entries {
+            let section: LanguagesSection = serde_yaml::from_value(entry)
+                .with_context(|| "Failed to parse languages section")?;
+            languages.extend(section.languages);
+        }
+
+        Ok(MoliConfig { projects, languages })
+    }
+
+    /// Register custom languages in the global language registry
+    pub fn register_languages(config: &MoliConfig) {
+        for generator in Self::custom_languages(config) {
+            LanguageRegistry::register(generator);
+        }
+    }
 
-        Ok(MoliConfig { projects })
+    /// Build the generators of the custom languages defined in a config
+    /// (built-in languages are never redefined; the validator reports such entries)
+    pub fn custom_languages(config: &MoliConfig) -> Vec<Arc<dyn LanguageGenerator>> {
+        let builtin = LanguageRegistry::builtin();
+        config.languages().iter()
+            .filter(|definition| !definition.name.is_empty() && !builtin.contains(&definition.name))
+            .map(|definition| Arc::new(CustomLanguageGenerator::new(definition.clone())) as Arc<dyn LanguageGenerator>)
+            .collect()
     }
 
     /// Parse default moli.yml in current directory
@@ -127,4 +172,38 @@ mod tests {
         // Test explicit extension
         assert_eq!(files[1].filename_with_extension("rust"), "component.vue");
     }
+
+    #[test]
+    fn test_parse_languages_section() {
+        let yaml_content = r#"
+- languages:
+    - name: protobuf
+      extension: proto
+      aggregator: index.proto
+      line: 'import "{file}";'
+      comment: //
+      ignore: [buf.lock]
+- name: schema
+  root: true
+  lang: protobuf
+  tree:
+    - name: api
+      file:
+        - name: user
+"#;
+
+        let config = ConfigParser::parse_string(yaml_content).unwrap();
+        assert_eq!(config.projects().len(), 1);
+        assert_eq!(config.projects()[0].name(), "schema");
+        assert_eq!(config.languages().len(), 1);
+        assert_eq!(config.languages()[0].comment(), "//");
+
+        let mut registry = LanguageRegistry::builtin();
+        for generator in ConfigParser::custom_languages(&config) {
+            registry.add(generator);
+        }
+        assert_eq!(registry.extension("protobuf").as_deref(), Some("proto"));
+        assert!(registry.managed_files().contains(&"index.proto".to_string()));
+        assert!(registry.excluded_files().contains(&"buf.lock".to_string()));
+    }
 }
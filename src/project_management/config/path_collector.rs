@@ -1,4 +1,12 @@
-use crate::project_management::config::models::{MoliConfig, Module};
+use crate::project_management::config::models::{MoliConfig, Project, Module, CodeFile};
+use crate::code_generation::core::barrel_policy::BarrelPolicy;
+use crate::code_generation::language::go::package_generator::GoPackageGenerator;
+use crate::code_generation::language::c::source_generator::CSourceGenerator;
+use crate::code_generation::language::dart::library_generator::DartLibraryGenerator;
+use crate::code_generation::language::zig::module_generator::ZigModuleGenerator;
+use crate::code_generation::language::ruby::gem_generator::RubyGemGenerator;
+use crate::code_generation::language::elixir::module_generator::ElixirModuleGenerator;
+use crate::code_generation::language::python::package_generator::{PythonPackageGenerator, PythonPackageKind};
 
 /// Represents a file or directory managed by moli.yml
 #[derive(Debug, Clone)]
@@ -69,6 +77,166 @@ impl PathCollector {
             .collect()
     }
 
+    /// Collect display paths of files that moli generates alongside declared files
+    /// (e.g. Go `_test.go` variants). They have no moli.yml entry of their own.
+    pub fn collect_companion_paths(config: &MoliConfig) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        for project in config.projects() {
+            let base_path = if project.is_root() {
+                String::new()
+            } else {
+                format!("{}/", project.name())
+            };
+
+            for codefile in project.files() {
+                Self::push_companion_paths(&base_path, codefile, project.language(), &mut paths);
+            }
+
+            for module in project.tree() {
+                Self::collect_module_companion_paths(&base_path, module, project, &mut paths);
+            }
+
+            // Dart barrels (models/models.dart) maintained without a `file` entry
+            if project.language() == "dart" {
+                for barrel in DartLibraryGenerator::collect_barrel_paths(project) {
+                    let barrel = format!("{}{}", base_path, barrel);
+                    if !paths.contains(&barrel) {
+                        paths.push(barrel);
+                    }
+                }
+            }
+
+            // Zig aggregators (src/root.zig, <dir>/mod.zig) maintained without a `file` entry
+            if project.language() == "zig" {
+                for aggregator in ZigModuleGenerator::collect_aggregator_paths(project) {
+                    let aggregator = format!("{}{}", base_path, aggregator);
+                    if !paths.contains(&aggregator) {
+                        paths.push(aggregator);
+                    }
+                }
+            }
+
+            // Ruby gem entry file (lib/<gem>.rb) maintained without a `file` entry
+            if project.language() == "ruby" {
+                if let Some(entry) = RubyGemGenerator::collect_entry_path(project) {
+                    let entry = format!("{}{}", base_path, entry);
+                    if !paths.contains(&entry) {
+                        paths.push(entry);
+                    }
+                }
+            }
+
+            // Paired ExUnit tests (test/**/*_test.exs) and test_helper.exs
+            if project.language() == "elixir" {
+                for test_path in ElixirModuleGenerator::collect_test_paths(project) {
+                    let test_path = format!("{}{}", base_path, test_path);
+                    if !paths.contains(&test_path) {
+                        paths.push(test_path);
+                    }
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Collect display paths (with trailing "/") of Python directories that moli manages
+    /// without an __init__.py (namespace packages, `package: false` directories, src/ of a src layout)
+    pub fn collect_init_free_dirs(config: &MoliConfig) -> Vec<String> {
+        let mut dirs = Vec::new();
+
+        for project in config.projects().iter().filter(|p| p.language() == "python") {
+            let base_path = if project.is_root() {
+                String::new()
+            } else {
+                format!("{}/", project.name())
+            };
+
+            for module in project.tree() {
+                let is_src_dir = project.is_src_layout() && module.name() == "src";
+                Self::collect_module_init_free_dirs(
+                    &base_path,
+                    module,
+                    project,
+                    PythonPackageGenerator::default_package_kind(project),
+                    is_src_dir,
+                    &mut dirs,
+                );
+            }
+        }
+
+        dirs
+    }
+
+    fn collect_module_init_free_dirs(
+        dir_path: &str,
+        module: &Module,
+        project: &Project,
+        inherited_kind: PythonPackageKind,
+        is_src_dir: bool,
+        dirs: &mut Vec<String>,
+    ) {
+        let module_dir = format!("{}{}/", dir_path, module.name());
+        let kind = PythonPackageGenerator::package_kind(module, inherited_kind);
+
+        if is_src_dir || kind != PythonPackageKind::Regular {
+            dirs.push(module_dir.clone());
+        }
+
+        for submodule in module.subtree() {
+            Self::collect_module_init_free_dirs(
+                &module_dir,
+                submodule,
+                project,
+                PythonPackageGenerator::child_package_kind(kind, project),
+                false,
+                dirs,
+            );
+        }
+    }
+
+    fn collect_module_companion_paths(
+        dir_path: &str,
+        module: &Module,
+        project: &Project,
+        paths: &mut Vec<String>,
+    ) {
+        let language = project.language();
+        let module_dir = format!("{}{}/", dir_path, module.name());
+
+        for codefile in module.files() {
+            Self::push_companion_paths(&module_dir, codefile, language, paths);
+        }
+
+        // Barrels maintained without a `file` entry (barrels: auto)
+        if matches!(language, "typescript" | "javascript") && BarrelPolicy::is_maintained(project, module, language) {
+            let barrel = format!("{}{}", module_dir, BarrelPolicy::filename(project, module, language));
+            if !paths.contains(&barrel) {
+                paths.push(barrel);
+            }
+        }
+
+        for submodule in module.subtree() {
+            Self::collect_module_companion_paths(&module_dir, submodule, project, paths);
+        }
+    }
+
+    fn push_companion_paths(dir_path: &str, codefile: &CodeFile, language: &str, paths: &mut Vec<String>) {
+        if language == "go" && codefile.test_setting().is_some() {
+            if let Some(test_file) = GoPackageGenerator::test_filename(&codefile.filename_with_extension(language)) {
+                paths.push(format!("{}{}", dir_path, test_file));
+            }
+        }
+
+        // Paired C/C++ headers
+        if matches!(language, "c" | "cpp") {
+            if let Some(header) = CSourceGenerator::header_filename(codefile, language) {
+                paths.push(format!("{}{}", dir_path, header));
+            }
+        }
+    }
+
     fn collect_module_entries(
         base_path: &str,
         module: &Module,
@@ -127,7 +295,7 @@ mod tests {
     use crate::project_management::config::models::*;
 
     fn make_config(projects: Vec<Project>) -> MoliConfig {
-        MoliConfig { projects }
+        MoliConfig { projects, ..Default::default() }
     }
 
     #[test]
@@ -147,14 +315,17 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![
-                        CodeFile { name: "model".to_string(), r#pub: None },
-                        CodeFile { name: "repository".to_string(), r#pub: None },
+                        CodeFile { name: "model".to_string(), r#pub: None, ..Default::default() },
+                        CodeFile { name: "repository".to_string(), r#pub: None, ..Default::default() },
                     ],
+                    ..Default::default()
                 }],
                 file: vec![
-                    CodeFile { name: "main".to_string(), r#pub: None },
+                    CodeFile { name: "main".to_string(), r#pub: None, ..Default::default() },
                 ],
+                ..Default::default()
             }],
+            ..Default::default()
         }]);
 
         let files = PathCollector::collect_all_files(&config);
@@ -183,11 +354,14 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![
-                        CodeFile { name: "model".to_string(), r#pub: None },
+                        CodeFile { name: "model".to_string(), r#pub: None, ..Default::default() },
                     ],
+                    ..Default::default()
                 }],
                 file: vec![],
+                ..Default::default()
             }],
+            ..Default::default()
         }]);
 
         let entries = PathCollector::collect_all_entries(&config);
@@ -222,9 +396,12 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 }],
                 file: vec![],
+                ..Default::default()
             }],
+            ..Default::default()
         }]);
 
         let entries = PathCollector::collect_all_entries(&config);
@@ -244,7 +421,7 @@ mod tests {
             root: false,
             lang: "go".to_string(),
             file: vec![
-                CodeFile { name: "main".to_string(), r#pub: None },
+                CodeFile { name: "main".to_string(), r#pub: None, ..Default::default() },
             ],
             tree: vec![Module {
                 name: Some("pkg".to_string()),
@@ -252,9 +429,11 @@ mod tests {
                 r#pub: None,
                 tree: vec![],
                 file: vec![
-                    CodeFile { name: "handler".to_string(), r#pub: None },
+                    CodeFile { name: "handler".to_string(), r#pub: None, ..Default::default() },
                 ],
+                ..Default::default()
             }],
+            ..Default::default()
         }]);
 
         let files = PathCollector::collect_all_files(&config);
@@ -272,9 +451,10 @@ mod tests {
             root: true,
             lang: "any".to_string(),
             file: vec![
-                CodeFile { name: "README.md".to_string(), r#pub: None },
+                CodeFile { name: "README.md".to_string(), r#pub: None, ..Default::default() },
             ],
             tree: vec![],
+            ..Default::default()
         }]);
 
         let files = PathCollector::collect_all_files(&config);
@@ -301,11 +481,14 @@ mod tests {
                     r#pub: None,
                     tree: vec![],
                     file: vec![
-                        CodeFile { name: "model".to_string(), r#pub: None },
+                        CodeFile { name: "model".to_string(), r#pub: None, ..Default::default() },
                     ],
+                    ..Default::default()
                 }],
                 file: vec![],
+                ..Default::default()
             }],
+            ..Default::default()
         }]);
 
         let files = PathCollector::collect_all_files(&config);
@@ -315,6 +498,78 @@ mod tests {
         assert!(!model_file.is_project_level);
     }
 
+    #[test]
+    fn test_collect_go_test_companion_paths() {
+        let config = make_config(vec![Project {
+            name: "api".to_string(),
+            root: false,
+            lang: "go".to_string(),
+            file: vec![],
+            tree: vec![Module {
+                name: Some("user".to_string()),
+                file: vec![
+                    CodeFile { name: "model".to_string(), test: Some("external".to_string()), ..Default::default() },
+                    CodeFile { name: "service".to_string(), ..Default::default() },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }]);
+
+        let paths = PathCollector::collect_companion_paths(&config);
+
+        assert_eq!(paths, vec!["api/user/model_test.go".to_string()]);
+    }
+
+    #[test]
+    fn test_collect_c_header_companion_paths() {
+        let config = make_config(vec![Project {
+            name: "engine".to_string(),
+            root: true,
+            lang: "cpp".to_string(),
+            tree: vec![Module {
+                name: Some("src".to_string()),
+                file: vec![
+                    CodeFile { name: "main".to_string(), ..Default::default() },
+                    CodeFile { name: "mesh".to_string(), ..Default::default() },
+                    CodeFile { name: "config.h".to_string(), ..Default::default() },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }]);
+
+        let paths = PathCollector::collect_companion_paths(&config);
+
+        assert_eq!(paths, vec!["src/mesh.hpp".to_string()]);
+    }
+
+    #[test]
+    fn test_collect_init_free_dirs() {
+        let config = make_config(vec![Project {
+            name: "app".to_string(),
+            root: true,
+            lang: "python".to_string(),
+            tree: vec![
+                Module {
+                    name: Some("app".to_string()),
+                    ..Default::default()
+                },
+                Module {
+                    name: Some("tests".to_string()),
+                    package: Some(PackageSetting::Flag(false)),
+                    tree: vec![Module { name: Some("unit".to_string()), ..Default::default() }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }]);
+
+        let dirs = PathCollector::collect_init_free_dirs(&config);
+
+        assert_eq!(dirs, vec!["tests/".to_string(), "tests/unit/".to_string()]);
+    }
+
     #[test]
     fn test_file_with_explicit_extension() {
         let config = make_config(vec![Project {
@@ -328,9 +583,11 @@ mod tests {
                 r#pub: None,
                 tree: vec![],
                 file: vec![
-                    CodeFile { name: "App.tsx".to_string(), r#pub: None },
+                    CodeFile { name: "App.tsx".to_string(), r#pub: None, ..Default::default() },
                 ],
+                ..Default::default()
             }],
+            ..Default::default()
         }]);
 
         let files = PathCollector::collect_all_files(&config);
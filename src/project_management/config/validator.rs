@@ -1,5 +1,7 @@
 use anyhow::{bail, Result};
 use crate::project_management::config::models::{MoliConfig, Project, Module};
+use crate::code_generation::core::language_registry::LanguageRegistry;
+use crate::code_generation::language::plugin::generator::PluginGenerator;
 
 /// Configuration validator for v2 moli.yml
 pub struct ConfigValidator;
@@ -43,6 +45,16 @@ impl ConfigValidator {
             errors.extend(name_errors);
         }
 
+        // Check workspace settings and project dependencies
+        if let Err(workspace_errors) = Self::validate_workspace(config) {
+            errors.extend(workspace_errors);
+        }
+
+        // Check custom language definitions
+        if let Err(language_errors) = Self::validate_languages(config) {
+            errors.extend(language_errors);
+        }
+
         if !errors.is_empty() {
             let error_messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
             bail!("Configuration validation failed:\n{}", error_messages.join("\n"));
@@ -69,9 +81,21 @@ impl ConfigValidator {
                 message: "Project language cannot be empty".to_string(),
                 path: format!("{}.lang", path),
             });
+        } else if PluginGenerator::is_plugin_language(project.language())
+            && !Self::is_supported_language(project.language()) {
+            errors.push(ValidationError {
+                message: format!(
+                    "Generator plugin not found on PATH: {}",
+                    PluginGenerator::executable_name(project.language())
+                ),
+                path: format!("{}.lang", path),
+            });
         } else if !Self::is_supported_language(project.language()) {
             errors.push(ValidationError {
-                message: format!("Unsupported language: {}", project.language()),
+                message: format!(
+                    "Unsupported language: {} (expected one of {})",
+                    project.language(), LanguageRegistry::global().names().join(", ")
+                ),
                 path: format!("{}.lang", path),
             });
         }
@@ -83,6 +107,27 @@ impl ConfigValidator {
             }
         }
 
+        if let Some(barrels) = project.barrels_setting() {
+            if !Self::is_supported_barrel_policy(barrels) {
+                errors.push(ValidationError {
+                    message: format!("Unsupported barrels policy: {} (expected auto, explicit or off)", barrels),
+                    path: format!("{}.barrels", path),
+                });
+            }
+        }
+
+        // Options meant for other languages
+        errors.extend(Self::validate_options(project.language_options(), project.language(), path));
+        for (i, file) in project.files().iter().enumerate() {
+            errors.extend(Self::validate_options(file.language_options(), project.language(), &format!("{}.file[{}]", path, i)));
+        }
+
+        // Language-specific settings such as build tools and package names
+        let generator = LanguageRegistry::global().get(project.language());
+        if let Some(generator) = generator {
+            errors.extend(generator.validate_project(project, path));
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
@@ -142,6 +187,27 @@ impl ConfigValidator {
             }
         }
 
+        if let Some(barrels) = module.barrels_setting() {
+            if !Self::is_supported_barrel_policy(barrels) {
+                errors.push(ValidationError {
+                    message: format!("Unsupported barrels policy: {} (expected auto, explicit or off)", barrels),
+                    path: format!("{}.barrels", path),
+                });
+            }
+        }
+
+        errors.extend(Self::validate_options(module.language_options(), language, path));
+        for (i, file) in module.files().iter().enumerate() {
+            errors.extend(Self::validate_options(file.language_options(), language, &format!("{}.file[{}]", path, i)));
+        }
+
+        if module.package_flag() == Some(false) && module.namespace_setting() == Some(true) {
+            errors.push(ValidationError {
+                message: "Module cannot be both 'package: false' and 'namespace: true'".to_string(),
+                path: format!("{}.namespace", path),
+            });
+        }
+
         // Validate sub-modules (subtree)
         for (i, submodule) in module.subtree().iter().enumerate() {
             if let Err(submodule_errors) = Self::validate_module(submodule, &format!("{}.tree[{}]", path, i), language) {
@@ -194,9 +260,129 @@ impl ConfigValidator {
         }
     }
 
-    /// Check if language is supported
+    /// Validate custom language definitions (unique, not redefining built-ins, aggregator with a line template)
+    fn validate_languages(config: &MoliConfig) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let mut seen_names = std::collections::HashSet::new();
+        let builtin = LanguageRegistry::builtin();
+
+        for (i, definition) in config.languages().iter().enumerate() {
+            let path = format!("languages[{}]", i);
+
+            if definition.name.is_empty() {
+                errors.push(ValidationError {
+                    message: "Language name cannot be empty".to_string(),
+                    path: format!("{}.name", path),
+                });
+            } else if builtin.contains(&definition.name) {
+                errors.push(ValidationError {
+                    message: format!("Built-in language cannot be redefined: {}", definition.name),
+                    path: format!("{}.name", path),
+                });
+            } else if !seen_names.insert(definition.name.as_str()) {
+                errors.push(ValidationError {
+                    message: format!("Duplicate language name: {}", definition.name),
+                    path: format!("{}.name", path),
+                });
+            }
+
+            if definition.aggregator.is_some() && definition.line.is_none() && definition.file_line.is_none() {
+                errors.push(ValidationError {
+                    message: "An aggregator needs a line template (line or file_line)".to_string(),
+                    path: format!("{}.line", path),
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate JavaScript/TypeScript workspace manager, `depends_on` references and language-specific
+    /// constraints across projects
+    fn validate_workspace(config: &MoliConfig) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let mut workspace_manager: Option<&str> = None;
+        let mut languages = std::collections::HashSet::new();
+
+        for (i, project) in config.projects().iter().enumerate() {
+            if let Some(workspace) = project.workspace_setting() {
+                if !matches!(workspace, "npm" | "yarn" | "pnpm") {
+                    errors.push(ValidationError {
+                        message: format!("Unsupported workspace manager: {} (expected npm, yarn or pnpm)", workspace),
+                        path: format!("projects[{}].workspace", i),
+                    });
+                } else if let Some(manager) = workspace_manager {
+                    if manager != workspace {
+                        errors.push(ValidationError {
+                            message: format!("Conflicting workspace manager: {} (already set to {})", workspace, manager),
+                            path: format!("projects[{}].workspace", i),
+                        });
+                    }
+                } else {
+                    workspace_manager = Some(workspace);
+                }
+            }
+
+            if languages.insert(project.language()) {
+                if let Some(generator) = LanguageRegistry::global().get(project.language()) {
+                    errors.extend(generator.validate_workspace(config));
+                }
+            }
+
+            for (j, dependency) in project.dependencies().iter().enumerate() {
+                if dependency == project.name() {
+                    errors.push(ValidationError {
+                        message: format!("Project cannot depend on itself: {}", dependency),
+                        path: format!("projects[{}].depends_on[{}]", i, j),
+                    });
+                } else if !config.projects().iter().any(|p| p.name() == dependency.as_str()) {
+                    errors.push(ValidationError {
+                        message: format!("Unknown project in depends_on: {}", dependency),
+                        path: format!("projects[{}].depends_on[{}]", i, j),
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Reject language-specific options the language does not read (`path` locates the entry setting them)
+    fn validate_options(options: Vec<&str>, language: &str, path: &str) -> Vec<ValidationError> {
+        // Plugins receive the whole project, so any option may be meaningful to them
+        if PluginGenerator::is_plugin_language(language) {
+            return Vec::new();
+        }
+        let Some(generator) = LanguageRegistry::global().get(language) else {
+            return Vec::new();
+        };
+
+        let supported = generator.options();
+        options.into_iter()
+            .filter(|option| !supported.contains(option))
+            .map(|option| ValidationError {
+                message: format!("Option {} is not supported for {} projects", option, language),
+                path: format!("{}.{}", path, option),
+            })
+            .collect()
+    }
+
+    /// Check if barrel policy is supported
+    fn is_supported_barrel_policy(policy: &str) -> bool {
+        matches!(policy, "auto" | "explicit" | "off")
+    }
+
+    /// Check if language is supported (registered or served by a plugin)
     fn is_supported_language(lang: &str) -> bool {
-        matches!(lang, "rust" | "go" | "python" | "javascript" | "typescript" | "any" | "bash" | "lua")
+        LanguageRegistry::global().contains(lang)
     }
 }
 
@@ -214,7 +400,9 @@ mod tests {
                 lang: "rust".to_string(),
                 tree: vec![],
                 file: vec![],
+                ..Default::default()
             }],
+            ..Default::default()
         };
 
         assert!(ConfigValidator::validate(&config).is_ok());
@@ -230,6 +418,7 @@ mod tests {
                     lang: "rust".to_string(),
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
                 Project {
                     name: "frontend".to_string(),
@@ -237,8 +426,10 @@ mod tests {
                     lang: "javascript".to_string(),
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         assert!(ConfigValidator::validate(&config).is_ok());
@@ -254,6 +445,7 @@ mod tests {
                     lang: "rust".to_string(),
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
                 Project {
                     name: "app2".to_string(),
@@ -261,8 +453,10 @@ mod tests {
                     lang: "go".to_string(),
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         assert!(ConfigValidator::validate(&config).is_err());
@@ -278,6 +472,7 @@ mod tests {
                     lang: "rust".to_string(),
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
                 Project {
                     name: "app".to_string(),
@@ -285,25 +480,309 @@ mod tests {
                     lang: "go".to_string(),
                     tree: vec![],
                     file: vec![],
+                    ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         assert!(ConfigValidator::validate(&config).is_err());
     }
 
     #[test]
-    fn test_unsupported_language_invalid() {
+    fn test_go_package_mismatch_invalid() {
+        let config = MoliConfig {
+            projects: vec![Project {
+                name: "app".to_string(),
+                root: true,
+                lang: "go".to_string(),
+                tree: vec![Module {
+                    name: Some("user".to_string()),
+                    file: vec![
+                        CodeFile { name: "model".to_string(), ..Default::default() },
+                        CodeFile { name: "service".to_string(), package: Some("users".to_string()), ..Default::default() },
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&config).is_err());
+    }
+
+    #[test]
+    fn test_go_module_package_override_valid() {
+        let config = MoliConfig {
+            projects: vec![Project {
+                name: "app".to_string(),
+                root: true,
+                lang: "go".to_string(),
+                package: Some(PackageSetting::Name("app".to_string())),
+                tree: vec![Module {
+                    name: Some("user-service".to_string()),
+                    package: Some(PackageSetting::Name("users".to_string())),
+                    file: vec![
+                        CodeFile { name: "model".to_string(), test: Some("external".to_string()), ..Default::default() },
+                        CodeFile { name: "service".to_string(), package: Some("users".to_string()), ..Default::default() },
+                    ],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+
+    #[test]
+    fn test_python_unknown_backend_invalid() {
+        let config = MoliConfig {
+            projects: vec![Project {
+                name: "app".to_string(),
+                root: true,
+                lang: "python".to_string(),
+                backend: Some("maturin".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&config).is_err());
+    }
+
+    #[test]
+    fn test_options_for_other_languages_invalid() {
+        let config = MoliConfig {
+            projects: vec![Project {
+                name: "app".to_string(),
+                root: true,
+                lang: "rust".to_string(),
+                layout: Some("src".to_string()),
+                tree: vec![Module {
+                    name: Some("src".to_string()),
+                    barrels: Some("auto".to_string()),
+                    file: vec![CodeFile { name: "main".to_string(), test: Some("internal".to_string()), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let error = ConfigValidator::validate(&config).unwrap_err().to_string();
+        assert!(error.contains("projects[0].layout: Option layout is not supported for rust projects"));
+        assert!(error.contains("projects[0].tree[0].barrels"));
+        assert!(error.contains("projects[0].tree[0].file[0].test"));
+
+        // The same options are accepted by the languages reading them
         let config = MoliConfig {
             projects: vec![Project {
                 name: "app".to_string(),
                 root: true,
-                lang: "cobol".to_string(),
+                lang: "go".to_string(),
+                tree: vec![Module {
+                    name: Some("api".to_string()),
+                    file: vec![CodeFile { name: "user".to_string(), test: Some("internal".to_string()), ..Default::default() }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(ConfigValidator::validate(&config).is_ok());
+    }
+
+    #[test]
+    fn test_unsupported_language_invalid() {
+        let make_config = |lang: &str| MoliConfig {
+            projects: vec![Project {
+                name: "app".to_string(),
+                root: true,
+                lang: lang.to_string(),
                 tree: vec![],
                 file: vec![],
+                ..Default::default()
             }],
+            ..Default::default()
         };
 
-        assert!(ConfigValidator::validate(&config).is_err());
+        assert!(ConfigValidator::validate(&make_config("cobol")).is_err());
+        // Only registered generators are accepted
+        assert!(ConfigValidator::validate(&make_config("bash")).is_err());
+    }
+
+    #[test]
+    fn test_workspace_dependencies() {
+        let make_config = |depends_on: &str, workspace: &str| MoliConfig {
+            projects: vec![
+                Project {
+                    name: "core".to_string(),
+                    lang: "typescript".to_string(),
+                    workspace: Some("pnpm".to_string()),
+                    ..Default::default()
+                },
+                Project {
+                    name: "web".to_string(),
+                    lang: "typescript".to_string(),
+                    workspace: Some(workspace.to_string()),
+                    depends_on: vec![depends_on.to_string()],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&make_config("core", "pnpm")).is_ok());
+        assert!(ConfigValidator::validate(&make_config("core", "yarn")).is_err());
+        assert!(ConfigValidator::validate(&make_config("web", "pnpm")).is_err());
+        assert!(ConfigValidator::validate(&make_config("missing", "pnpm")).is_err());
+    }
+
+    #[test]
+    fn test_java_package_directory_names() {
+        let make_config = |package_dir: &str| MoliConfig {
+            projects: vec![Project {
+                name: "app".to_string(),
+                root: true,
+                lang: "java".to_string(),
+                tree: vec![Module {
+                    name: Some("src".to_string()),
+                    tree: vec![Module {
+                        name: Some("main".to_string()),
+                        tree: vec![Module {
+                            name: Some("java".to_string()),
+                            tree: vec![Module {
+                                name: Some(package_dir.to_string()),
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&make_config("acme")).is_ok());
+        assert!(ConfigValidator::validate(&make_config("user-service")).is_err());
+    }
+
+    #[test]
+    fn test_php_namespace_directory_names() {
+        let make_config = |root_namespace: &str, dir: &str| MoliConfig {
+            projects: vec![Project {
+                name: "app".to_string(),
+                root: true,
+                lang: "php".to_string(),
+                package: Some(PackageSetting::Name(root_namespace.to_string())),
+                tree: vec![Module {
+                    name: Some("src".to_string()),
+                    tree: vec![Module {
+                        name: Some(dir.to_string()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&make_config("Acme\\Shop", "Domain")).is_ok());
+        assert!(ConfigValidator::validate(&make_config("Acme\\Shop", "user-service")).is_err());
+        assert!(ConfigValidator::validate(&make_config("Acme\\2Shop", "Domain")).is_err());
+    }
+
+    #[test]
+    fn test_ruby_zeitwerk_names() {
+        let make_config = |filename: &str| MoliConfig {
+            projects: vec![Project {
+                name: "my_gem".to_string(),
+                root: true,
+                lang: "ruby".to_string(),
+                tree: vec![Module {
+                    name: Some("lib".to_string()),
+                    tree: vec![Module {
+                        name: Some("my_gem".to_string()),
+                        file: vec![CodeFile { name: filename.to_string(), ..Default::default() }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&make_config("user_service")).is_ok());
+        assert!(ConfigValidator::validate(&make_config("UserService")).is_err());
+        assert!(ConfigValidator::validate(&make_config("user-service")).is_err());
+    }
+
+    #[test]
+    fn test_elixir_umbrella_app_names() {
+        let make_config = |name: &str| MoliConfig {
+            projects: vec![
+                Project { name: name.to_string(), lang: "elixir".to_string(), ..Default::default() },
+                Project { name: "frontend".to_string(), lang: "typescript".to_string(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&make_config("apps/accounts")).is_ok());
+        assert!(ConfigValidator::validate(&make_config("accounts")).is_err());
+    }
+
+    #[test]
+    fn test_haskell_module_names() {
+        let make_config = |dir: &str| MoliConfig {
+            projects: vec![Project {
+                name: "parser".to_string(),
+                root: true,
+                lang: "haskell".to_string(),
+                tree: vec![Module {
+                    name: Some("src".to_string()),
+                    tree: vec![Module {
+                        name: Some(dir.to_string()),
+                        file: vec![CodeFile { name: "Lexer".to_string(), ..Default::default() }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(ConfigValidator::validate(&make_config("Data")).is_ok());
+        assert!(ConfigValidator::validate(&make_config("data")).is_err());
+    }
+
+    #[test]
+    fn test_custom_languages() {
+        let make_config = |name: &str, line: Option<&str>| MoliConfig {
+            projects: vec![Project {
+                name: "infra".to_string(),
+                root: true,
+                lang: "go".to_string(),
+                ..Default::default()
+            }],
+            languages: vec![LanguageDefinition {
+                name: name.to_string(),
+                aggregator: Some("main.tf".to_string()),
+                line: line.map(str::to_string),
+                ..Default::default()
+            }],
+        };
+
+        assert!(ConfigValidator::validate(&make_config("terraform", Some("module \"{name}\" {}"))).is_ok());
+        assert!(ConfigValidator::validate(&make_config("rust", Some("mod {name};"))).is_err());
+        assert!(ConfigValidator::validate(&make_config("terraform", None)).is_err());
     }
 }
@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::ops::Range;
 use std::path::Path;
 use anyhow::{Result, bail};
 use crate::project_management::config::path_collector::ManagedFile;
@@ -131,21 +132,14 @@ impl YamlModifier {
         project_index: usize,
         module_name: &str,
     ) -> Result<Option<usize>> {
-        let mut current_project_index: i32 = -1;
+        let Some(project) = Self::project_range(lines, project_index) else {
+            return Ok(None);
+        };
         let mut in_tree_section = false;
 
-        for (i, line) in lines.iter().enumerate() {
+        for (i, line) in lines.iter().enumerate().take(project.end).skip(project.start) {
             let trimmed = line.trim();
 
-            if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                current_project_index += 1;
-                in_tree_section = false;
-            }
-
-            if current_project_index as usize != project_index {
-                continue;
-            }
-
             if trimmed == "tree:" && Self::line_indent(line) == 2 {
                 in_tree_section = true;
                 continue;
@@ -167,23 +161,15 @@ impl YamlModifier {
         lines: &[&str],
         target: &ManagedFile,
     ) -> Result<Option<(usize, usize)>> {
-        let mut current_project_index: i32 = -1;
+        let Some(project) = Self::project_range(lines, target.project_index) else {
+            return Ok(None);
+        };
         let mut in_project_file_section = false;
-        let mut project_indent = 0;
+        let project_indent = 2;
 
-        for (i, line) in lines.iter().enumerate() {
+        for (i, line) in lines.iter().enumerate().take(project.end).skip(project.start) {
             let trimmed = line.trim();
 
-            if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                current_project_index += 1;
-                in_project_file_section = false;
-                project_indent = 2;
-            }
-
-            if current_project_index as usize != target.project_index {
-                continue;
-            }
-
             if trimmed == "file:" && Self::line_indent(line) == project_indent {
                 in_project_file_section = true;
                 continue;
@@ -518,53 +504,41 @@ impl YamlModifier {
         module_name: &str,
     ) -> Result<String> {
         let mut result_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
-        let mut current_project: i32 = -1;
+        let project = Self::project_range(lines, project_index).unwrap_or(lines.len()..lines.len());
         let mut tree_line = None;
         let mut insert_pos = None;
 
-        for (i, line) in lines.iter().enumerate() {
+        for (i, line) in lines.iter().enumerate().take(project.end).skip(project.start) {
             let trimmed = line.trim();
 
-            if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                current_project += 1;
-                if current_project as usize > project_index {
-                    // We've passed our project, insert before this project
-                    insert_pos = Some(i);
-                    break;
-                }
+            if trimmed == "tree:" && Self::line_indent(line) == 2 {
+                tree_line = Some(i);
             }
-
-            if current_project as usize == project_index {
-                if trimmed == "tree:" && Self::line_indent(line) == 2 {
-                    tree_line = Some(i);
-                }
-                // Find the last entry in this project's tree section
-                if tree_line.is_some() && Self::line_indent(line) == 4 && trimmed.starts_with("- name:") {
-                    // Track last tree entry
-                    // Find end of this module block
-                    let mut end = i;
-                    for j in (i + 1)..lines.len() {
-                        let next_trimmed = lines[j].trim();
-                        let next_indent = Self::line_indent(lines[j]);
-                        if next_trimmed.is_empty() {
-                            continue;
-                        }
-                        if next_indent > 4 {
-                            end = j;
-                        } else {
-                            break;
-                        }
+            // Find the last entry in this project's tree section
+            if tree_line.is_some() && Self::line_indent(line) == 4 && trimmed.starts_with("- name:") {
+                // Track last tree entry
+                // Find end of this module block
+                let mut end = i;
+                for j in (i + 1)..project.end {
+                    let next_trimmed = lines[j].trim();
+                    let next_indent = Self::line_indent(lines[j]);
+                    if next_trimmed.is_empty() {
+                        continue;
+                    }
+                    if next_indent > 4 {
+                        end = j;
+                    } else {
+                        break;
                     }
-                    insert_pos = Some(end + 1);
                 }
+                insert_pos = Some(end + 1);
             }
         }
 
         if tree_line.is_none() {
             // No tree: section exists, create one at the end of the project
-            let project_end = Self::find_project_end(lines, project_index);
             let new_lines = format!("  tree:\n    - name: {}", module_name);
-            result_lines.insert(project_end, new_lines);
+            result_lines.insert(project.end, new_lines);
         } else if let Some(pos) = insert_pos {
             let new_line = format!("    - name: {}", module_name);
             result_lines.insert(pos, new_line);
@@ -582,20 +556,8 @@ impl YamlModifier {
     ) -> Result<String> {
         let mut result_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
 
-        // Find the parent module
-        let mut search_start = 0;
-        // First, navigate to the correct project
-        let mut current_project: i32 = -1;
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-            if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                current_project += 1;
-                if current_project as usize == project_index {
-                    search_start = i;
-                    break;
-                }
-            }
-        }
+        // Find the parent module, starting from the correct project
+        let mut search_start = Self::project_range(lines, project_index).map_or(0, |project| project.start);
 
         for seg in parent_segments {
             match Self::find_module_start(lines, search_start, seg)? {
@@ -695,19 +657,8 @@ impl YamlModifier {
         let lines: Vec<&str> = yaml_content.lines().collect();
         let mut result_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
 
-        // Find the target module
-        let mut search_start = 0;
-        let mut current_project: i32 = -1;
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-            if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                current_project += 1;
-                if current_project as usize == project_index {
-                    search_start = i;
-                    break;
-                }
-            }
-        }
+        // Find the target module, starting from the correct project
+        let mut search_start = Self::project_range(&lines, project_index).map_or(0, |project| project.start);
 
         for seg in module_segments {
             match Self::find_module_start(&lines, search_start, seg)? {
@@ -840,22 +791,13 @@ impl YamlModifier {
         let lines: Vec<&str> = yaml_content.lines().collect();
         let mut result_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
 
-        let mut current_project: i32 = -1;
+        let project = Self::project_range(&lines, project_index).unwrap_or(lines.len()..lines.len());
         let mut file_section_found = false;
         let mut insert_pos = None;
 
-        for (i, line) in lines.iter().enumerate() {
+        for (i, line) in lines.iter().enumerate().take(project.end).skip(project.start) {
             let trimmed = line.trim();
 
-            if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                current_project += 1;
-                file_section_found = false;
-            }
-
-            if current_project as usize != project_index {
-                continue;
-            }
-
             if trimmed == "file:" && Self::line_indent(line) == 2 {
                 file_section_found = true;
                 insert_pos = Some(i + 1);
@@ -869,7 +811,7 @@ impl YamlModifier {
                     return Ok(yaml_content.to_string());
                 }
                 let mut end = i;
-                for j in (i + 1)..lines.len() {
+                for j in (i + 1)..project.end {
                     let next_trimmed = lines[j].trim();
                     let next_indent = Self::line_indent(lines[j]);
                     if next_trimmed.is_empty() {
@@ -887,30 +829,15 @@ impl YamlModifier {
 
         if !file_section_found {
             // Add file: section before tree: or at end of project
-            let mut tree_pos = None;
-            current_project = -1;
-
-            for (i, line) in lines.iter().enumerate() {
-                let trimmed = line.trim();
-                if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                    current_project += 1;
-                }
-                if current_project as usize != project_index {
-                    continue;
-                }
-                if trimmed == "tree:" && Self::line_indent(line) == 2 {
-                    tree_pos = Some(i);
-                    break;
-                }
-            }
+            let tree_pos = (project.start..project.end)
+                .find(|&i| lines[i].trim() == "tree:" && Self::line_indent(lines[i]) == 2);
 
             if let Some(tp) = tree_pos {
                 result_lines.insert(tp, format!("    - name: {}", file_name));
                 result_lines.insert(tp, "  file:".to_string());
             } else {
-                let project_end = Self::find_project_end(&lines, project_index);
-                result_lines.insert(project_end, format!("    - name: {}", file_name));
-                result_lines.insert(project_end, "  file:".to_string());
+                result_lines.insert(project.end, format!("    - name: {}", file_name));
+                result_lines.insert(project.end, "  file:".to_string());
             }
         } else if let Some(pos) = insert_pos {
             let new_line = format!("    - name: {}", file_name);
@@ -920,27 +847,28 @@ impl YamlModifier {
         Ok(result_lines.join("\n"))
     }
 
-    /// Find the end line index of a project (exclusive - line after last content)
-    fn find_project_end(lines: &[&str], project_index: usize) -> usize {
-        let mut current_project: i32 = -1;
-        let mut project_start = 0;
+    /// Line range of a project: from its `- name:` line up to the next top-level item
+    /// (another project or a `- languages:` entry) or the end of the file
+    fn project_range(lines: &[&str], project_index: usize) -> Option<Range<usize>> {
+        let mut projects = 0;
+        let mut start = None;
 
         for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-            if trimmed.starts_with("- name:") && Self::line_indent(line) == 0 {
-                current_project += 1;
-                if current_project as usize == project_index {
-                    project_start = i;
-                }
-                if current_project as usize > project_index {
-                    return i;
+            if Self::line_indent(line) != 0 || !line.starts_with("- ") {
+                continue;
+            }
+            if let Some(start) = start {
+                return Some(start..i);
+            }
+            if line.starts_with("- name:") {
+                if projects == project_index {
+                    start = Some(i);
                 }
+                projects += 1;
             }
         }
 
-        // Last project - return end of file
-        let _ = project_start;
-        lines.len()
+        start.map(|start| start..lines.len())
     }
 
     /// Add a new project to the YAML content (preserves existing formatting)
@@ -1081,6 +1009,8 @@ impl AddChild {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::project_management::config::models::{CodeFile, Module};
+    use crate::project_management::config::parser::ConfigParser;
 
     fn make_file_target(
         file_name: &str,
@@ -1562,4 +1492,27 @@ mod tests {
         assert!(result.contains("tree:"));
         assert!(result.contains("- name: src"));
     }
+
+    #[test]
+    fn test_add_entries_before_trailing_languages_entry() {
+        let yaml = "\
+- name: schema
+  root: true
+  lang: protobuf
+
+- languages:
+    - name: protobuf
+      extension: proto
+";
+
+        let result = YamlModifier::add_entry(yaml, 0, &["api".to_string()], true, "protobuf", &[]).unwrap();
+        let result = YamlModifier::add_entry(&result, 0, &["buf.yaml".to_string()], false, "protobuf", &[]).unwrap();
+
+        let config = ConfigParser::parse_string(&result).unwrap();
+        let project = &config.projects()[0];
+        assert_eq!(project.tree().iter().map(Module::name).collect::<Vec<_>>(), vec!["api"]);
+        assert_eq!(project.files().iter().map(CodeFile::name).collect::<Vec<_>>(), vec!["buf.yaml"]);
+        assert_eq!(config.languages().len(), 1);
+        assert!(result.ends_with("- languages:\n    - name: protobuf\n      extension: proto\n"));
+    }
 }
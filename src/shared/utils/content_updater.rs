@@ -1,6 +1,8 @@
-use std::fs;
+use crate::shared::utils::file_system::Fs;
 use std::path::Path;
-use anyhow::{Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
 use regex::Regex;
 
 /// Utility for updating managed content sections while preserving user code
@@ -9,6 +11,7 @@ pub struct ContentUpdater;
 impl ContentUpdater {
     /// Update content between header and footer markers, preserving everything else
     pub fn update_managed_section<P: AsRef<Path>>(
+        fs: &dyn Fs,
         file_path: P,
         new_managed_content: &str,
         header: &str,
@@ -17,8 +20,8 @@ impl ContentUpdater {
         let file_path = file_path.as_ref();
         
         // Read existing content if file exists
-        let existing_content = if file_path.exists() {
-            fs::read_to_string(file_path)
+        let existing_content = if fs.exists(file_path) {
+            fs.read_to_string(file_path)
                 .with_context(|| format!("Failed to read file: {}", file_path.display()))?
         } else {
             String::new()
@@ -37,7 +40,7 @@ impl ContentUpdater {
         // Replace or append the managed section
         let updated_content = if regex.is_match(&existing_content) {
             // Replace existing managed section
-            regex.replace(&existing_content, new_section.as_str()).to_string()
+            regex.replace(&existing_content, regex::NoExpand(&new_section)).to_string()
         } else {
             // Prepend new managed section (file doesn't have managed section yet)
             if existing_content.is_empty() {
@@ -48,7 +51,7 @@ impl ContentUpdater {
         };
 
         // Write updated content
-        fs::write(file_path, updated_content)
+        fs.write(file_path, updated_content)
             .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
 
         Ok(())
@@ -56,6 +59,7 @@ impl ContentUpdater {
 
     /// Update Rust module files (mod.rs, main.rs, lib.rs) with module declarations
     pub fn update_rust_module_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
         file_path: P,
         module_declarations: &[String],
         additional_content: Option<&str>,
@@ -67,6 +71,7 @@ impl ContentUpdater {
         };
 
         Self::update_managed_section(
+            fs,
             &file_path,
             &managed_content,
             "// start auto exported by moli.\n",
@@ -76,7 +81,7 @@ impl ContentUpdater {
         // Add additional content (like main function) only if this is a new file that didn't exist before
         if let Some(extra) = additional_content {
             let file_path = file_path.as_ref();
-            let current_content = fs::read_to_string(file_path)
+            let current_content = fs.read_to_string(file_path)
                 .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
 
             // Only add additional content if:
@@ -90,7 +95,7 @@ impl ContentUpdater {
             
             if is_essentially_empty && !current_content.contains(extra.trim()) {
                 let updated_content = format!("{}{}\n", current_content, extra);
-                fs::write(file_path, updated_content)
+                fs.write(file_path, updated_content)
                     .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
             }
         }
@@ -100,6 +105,7 @@ impl ContentUpdater {
 
     /// Update Python __init__.py files with import statements
     pub fn update_python_init_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
         file_path: P,
         import_statements: &[String],
     ) -> Result<()> {
@@ -110,6 +116,7 @@ impl ContentUpdater {
         };
 
         Self::update_managed_section(
+            fs,
             &file_path,
             &managed_content,
             "# start auto exported by moli.\n",
@@ -119,6 +126,7 @@ impl ContentUpdater {
 
     /// Update JavaScript/TypeScript index files with export statements
     pub fn update_js_index_file<P: AsRef<Path>>(
+        fs: &dyn Fs,
         file_path: P,
         export_statements: &[String],
     ) -> Result<()> {
@@ -129,6 +137,7 @@ impl ContentUpdater {
         };
 
         Self::update_managed_section(
+            fs,
             &file_path,
             &managed_content,
             "// start auto exported by moli.\n",
@@ -136,9 +145,181 @@ impl ContentUpdater {
         )
     }
 
+    /// Update CMakeLists.txt files with target_sources and add_subdirectory statements
+    pub fn update_cmake_lists<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        file_path: P,
+        cmake_statements: &[String],
+    ) -> Result<()> {
+        let managed_content = if cmake_statements.is_empty() {
+            "".to_string()
+        } else {
+            cmake_statements.join("\n")
+        };
+
+        Self::update_managed_section(
+            fs,
+            &file_path,
+            &managed_content,
+            "# start auto exported by moli.\n",
+            "# end auto exported by moli.",
+        )
+    }
+
+    /// Merge entries into the `exports` map of a package.json while preserving other fields
+    /// - "." falls back to the package's `main` entry when no entry exports it
+    /// - subpaths with conditional (object or array) targets are left as the user wrote them
+    /// - plain subpaths pointing at one of `managed_targets` are removed once no longer exported
+    pub fn update_package_json_exports<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        file_path: P,
+        entries: &[(String, String)],
+        managed_targets: &[String],
+    ) -> Result<()> {
+        Self::update_json_object(fs, file_path, |root| {
+            let mut entries = entries.to_vec();
+            if !entries.iter().any(|(subpath, _)| subpath == ".") {
+                let main = root.get("main").and_then(Value::as_str).unwrap_or("index.js");
+                entries.insert(0, (".".to_string(), format!("./{}", main.trim_start_matches("./"))));
+            }
+
+            let mut exports = match root.get("exports") {
+                Some(Value::Object(existing)) => existing.clone(),
+                Some(Value::String(target)) => Self::json_string_map(&[(".".to_string(), target.clone())]),
+                Some(_) => bail!("\"exports\" is not an object"),
+                None => Map::new(),
+            };
+
+            exports.retain(|subpath, target| match target {
+                Value::String(target) => !managed_targets.contains(target)
+                    || entries.iter().any(|(exported, _)| exported == subpath),
+                _ => true,
+            });
+
+            for (subpath, target) in entries {
+                match exports.get_mut(&subpath) {
+                    Some(existing @ Value::String(_)) => *existing = Value::String(target),
+                    Some(_) => {}
+                    None => {
+                        exports.insert(subpath, Value::String(target));
+                    }
+                }
+            }
+
+            Self::set_json_field(root, "exports", Value::Object(exports));
+            Ok(())
+        })
+    }
+
+    /// Update the `autoload.psr-4` map of a composer.json while preserving other fields
+    /// A missing map is inserted at the start of `autoload`, or a new `autoload` is added
+    pub fn update_composer_autoload<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        file_path: P,
+        entries: &[(String, String)],
+    ) -> Result<()> {
+        let psr4 = Value::Object(Self::json_string_map(entries));
+
+        Self::update_json_object(fs, file_path, |root| {
+            match root.get_mut("autoload") {
+                Some(Value::Object(autoload)) => {
+                    match autoload.get_mut("psr-4") {
+                        Some(existing) => *existing = psr4,
+                        None => {
+                            autoload.shift_insert(0, "psr-4".to_string(), psr4);
+                        }
+                    }
+                }
+                Some(_) => bail!("\"autoload\" is not an object"),
+                None => Self::set_json_field(root, "autoload", json!({ "psr-4": psr4 })),
+            }
+            Ok(())
+        })
+    }
+
+    /// Update a top-level JSON field, preserving the other fields and their order.
+    /// A missing field is inserted after the name/version/private/description/main header, otherwise first.
+    pub fn update_json_field<P: AsRef<Path>>(
+        fs: &dyn Fs,
+        file_path: P,
+        key: &str,
+        value: Value,
+    ) -> Result<()> {
+        Self::update_json_object(fs, file_path, |root| {
+            Self::set_json_field(root, key, value);
+            Ok(())
+        })
+    }
+
+    /// Edit a JSON file whose top level is an object, keeping field order and indentation
+    /// (the file is left untouched when the edit does not change its value)
+    pub fn update_json_object<P, F>(fs: &dyn Fs, file_path: P, edit: F) -> Result<()>
+    where
+        P: AsRef<Path>,
+        F: FnOnce(&mut Map<String, Value>) -> Result<()>,
+    {
+        let file_path = file_path.as_ref();
+        let existing_content = fs.read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+
+        let mut root: Value = serde_json::from_str(&existing_content)
+            .with_context(|| format!("Failed to parse JSON: {}", file_path.display()))?;
+        let original = root.clone();
+        let object = root.as_object_mut()
+            .ok_or_else(|| anyhow!("Expected a JSON object at the top level of {}", file_path.display()))?;
+        edit(object)
+            .with_context(|| format!("Failed to update JSON: {}", file_path.display()))?;
+
+        if root == original {
+            return Ok(());
+        }
+
+        let indent = Self::json_indent(&existing_content);
+        let mut buffer = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+        root.serialize(&mut serializer)?;
+        let updated_content = format!("{}\n", String::from_utf8(buffer)?);
+        fs.write(file_path, updated_content)
+            .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
+
+        Ok(())
+    }
+
+    /// Get the indentation of the first indented line, defaulting to two spaces
+    fn json_indent(content: &str) -> String {
+        content.lines()
+            .map(|line| &line[..line.len() - line.trim_start().len()])
+            .find(|indent| !indent.is_empty())
+            .unwrap_or("  ")
+            .to_string()
+    }
+
+    /// Replace a field in place, or insert it after the last header field present, otherwise first
+    fn set_json_field(object: &mut Map<String, Value>, key: &str, value: Value) {
+        const HEADER_FIELDS: [&str; 5] = ["name", "version", "private", "description", "main"];
+
+        if let Some(existing) = object.get_mut(key) {
+            *existing = value;
+            return;
+        }
+        let index = object.keys()
+            .rposition(|k| HEADER_FIELDS.contains(&k.as_str()))
+            .map_or(0, |header_index| header_index + 1);
+        object.shift_insert(index, key.to_string(), value);
+    }
+
+    /// Build a JSON object of string values, keeping the entry order
+    fn json_string_map(entries: &[(String, String)]) -> Map<String, Value> {
+        entries.iter()
+            .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+            .collect()
+    }
+
     /// Update content between header and footer markers, appending managed section at the end
     /// This is specifically designed for markdown files where table of contents should come after content
     pub fn append_managed_section<P: AsRef<Path>>(
+        fs: &dyn Fs,
         file_path: P,
         new_managed_content: &str,
         header: &str,
@@ -147,8 +328,8 @@ impl ContentUpdater {
         let file_path = file_path.as_ref();
         
         // Read existing content if file exists
-        let existing_content = if file_path.exists() {
-            fs::read_to_string(file_path)
+        let existing_content = if fs.exists(file_path) {
+            fs.read_to_string(file_path)
                 .with_context(|| format!("Failed to read file: {}", file_path.display()))?
         } else {
             String::new()
@@ -178,7 +359,7 @@ impl ContentUpdater {
         };
 
         // Write updated content
-        fs::write(file_path, updated_content)
+        fs.write(file_path, updated_content)
             .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
 
         Ok(())
@@ -187,6 +368,7 @@ impl ContentUpdater {
     /// Update workspace Cargo.toml members section while preserving other settings
     /// If package_info is provided, also adds a [package] section for mixed workspace
     pub fn update_workspace_cargo_toml<P: AsRef<Path>>(
+        fs: &dyn Fs,
         file_path: P,
         members: &[String],
         package_info: Option<(String, &crate::project_management::config::models::Project)>,
@@ -194,8 +376,8 @@ impl ContentUpdater {
         let file_path = file_path.as_ref();
 
         // Read existing content if file exists
-        let existing_content = if file_path.exists() {
-            fs::read_to_string(file_path)
+        let existing_content = if fs.exists(file_path) {
+            fs.read_to_string(file_path)
                 .with_context(|| format!("Failed to read file: {}", file_path.display()))?
         } else {
             // Create initial content with basic workspace structure
@@ -270,9 +452,124 @@ edition = "2024"
         }
 
         // Write updated content
-        fs::write(file_path, updated_content)
+        fs.write(file_path, updated_content)
             .with_context(|| format!("Failed to write file: {}", file_path.display()))?;
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_update_conditional_exports() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json_path = temp_dir.path().join("package.json");
+        std::fs::write(&package_json_path, r#"{
+  "name": "lib",
+  "main": "index.js",
+  "exports": {
+    ".": { "import": "./index.mjs", "require": "./index.cjs" },
+    "./utils": { "types": "./utils.d.ts", "default": "./utils.js" },
+    "./package.json": "./package.json",
+    "./legacy": "./src/legacy/index.js"
+  },
+  "type": "module"
+}
+"#).unwrap();
+
+        let entries = vec![
+            (".".to_string(), "./src/index.js".to_string()),
+            ("./user".to_string(), "./src/user/index.js".to_string()),
+        ];
+        let managed_targets = vec![
+            "./src/index.js".to_string(),
+            "./src/user/index.js".to_string(),
+            "./src/legacy/index.js".to_string(),
+        ];
+        ContentUpdater::update_package_json_exports(&RealFs, &package_json_path, &entries, &managed_targets).unwrap();
+
+        let content = std::fs::read_to_string(&package_json_path).unwrap();
+        let package_json: Value = serde_json::from_str(&content).unwrap();
+        let keys: Vec<&String> = package_json.as_object().unwrap().keys().collect();
+        assert_eq!(keys, vec!["name", "main", "exports", "type"]);
+        assert_eq!(package_json["exports"], json!({
+            ".": { "import": "./index.mjs", "require": "./index.cjs" },
+            "./utils": { "types": "./utils.d.ts", "default": "./utils.js" },
+            "./package.json": "./package.json",
+            "./user": "./src/user/index.js"
+        }));
+    }
+
+    #[test]
+    fn test_update_exports_maps_root_to_main() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json_path = temp_dir.path().join("package.json");
+        std::fs::write(&package_json_path, r#"{"name":"lib","main":"lib/main.js"}"#).unwrap();
+
+        let entries = vec![("./errors".to_string(), "./src/errors.js".to_string())];
+        ContentUpdater::update_package_json_exports(&RealFs, &package_json_path, &entries, &[]).unwrap();
+
+        let package_json: Value = serde_json::from_str(&std::fs::read_to_string(&package_json_path).unwrap()).unwrap();
+        assert_eq!(package_json["exports"], json!({ ".": "./lib/main.js", "./errors": "./src/errors.js" }));
+    }
+
+    #[test]
+    fn test_update_json_field_inserts_after_main() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json_path = temp_dir.path().join("package.json");
+        std::fs::write(&package_json_path, r#"{"name":"lib","main":"index.js","type":"module"}"#).unwrap();
+
+        ContentUpdater::update_json_field(&RealFs, &package_json_path, "exports", json!({ ".": "./index.js" })).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&package_json_path).unwrap(), r#"{
+  "name": "lib",
+  "main": "index.js",
+  "exports": {
+    ".": "./index.js"
+  },
+  "type": "module"
+}
+"#);
+    }
+
+    #[test]
+    fn test_update_json_field_keeps_indentation() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json_path = temp_dir.path().join("package.json");
+        std::fs::write(&package_json_path, "{\n\t\"name\": \"lib\",\n\t\"main\": \"index.js\"\n}\n").unwrap();
+
+        ContentUpdater::update_json_field(&RealFs, &package_json_path, "exports", json!({ ".": "./index.js" })).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&package_json_path).unwrap(),
+            "{\n\t\"name\": \"lib\",\n\t\"main\": \"index.js\",\n\t\"exports\": {\n\t\t\".\": \"./index.js\"\n\t}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_update_json_field_skips_unchanged_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json_path = temp_dir.path().join("package.json");
+        let content = "{\n    \"name\": \"lib\",\n    \"exports\": { \".\": \"./index.js\" }\n}";
+        std::fs::write(&package_json_path, content).unwrap();
+
+        ContentUpdater::update_json_field(&RealFs, &package_json_path, "exports", json!({ ".": "./index.js" })).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&package_json_path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_update_json_field_rejects_invalid_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json_path = temp_dir.path().join("package.json");
+        std::fs::write(&package_json_path, "{ \"name\": \"lib\", }").unwrap();
+
+        assert!(ContentUpdater::update_json_field(&RealFs, &package_json_path, "exports", json!({})).is_err());
+        assert_eq!(std::fs::read_to_string(&package_json_path).unwrap(), "{ \"name\": \"lib\", }");
+    }
+}
@@ -0,0 +1,203 @@
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use ignore::WalkBuilder;
+
+// Filesystem access used by the generators, ContentUpdater and FilesystemScanner in place of `std::fs`.
+// The backend is passed explicitly as `&dyn Fs`, so the same generation code can write to disk,
+// record a plan or build a tree in memory.
+
+/// Entry returned when walking a directory tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalkEntry {
+    pub path: PathBuf,
+    pub is_dir: bool,
+}
+
+/// Filesystem backend
+pub trait Fs: Send + Sync {
+    /// Recursively create a directory and all of its parents
+    fn make_dir_all(&self, path: &Path) -> io::Result<()>;
+
+    /// Write a file, replacing its content (the parent directory must exist)
+    fn write_bytes(&self, path: &Path, contents: &[u8]) -> io::Result<()>;
+
+    /// Read a file into a string
+    fn read_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Move a file, replacing the destination
+    fn move_file(&self, from: &Path, to: &Path) -> io::Result<()>;
+
+    /// Remove a file
+    fn delete_file(&self, path: &Path) -> io::Result<()>;
+
+    /// Remove a directory and everything below it
+    fn delete_dir_all(&self, path: &Path) -> io::Result<()>;
+
+    /// Check if a file or directory exists
+    fn entry_exists(&self, path: &Path) -> bool;
+
+    /// Check if a path is an existing directory
+    fn dir_exists(&self, path: &Path) -> bool;
+
+    /// List every entry below a directory, parents before children, skipping hidden
+    /// and git-ignored entries
+    fn walk_tree(&self, root: &Path) -> io::Result<Vec<WalkEntry>>;
+
+    /// Clone a git repository into a directory
+    fn clone_repo(&self, url: &str, path: &Path) -> io::Result<()>;
+
+    /// Resolve a path to an absolute path against the backend's root (fails if it does not exist)
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf>;
+
+    /// Check if operations reach the real disk (git clones report progress only then)
+    fn is_real(&self) -> bool {
+        false
+    }
+}
+
+/// Backend performing the operations on disk
+pub struct RealFs;
+
+impl Fs for RealFs {
+    fn make_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn write_bytes(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        std::fs::write(path, contents)
+    }
+
+    fn read_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn move_file(&self, from: &Path, to: &Path) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    fn delete_file(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn delete_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_dir_all(path)
+    }
+
+    fn entry_exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn dir_exists(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn walk_tree(&self, root: &Path) -> io::Result<Vec<WalkEntry>> {
+        let walker = WalkBuilder::new(root)
+            .hidden(true)       // skip hidden files
+            .git_ignore(true)   // respect .gitignore
+            .git_global(true)   // respect global gitignore
+            .git_exclude(true)  // respect .git/info/exclude
+            .build();
+
+        let mut entries = Vec::new();
+        for result in walker {
+            let entry = result.map_err(io::Error::other)?;
+            // Skip the root directory itself
+            if entry.depth() == 0 {
+                continue;
+            }
+            entries.push(WalkEntry {
+                is_dir: entry.file_type().is_some_and(|ft| ft.is_dir()),
+                path: entry.into_path(),
+            });
+        }
+        Ok(entries)
+    }
+
+    fn clone_repo(&self, url: &str, path: &Path) -> io::Result<()> {
+        let output = Command::new("git")
+            .arg("clone")
+            .arg(url)
+            .arg(path)
+            .output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+        }
+        Ok(())
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::canonicalize(path)
+    }
+
+    fn is_real(&self) -> bool {
+        true
+    }
+}
+
+/// Drop `.` components so "./src" and "src" name the same entry
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect()
+}
+
+// Generic forms of the trait methods so callers can pass `PathBuf`s, `&str`s and `String`s directly.
+// Backends implement the trait methods; callers use these `std::fs`-style names on `&dyn Fs`.
+impl dyn Fs + '_ {
+    /// Recursively create a directory and all of its parents
+    pub fn create_dir_all<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        Fs::make_dir_all(self, path.as_ref())
+    }
+
+    /// Write a file, replacing its content
+    pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(&self, path: P, contents: C) -> io::Result<()> {
+        Fs::write_bytes(self, path.as_ref(), contents.as_ref())
+    }
+
+    /// Read a file into a string
+    pub fn read_to_string<P: AsRef<Path>>(&self, path: P) -> io::Result<String> {
+        Fs::read_string(self, path.as_ref())
+    }
+
+    /// Move a file, replacing the destination
+    pub fn rename<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> io::Result<()> {
+        Fs::move_file(self, from.as_ref(), to.as_ref())
+    }
+
+    /// Remove a file
+    pub fn remove_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        Fs::delete_file(self, path.as_ref())
+    }
+
+    /// Remove a directory and everything below it
+    pub fn remove_dir_all<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        Fs::delete_dir_all(self, path.as_ref())
+    }
+
+    /// Check if a file or directory exists
+    pub fn exists<P: AsRef<Path>>(&self, path: P) -> bool {
+        Fs::entry_exists(self, path.as_ref())
+    }
+
+    /// Check if a path is an existing directory
+    pub fn is_dir<P: AsRef<Path>>(&self, path: P) -> bool {
+        Fs::dir_exists(self, path.as_ref())
+    }
+
+    /// Check if a path is an existing file
+    pub fn is_file<P: AsRef<Path>>(&self, path: P) -> bool {
+        Fs::entry_exists(self, path.as_ref()) && !Fs::dir_exists(self, path.as_ref())
+    }
+
+    /// List every entry below a directory, parents before children
+    pub fn walk<P: AsRef<Path>>(&self, root: P) -> io::Result<Vec<WalkEntry>> {
+        Fs::walk_tree(self, root.as_ref())
+    }
+
+    /// Clone a git repository into a directory
+    pub fn git_clone<P: AsRef<Path>>(&self, url: &str, path: P) -> io::Result<()> {
+        Fs::clone_repo(self, url, path.as_ref())
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use crate::shared::utils::file_system::{normalize, Fs, WalkEntry};
+
+#[derive(Debug, Clone)]
+enum MemoryEntry {
+    Dir,
+    File(Vec<u8>),
+}
+
+/// Backend keeping the whole tree in memory, for previews, golden tests and embedding
+///
+/// Paths are used as given (relative paths stay relative), with `.` components dropped.
+/// Relative paths are resolved against the root (defaults to "/") only by `canonicalize`.
+#[derive(Debug)]
+pub struct MemoryFs {
+    root: PathBuf,
+    entries: Mutex<BTreeMap<PathBuf, MemoryEntry>>,
+}
+
+impl Default for MemoryFs {
+    fn default() -> Self {
+        Self { root: PathBuf::from("/"), entries: Mutex::default() }
+    }
+}
+
+impl MemoryFs {
+    /// Create an empty filesystem
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the absolute directory relative paths stand for (e.g. "/work/shop" names "." shop)
+    pub fn with_root<P: Into<PathBuf>>(mut self, root: P) -> Self {
+        self.root = root.into();
+        self
+    }
+
+    /// Add a file and its parent directories (for setting up fixtures)
+    pub fn with_file<P: AsRef<Path>>(self, path: P, content: &str) -> Self {
+        let path = normalize(path.as_ref());
+        if let Some(parent) = path.parent() {
+            self.make_dir_all(parent).expect("fixture parent is not a directory");
+        }
+        self.write_bytes(&path, content.as_bytes()).expect("fixture path is a directory");
+        self
+    }
+
+    /// Get every file with its content (invalid UTF-8 is replaced)
+    pub fn files(&self) -> BTreeMap<PathBuf, String> {
+        self.entries.lock().unwrap()
+            .iter()
+            .filter_map(|(path, entry)| match entry {
+                MemoryEntry::File(content) => Some((path.clone(), String::from_utf8_lossy(content).to_string())),
+                MemoryEntry::Dir => None,
+            })
+            .collect()
+    }
+
+    /// Get every directory
+    pub fn dirs(&self) -> Vec<PathBuf> {
+        self.entries.lock().unwrap()
+            .iter()
+            .filter(|(_, entry)| matches!(entry, MemoryEntry::Dir))
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    fn is_dir_in(entries: &BTreeMap<PathBuf, MemoryEntry>, path: &Path) -> bool {
+        // The empty path is the working directory, which always exists
+        path.as_os_str().is_empty() || path == Path::new("/")
+            || matches!(entries.get(path), Some(MemoryEntry::Dir))
+    }
+}
+
+impl Fs for MemoryFs {
+    fn make_dir_all(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut entries = self.entries.lock().unwrap();
+        let ancestors: Vec<&Path> = path.ancestors()
+            .filter(|ancestor| !Self::is_dir_in(&entries, ancestor))
+            .collect();
+        for ancestor in ancestors.into_iter().rev() {
+            if matches!(entries.get(ancestor), Some(MemoryEntry::File(_))) {
+                return Err(io::Error::new(io::ErrorKind::NotADirectory, format!("Not a directory: {}", ancestor.display())));
+            }
+            entries.insert(ancestor.to_path_buf(), MemoryEntry::Dir);
+        }
+        Ok(())
+    }
+
+    fn write_bytes(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let path = normalize(path);
+        let mut entries = self.entries.lock().unwrap();
+        let parent = path.parent().unwrap_or(Path::new(""));
+        if !Self::is_dir_in(&entries, parent) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("No such directory: {}", parent.display())));
+        }
+        if Self::is_dir_in(&entries, &path) {
+            return Err(io::Error::new(io::ErrorKind::IsADirectory, format!("Is a directory: {}", path.display())));
+        }
+        entries.insert(path, MemoryEntry::File(contents.to_vec()));
+        Ok(())
+    }
+
+    fn read_string(&self, path: &Path) -> io::Result<String> {
+        let path = normalize(path);
+        match self.entries.lock().unwrap().get(&path) {
+            Some(MemoryEntry::File(content)) => String::from_utf8(content.clone())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Some(MemoryEntry::Dir) => Err(io::Error::new(io::ErrorKind::IsADirectory, format!("Is a directory: {}", path.display()))),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, format!("No such file: {}", path.display()))),
+        }
+    }
+
+    fn move_file(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let (from, to) = (normalize(from), normalize(to));
+        let mut entries = self.entries.lock().unwrap();
+        let content = match entries.get(&from) {
+            Some(MemoryEntry::File(content)) => content.clone(),
+            Some(MemoryEntry::Dir) => return Err(io::Error::new(io::ErrorKind::IsADirectory, format!("Is a directory: {}", from.display()))),
+            None => return Err(io::Error::new(io::ErrorKind::NotFound, format!("No such file: {}", from.display()))),
+        };
+        if !Self::is_dir_in(&entries, to.parent().unwrap_or(Path::new(""))) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("No such directory for: {}", to.display())));
+        }
+        if Self::is_dir_in(&entries, &to) {
+            return Err(io::Error::new(io::ErrorKind::IsADirectory, format!("Is a directory: {}", to.display())));
+        }
+        entries.remove(&from);
+        entries.insert(to, MemoryEntry::File(content));
+        Ok(())
+    }
+
+    fn delete_file(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(&path) {
+            Some(MemoryEntry::File(_)) => {
+                entries.remove(&path);
+                Ok(())
+            }
+            Some(MemoryEntry::Dir) => Err(io::Error::new(io::ErrorKind::IsADirectory, format!("Is a directory: {}", path.display()))),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, format!("No such file: {}", path.display()))),
+        }
+    }
+
+    fn delete_dir_all(&self, path: &Path) -> io::Result<()> {
+        let path = normalize(path);
+        let mut entries = self.entries.lock().unwrap();
+        if !matches!(entries.get(&path), Some(MemoryEntry::Dir)) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("No such directory: {}", path.display())));
+        }
+        entries.retain(|entry, _| !entry.starts_with(&path));
+        Ok(())
+    }
+
+    fn entry_exists(&self, path: &Path) -> bool {
+        let path = normalize(path);
+        let entries = self.entries.lock().unwrap();
+        Self::is_dir_in(&entries, &path) || entries.contains_key(&path)
+    }
+
+    fn dir_exists(&self, path: &Path) -> bool {
+        Self::is_dir_in(&self.entries.lock().unwrap(), &normalize(path))
+    }
+
+    fn walk_tree(&self, root: &Path) -> io::Result<Vec<WalkEntry>> {
+        let normalized_root = normalize(root);
+        let entries = self.entries.lock().unwrap();
+        if !Self::is_dir_in(&entries, &normalized_root) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("No such directory: {}", root.display())));
+        }
+
+        Ok(entries.iter()
+            .filter_map(|(path, entry)| {
+                let relative = path.strip_prefix(&normalized_root).ok()?;
+                let hidden = relative.components()
+                    .any(|component| component.as_os_str().to_string_lossy().starts_with('.'));
+                if relative.as_os_str().is_empty() || hidden {
+                    return None;
+                }
+                Some(WalkEntry {
+                    path: root.join(relative),
+                    is_dir: matches!(entry, MemoryEntry::Dir),
+                })
+            })
+            .collect())
+    }
+
+    fn clone_repo(&self, _url: &str, path: &Path) -> io::Result<()> {
+        // Nothing is fetched; the checkout is an empty directory
+        self.make_dir_all(path)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        // There are no symlinks in memory, so folding `..` lexically gives the real location
+        let mut absolute = PathBuf::new();
+        for component in self.root.join(path).components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    absolute.pop();
+                }
+                other => absolute.push(other),
+            }
+        }
+
+        let entry = absolute.strip_prefix(&self.root).unwrap_or(&absolute);
+        if !self.entry_exists(entry) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("No such file or directory: {}", path.display())));
+        }
+        Ok(absolute)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_requires_parent_directory() {
+        let memory = MemoryFs::new();
+        let fs: &dyn Fs = &memory;
+
+        assert_eq!(fs.write(Path::new("src/main.rs"), b"").unwrap_err().kind(), io::ErrorKind::NotFound);
+
+        fs.create_dir_all(Path::new("./src/core")).unwrap();
+        fs.write(Path::new("src/main.rs"), b"fn main() {}\n").unwrap();
+
+        assert!(fs.is_dir(Path::new("src")));
+        assert!(fs.exists(Path::new("./src/main.rs")));
+        assert!(!fs.is_dir(Path::new("src/main.rs")));
+        assert_eq!(fs.read_to_string(Path::new("src/main.rs")).unwrap(), "fn main() {}\n");
+        assert_eq!(memory.dirs(), vec![PathBuf::from("src"), PathBuf::from("src/core")]);
+        assert!(fs.create_dir_all(Path::new("src/main.rs/nested")).is_err());
+    }
+
+    #[test]
+    fn test_walk_skips_hidden_entries() {
+        let memory = MemoryFs::new()
+            .with_file("src/lib.rs", "")
+            .with_file(".git/HEAD", "")
+            .with_file("src/.cache", "");
+        let fs: &dyn Fs = &memory;
+
+        let entries = fs.walk(Path::new(".")).unwrap();
+
+        assert_eq!(entries, vec![
+            WalkEntry { path: PathBuf::from("./src"), is_dir: true },
+            WalkEntry { path: PathBuf::from("./src/lib.rs"), is_dir: false },
+        ]);
+    }
+}
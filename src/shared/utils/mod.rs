@@ -1,6 +1,8 @@
 // start auto exported by moli.
 pub mod content_updater;
 pub mod diff;
+pub mod file_system;
+pub mod memory_fs;
+pub mod paths;
 pub mod yaml_formatter;
 // end auto exported by moli.
-
@@ -0,0 +1,37 @@
+use std::path::Path;
+use crate::shared::utils::file_system::Fs;
+
+/// Get the name of the directory a path points at ("." is resolved against the backend's root),
+/// falling back to `default` for paths without a name such as "/"
+pub fn directory_name(fs: &dyn Fs, path: &Path, default: &str) -> String {
+    let absolute_path = fs.canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf());
+
+    absolute_path.file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(default)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::utils::file_system::RealFs;
+    use crate::shared::utils::memory_fs::MemoryFs;
+
+    #[test]
+    fn test_directory_name() {
+        let current_dir = std::env::current_dir().unwrap();
+        let current_name = current_dir.file_name().unwrap().to_str().unwrap();
+
+        assert_eq!(directory_name(&RealFs, Path::new("."), "app"), current_name);
+        assert_eq!(directory_name(&RealFs, Path::new("services/api"), "app"), "api");
+        assert_eq!(directory_name(&RealFs, Path::new("/"), "app"), "app");
+
+        // In memory, "." and ".." resolve against the root instead of the working directory
+        let memory = MemoryFs::new().with_root("/work/shop").with_file("api/main.go", "");
+        assert_eq!(directory_name(&memory, Path::new("."), "app"), "shop");
+        assert_eq!(directory_name(&memory, Path::new("api/.."), "app"), "shop");
+        assert_eq!(directory_name(&MemoryFs::new(), Path::new("."), "app"), "app");
+    }
+}